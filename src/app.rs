@@ -1,6 +1,7 @@
 use std::{
     collections::HashMap,
     sync::mpsc::{self, Receiver, Sender},
+    time::Instant,
 };
 
 use ratatui::{
@@ -21,29 +22,39 @@ use sysinfo::Signal;
 
 use crate::{
     components::{
-        network::draw_network_info, process::draw_process_info,
-        theme::get_and_return_app_color_info,
+        battery::draw_battery_info,
+        cgroup::draw_cgroup_info,
+        compact::draw_compact_info,
+        interrupt::draw_interrupt_info,
+        network::draw_network_info,
+        numa::draw_numa_info,
+        process::draw_process_info,
+        session::draw_session_info,
+        theme::{get_and_return_app_color_info, get_theme_names, set_theme},
     },
+    config::{get_config_display_path, MAX_GRAPH_RANGE, MAX_TICK, MIN_GRAPH_RANGE, MIN_TICK},
     cpu::draw_cpu_info,
     disk::draw_disk_info,
     get_sys_info::{spawn_process_info_collector, spawn_system_info_collector},
     memory::draw_memory_info,
     types::{
-        AppColorInfo, AppPopUpType, AppState, CProcessesInfo, CSysInfo,
-        CurrentProcessSignalStateData, MemoryData, ProcessData, ProcessSortType, ProcessesInfo,
-        SelectedContainer, SysInfo,
+        AppColorInfo, AppConfig, AppPopUpType, AppState, CProcessesInfo, CSysInfo, CgroupSortType,
+        CompactViewData, CurrentProcessOomScoreAdjStateData, CurrentProcessSignalStateData,
+        CurrentThemeSelectionStateData, InterruptData, InterruptSortType, LayoutConfig,
+        LayoutPanel, MemoryData, OomKillEventData, PanelConfig, ProcessData, ProcessPreFilter,
+        ProcessQuickFilter, ProcessSortType, ProcessesInfo, SelectedContainer, SysInfo,
     },
     utils::{
-        get_signal_from_int, process_processes_info, process_sys_info, render_pop_up_menu,
-        send_signal,
+        get_layout_panel_areas, get_layout_shown_panels, get_oom_kill_lookahead,
+        get_oom_kill_lookback, get_oom_kill_victim, get_signal_from_int, process_processes_info,
+        process_sys_info, render_config_warning_banner, render_oom_kill_banner,
+        render_oom_score_adj_pop_up, render_pop_up_menu, render_theme_selection_pop_up,
+        send_signal, set_oom_score_adj,
     },
 };
 
-// this need to be the same as MAXIMUM_DATA_COLLECTION in types.rs
-const MAX_GRAPH_SHOWN_RANGE: usize = 500;
-
 struct App {
-    is_quit: bool,                          // to indicate is user wanted to quit the app
+    is_quit: bool,                            // to indicate is user wanted to quit the app
     tick: u32, // refresh rate for the metrics ( default is 1000ms, customizable by user )
     tx: Sender<CSysInfo>, // this will be pass to another thread that will be spawn for collecting metrics to send the data collected back
     rx: Receiver<CSysInfo>, // this will be in the main app to receive the data info send back
@@ -51,91 +62,196 @@ struct App {
     process_rx: Receiver<CProcessesInfo>, // this will be in the main app to receive the process data info send back
     tick_tx: Sender<u32>, // this will be for sending the updated tick to the thread spawn to update the frequency of collecting data
     process_tick_tx: Sender<u32>, // this will be for sending the updated tick to the thread spawn to update the frequency of collecting process data
-    sys_info: SysInfo,            // the system info collected
-    process_info: ProcessesInfo,  // the system process info collected
+    process_detail_tx: Sender<Option<u32>>, // this will be for sending the pid shown in the process detail to the thread spawn for collecting process data, only that process will have its NUMA memory collected
+    cgroup_shown_tx: Sender<bool>, // this will be for telling the thread spawn for collecting metrics if the cgroup panel was shown, the cgroup tree is only walked then
+    is_cgroup_shown: bool, // indicate if the cgroup panel was shown, as last sent to the thread collecting metrics
+    sys_info: SysInfo,     // the system info collected
+    process_info: ProcessesInfo, // the system process info collected
     selected_container: SelectedContainer, // current selected container in the UI
-    state: AppState,              // current state of the app
-    pop_up_type: AppPopUpType,    // current pop up type
+    state: AppState,       // current state of the app
+    pop_up_type: AppPopUpType, // current pop up type
     cpu_graph_shown_range: usize, // range of graph shown for CPU
     memory_graph_shown_range: usize, // range of graph shown for MEMORY
     disk_graph_shown_range: usize, // range of graph shown for DISK
     network_graph_shown_range: usize, // range of graph shown for NETWORK
     process_graph_shown_range: usize, // range of graph shown for PROCESS [ this will the the graph shown in the process detail layout ]
-    cpu_selected_state: ListState,    // current selected individual cpu
-    disk_selected_entry: usize,       // current selected individual disk
-    network_selected_entry: usize,    // current selected individual network
+    battery_graph_shown_range: usize, // range of graph shown for BATTERY
+    cgroup_graph_shown_range: usize, // range of graph shown for CGROUP [ this will be the graph shown for the selected cgroup ]
+    interrupt_graph_shown_range: usize, // range of graph shown for INTERRUPT
+    cpu_selected_state: ListState,   // current selected individual cpu
+    disk_selected_entry: usize,      // current selected individual disk
+    network_selected_entry: usize,   // current selected individual network
+    battery_selected_entry: usize,   // current selected individual battery
     process_current_list: Vec<ProcessData>, // current process list after filtering/sorting
     process_selectable_entries: usize, // current selectable entries in the process list
     process_selected_state: ListState, // current selected individual process
-    process_sort_selected_state: u8,  // current selected sorting
+    process_sort_selected_state: u8, // current selected sorting
     process_sort_type: ProcessSortType, // current sorting type
     process_sort_is_reversed: bool, // by default the sorting will be in descending order (true), by setting this to false, the sort will be in ascending order
     process_filter: String,         // current user input for filtering
     process_show_details: bool,     // indicate if user wanted to show process details
+    process_show_container: bool, // indicate if user wanted to show the container/cgroup column in the process list
+    process_quick_filter_selected_state: u8, // current selected quick filter
+    process_quick_filter: ProcessQuickFilter, // current quick filter, to only show processes with elevated capabilities for example
+    process_pre_filter: ProcessPreFilter, // processes to be shown set from the command line, this can't be changed in the UI
+    memory_show_numa: bool, // indicate if user switched the memory container to the per NUMA node view
+    panels: PanelConfig,    // the containers shown in the main view
+    layouts: Vec<LayoutConfig>, // the layouts of the main view that can be cycled through
+    layout_selected_entry: usize, // current selected layout
+    is_compact: bool, // indicate if the terminal was too small for the main view, only the process list can be selected then
     current_showing_process_detail: Option<HashMap<String, ProcessData>>, // the current showing process detail
-    is_renderable: bool,         // to indicate if this app UI is renderable
-    is_init: bool,               // to indicate is this app has done initialization
+    cgroup_current_list: Vec<String>, // current cgroup paths in the order shown in the cgroup tree
+    cgroup_selected_state: ListState, // current selected row in the cgroup tree, kept in line with the selected path on every draw
+    cgroup_selected_path: Option<String>, // current selected individual cgroup, kept by path as the tree is sorted again on every refresh
+    cgroup_sort_selected_state: u8,       // current selected cgroup sorting
+    cgroup_sort_type: CgroupSortType,     // current cgroup sorting type
+    interrupt_selected_state: ListState,  // current selected individual interrupt source
+    interrupt_sort_selected_state: usize, // current selected interrupt sorting
+    interrupt_sort_type: InterruptSortType, // current interrupt sorting type
+    is_renderable: bool,                  // to indicate if this app UI is renderable
+    is_init: bool,                        // to indicate is this app has done initialization
     container_full_screen: bool, // to indicate is user choose to full screen the current selected container
+    show_host_totals: bool, // when running under cgroup limits, indicate if user wanted to see the host-wide cpu and memory instead
     current_process_signal_state_data: Option<CurrentProcessSignalStateData>, // this was used to temporary save the data when user trigger the process signal related pop-up
+    current_process_oom_score_adj_state_data: Option<CurrentProcessOomScoreAdjStateData>, // this was used to temporary save the data when user trigger the oom_score_adj pop-up
+    current_theme_selection_state_data: Option<CurrentThemeSelectionStateData>, // this was used to temporary save the data when user trigger the theme selection pop-up
+    theme: String,                            // current theme name
+    app_color_info: AppColorInfo, // the colors of the current theme, this will change as user move through the theme selection pop-up
+    oom_kill_event: Option<OomKillEventData>, // the latest OOM kill, shown as a banner until user dismiss it
+    config_warnings: Vec<String>, // problems found in the settings and the theme on start up, shown as a banner until user dismiss it
 }
 
 const MIN_HEIGHT: u16 = 25;
 const MIN_WIDTH: u16 = 90;
+// below the min size above, the compact view will be shown until the terminal is smaller than this
+const COMPACT_MIN_HEIGHT: u16 = 12;
+const COMPACT_MIN_WIDTH: u16 = 40;
+
+pub fn app(
+    app_config: AppConfig,
+    config_warnings: Vec<String>,
+    process_pre_filter: ProcessPreFilter,
+    full_screen_container: Option<SelectedContainer>,
+) {
+    // the theme was loaded before the terminal was taken over, so that its problems can be shown along with the settings
+    let (app_color_info, theme_warnings) = get_and_return_app_color_info(&app_config.theme);
+    let config_warnings = config_warnings
+        .into_iter()
+        .map(|warning| format!("{} {}", get_config_display_path(), warning))
+        .chain(theme_warnings)
+        .collect();
 
-pub fn app() {
     enable_raw_mode().unwrap();
     let mut terminal = init();
     let (tx, rx) = mpsc::channel();
     let (process_tx, process_rx) = mpsc::channel();
     let (tick_tx, tick_rx) = mpsc::channel();
     let (process_tick_tx, process_tick_rx) = mpsc::channel();
+    let (process_detail_tx, process_detail_rx) = mpsc::channel();
+    let (cgroup_shown_tx, cgroup_shown_rx) = mpsc::channel();
+
+    let process_sort_selected_state = (0..ProcessSortType::total_selection_count())
+        .find(|state| {
+            ProcessSortType::get_process_sort_type_from_int(*state) == app_config.process.sort
+        })
+        .unwrap_or(0);
+    // the filter always ends with a "_" when it is not empty, it act as the cursor when typing
+    let process_filter = if app_config.process.filter.is_empty() {
+        String::new()
+    } else {
+        format!("{}_", app_config.process.filter)
+    };
 
     let mut app = App {
         is_quit: false,
-        tick: 1000,
+        tick: app_config.tick,
         tx,
         rx,
         process_tx,
         process_rx,
         tick_tx,
         process_tick_tx,
+        process_detail_tx,
+        cgroup_shown_tx,
+        is_cgroup_shown: false,
         sys_info: SysInfo {
             cpus: vec![],
             memory: MemoryData::default(),
             disks: HashMap::new(),
             networks: HashMap::new(),
+            cgroups: None,
+            container_limit: None,
+            batteries: HashMap::new(),
+            power_zones: HashMap::new(),
+            sessions: vec![],
+            interrupts: InterruptData::default(),
+            numa_nodes: HashMap::new(),
         },
         process_info: ProcessesInfo {
             processes: HashMap::new(),
+            recently_exited: vec![],
         },
-        selected_container: SelectedContainer::None,
+        container_full_screen: full_screen_container.is_some(),
+        selected_container: full_screen_container.unwrap_or(SelectedContainer::None),
         state: AppState::View,
         pop_up_type: AppPopUpType::None,
-        cpu_graph_shown_range: 100,
-        memory_graph_shown_range: 100,
-        disk_graph_shown_range: 100,
-        network_graph_shown_range: 100,
-        process_graph_shown_range: 100,
+        cpu_graph_shown_range: app_config.graph_range.cpu,
+        memory_graph_shown_range: app_config.graph_range.memory,
+        disk_graph_shown_range: app_config.graph_range.disk,
+        network_graph_shown_range: app_config.graph_range.network,
+        process_graph_shown_range: app_config.graph_range.process,
+        battery_graph_shown_range: app_config.graph_range.battery,
+        cgroup_graph_shown_range: app_config.graph_range.cgroup,
+        interrupt_graph_shown_range: app_config.graph_range.interrupt,
         cpu_selected_state: ListState::default(),
         disk_selected_entry: 0,
         network_selected_entry: 0,
+        battery_selected_entry: 0,
         process_current_list: vec![],
         process_selectable_entries: 0,
         process_selected_state: ListState::default(),
-        process_sort_selected_state: 0,
-        process_sort_type: ProcessSortType::Thread,
-        process_sort_is_reversed: true,
-        process_filter: String::new(),
+        process_sort_selected_state,
+        process_sort_type: app_config.process.sort.clone(),
+        process_sort_is_reversed: app_config.process.reverse,
+        process_filter,
         process_show_details: false,
+        process_show_container: false,
+        process_quick_filter_selected_state: 0,
+        process_quick_filter: ProcessQuickFilter::All,
+        process_pre_filter,
+        memory_show_numa: false,
+        panels: app_config.panels.clone(),
+        layouts: app_config.layouts.clone(),
+        layout_selected_entry: 0,
+        is_compact: false,
         current_showing_process_detail: None,
+        cgroup_current_list: vec![],
+        cgroup_selected_state: ListState::default(),
+        cgroup_selected_path: None,
+        cgroup_sort_selected_state: 1,
+        cgroup_sort_type: CgroupSortType::Cpu,
+        interrupt_selected_state: ListState::default(),
+        interrupt_sort_selected_state: 0,
+        interrupt_sort_type: InterruptSortType::Total,
         is_renderable: true,
         is_init: false,
-        container_full_screen: false,
+        show_host_totals: false,
         current_process_signal_state_data: None,
+        current_process_oom_score_adj_state_data: None,
+        current_theme_selection_state_data: None,
+        theme: app_config.theme.clone(),
+        app_color_info,
+        oom_kill_event: None,
+        config_warnings,
     };
 
-    let app_color_info = get_and_return_app_color_info();
-    app.run(&mut terminal, tick_rx, process_tick_rx, app_color_info);
+    app.run(
+        &mut terminal,
+        tick_rx,
+        process_tick_rx,
+        process_detail_rx,
+        cgroup_shown_rx,
+    );
     disable_raw_mode().unwrap();
     restore();
 }
@@ -147,12 +263,20 @@ impl App {
         terminal: &mut DefaultTerminal,
         tick_rx: Receiver<u32>,
         process_tick_rx: Receiver<u32>,
-        app_color_info: AppColorInfo,
+        process_detail_rx: Receiver<Option<u32>>,
+        cgroup_shown_rx: Receiver<bool>,
     ) {
         // when the program start, we let the info collector to collect at 100ms
         // only after the initial collection, we reset to the user selected tick ( this will be able to be configure at a later stage )
-        spawn_system_info_collector(tick_rx, self.tx.clone(), 100);
-        spawn_process_info_collector(process_tick_rx, self.process_tx.clone(), 100);
+        // the collector will only start walking the cgroup tree once it was told the cgroup panel was shown
+        self.send_cgroup_shown();
+        spawn_system_info_collector(tick_rx, cgroup_shown_rx, self.tx.clone(), 100);
+        spawn_process_info_collector(
+            process_tick_rx,
+            process_detail_rx,
+            self.process_tx.clone(),
+            100,
+        );
 
         while !self.is_init {
             match self.rx.try_recv() {
@@ -188,6 +312,19 @@ impl App {
             let c_sys_info = self.rx.try_recv();
             if c_sys_info.is_ok() {
                 process_sys_info(&mut self.sys_info, c_sys_info.unwrap());
+
+                // a new OOM kill since the previous tick
+                if let Some(oom_kills) = self.sys_info.memory.oom_kill_vec.last() {
+                    if *oom_kills > 0.0 {
+                        self.oom_kill_event = Some(OomKillEventData {
+                            detected_at: Instant::now(),
+                            time: chrono::Local::now().format("%H:%M:%S").to_string(),
+                            count: *oom_kills as u64,
+                            victim: None,
+                            is_resolved: false,
+                        });
+                    }
+                }
             }
 
             let c_process_info = self.process_rx.try_recv();
@@ -198,6 +335,10 @@ impl App {
                     &mut self.current_showing_process_detail,
                 );
             }
+            self.resolve_oom_kill_victim();
+            self.send_cgroup_shown();
+
+            let app_color_info = self.app_color_info.clone();
             let _ = terminal.draw(|frame| self.draw(frame, &app_color_info));
 
             // we only handle event if the tui is renderable
@@ -208,33 +349,29 @@ impl App {
     }
 
     fn draw(&mut self, frame: &mut Frame, app_color_info: &AppColorInfo) {
-        //
-        //                       The TUI Layout
-        //
-        //   ------------------------------------------------------------
-        //   |                                                          |
-        //   |                  CPU INFO (top 30.0%)                    |
-        //   |                                                          |
-        //   ------------------------------------------------------------
-        //   |   (MEMORY AND DIKS)     |                                |
-        //   |    Bottom left (45%)    |   (PROCESS bottom right 55%)   |
-        //   |      & top (65%)        |                                |
-        //   |--------------------(BOTTOM 70%)                          |
-        //   |      (NETWORK)          |                                |
-        //   |    Bottom left (45%)    |                                |
-        //   |     & bottom (35%)      |                                |
-        //   ------------------------------------------------------------
-
-        // split and init the layout space for each container
-        let top_and_bottom = Layout::vertical([Constraint::Fill(30), Constraint::Fill(70)]);
-        let [cpu_area, bottom] = top_and_bottom.areas(frame.area());
-        let [bottom_left, process_area] =
-            Layout::horizontal([Constraint::Fill(45), Constraint::Fill(55)]).areas(bottom);
-        let [memory_disk_area, network_area] =
-            Layout::vertical([Constraint::Fill(65), Constraint::Fill(35)]).areas(bottom_left);
-        let [memory_area, disk_area] =
-            Layout::horizontal([Constraint::Fill(50), Constraint::Fill(50)])
-                .areas(memory_disk_area);
+        // split and init the layout space for each container from the layout selected ( see LayoutConfig::get_default_layouts for the default layouts )
+        // hidden containers and the containers not in the layout will give up their space to the others
+        let panel_areas = get_layout_panel_areas(
+            &self.layouts[self.layout_selected_entry],
+            frame.area(),
+            &self.panels,
+        );
+        let panels = PanelConfig {
+            cpu: panel_areas.contains_key(&LayoutPanel::Cpu),
+            memory: panel_areas.contains_key(&LayoutPanel::Memory),
+            disk: panel_areas.contains_key(&LayoutPanel::Disk),
+            network: panel_areas.contains_key(&LayoutPanel::Network),
+            process: panel_areas.contains_key(&LayoutPanel::Process),
+            cgroup: panel_areas.contains_key(&LayoutPanel::Cgroup),
+        };
+        let get_panel_area =
+            |panel: LayoutPanel| panel_areas.get(&panel).copied().unwrap_or_default();
+        let cpu_area = get_panel_area(LayoutPanel::Cpu);
+        let memory_area = get_panel_area(LayoutPanel::Memory);
+        let disk_area = get_panel_area(LayoutPanel::Disk);
+        let network_area = get_panel_area(LayoutPanel::Network);
+        let process_area = get_panel_area(LayoutPanel::Process);
+        let cgroup_area = get_panel_area(LayoutPanel::Cgroup);
 
         // set the bg
         let background =
@@ -243,7 +380,9 @@ impl App {
 
         // check if the terminal size is valid
         let full_frame_view_rect = frame.area();
-        if full_frame_view_rect.width < MIN_WIDTH || full_frame_view_rect.height < MIN_HEIGHT {
+        if full_frame_view_rect.width < COMPACT_MIN_WIDTH
+            || full_frame_view_rect.height < COMPACT_MIN_HEIGHT
+        {
             self.is_renderable = false;
             draw_not_renderable_message(frame, app_color_info);
             return;
@@ -277,12 +416,80 @@ impl App {
                 self.network_selected_entry = 0;
             }
 
-            // handling for full screen mode
-            if self.container_full_screen {
+            // the battery panel will only be shown on machines with battery
+            // batteries were sorted by name so that the order stays the same between refresh
+            let mut battery_names: Vec<&String> = self.sys_info.batteries.keys().collect();
+            battery_names.sort();
+            if self.battery_selected_entry >= battery_names.len() {
+                self.battery_selected_entry = 0;
+            }
+            let selected_battery = battery_names
+                .get(self.battery_selected_entry)
+                .and_then(|name| self.sys_info.batteries.get(*name));
+            if selected_battery.is_none() && self.selected_container == SelectedContainer::Battery {
+                self.container_full_screen = false;
+                self.selected_container = SelectedContainer::None;
+            }
+
+            // when rtop was running under cgroup limits ( inside a container for example ), the cpu and memory
+            // will be shown relative to the limits, unless user wanted to see the host-wide numbers
+            let (shown_cpus, shown_memory) = match &self.sys_info.container_limit {
+                Some(limit) if !self.show_host_totals => (&limit.cpus, &limit.memory),
+                _ => (&self.sys_info.cpus, &self.sys_info.memory),
+            };
+            // prevent selecting a cpu that is not within the cpuset
+            if self.cpu_selected_state.selected().unwrap_or(0) >= shown_cpus.len() {
+                self.cpu_selected_state.select(Some(0));
+            }
+
+            // the compact view only has the process list to select from, so the other containers were deselected
+            self.is_compact =
+                full_frame_view_rect.width < MIN_WIDTH || full_frame_view_rect.height < MIN_HEIGHT;
+            if self.is_compact
+                && self.selected_container != SelectedContainer::None
+                && self.selected_container != SelectedContainer::Process
+            {
+                self.container_full_screen = false;
+                self.selected_container = SelectedContainer::None;
+            }
+
+            if self.is_compact {
+                draw_compact_info(
+                    CompactViewData {
+                        tick: self.tick as u64,
+                        cpus: shown_cpus,
+                        memory: shown_memory,
+                        disk_data: selected_disk,
+                        network_data: selected_network,
+                        process_data: &self.process_info.processes,
+                        process_current_list: &mut self.process_current_list,
+                        process_selectable_entries: &mut self.process_selectable_entries,
+                        process_selected_state: &mut self.process_selected_state,
+                        process_sort_type: &self.process_sort_type,
+                        process_sort_is_reversed: self.process_sort_is_reversed,
+                        process_filter: self.process_filter.clone(),
+                        is_filtering: self.state == AppState::Typing,
+                        process_quick_filter: &self.process_quick_filter,
+                        process_pre_filter: &self.process_pre_filter,
+                        process_detail: self
+                            .current_showing_process_detail
+                            .as_ref()
+                            .filter(|_| self.process_show_details)
+                            .and_then(|process_detail| process_detail.values().next()),
+                        is_process_selected: self.selected_container == SelectedContainer::Process,
+                        panels: &panels,
+                    },
+                    full_frame_view_rect,
+                    frame,
+                    app_color_info,
+                );
+            }
+            // handling for full screen mode ( standalone containers like the cgroup panel are always full screen )
+            else if self.container_full_screen || self.is_selected_container_standalone() {
                 if self.selected_container == SelectedContainer::Cpu {
                     draw_cpu_info(
                         self.tick as u64,
-                        &self.sys_info.cpus,
+                        shown_cpus,
                         full_frame_view_rect,
                         frame,
                         &mut self.cpu_selected_state,
@@ -293,22 +500,38 @@ impl App {
                             false
                         },
                         app_color_info,
+                        self.sys_info.container_limit.as_ref(),
+                        self.show_host_totals,
+                        &self.sys_info.power_zones,
                     );
                 } else if self.selected_container == SelectedContainer::Memory {
-                    draw_memory_info(
-                        self.tick as u64,
-                        &self.sys_info.memory,
-                        full_frame_view_rect,
-                        frame,
-                        self.memory_graph_shown_range,
-                        if self.selected_container == SelectedContainer::Memory {
-                            true
-                        } else {
-                            false
-                        },
-                        app_color_info,
-                        true,
-                    )
+                    if self.memory_show_numa && !self.sys_info.numa_nodes.is_empty() {
+                        draw_numa_info(
+                            self.tick as u64,
+                            &self.sys_info.numa_nodes,
+                            &self.sys_info.cpus,
+                            &self.process_info.processes,
+                            full_frame_view_rect,
+                            frame,
+                            self.memory_graph_shown_range,
+                            true,
+                            app_color_info,
+                            true,
+                        )
+                    } else {
+                        draw_memory_info(
+                            self.tick as u64,
+                            shown_memory,
+                            &self.sys_info.memory,
+                            full_frame_view_rect,
+                            frame,
+                            self.memory_graph_shown_range,
+                            true,
+                            app_color_info,
+                            true,
+                            !self.sys_info.numa_nodes.is_empty(),
+                        )
+                    }
                 } else if self.selected_container == SelectedContainer::Disk {
                     draw_disk_info(
                         self.tick as u64,
@@ -351,8 +574,11 @@ impl App {
                         self.process_filter.clone(),
                         self.process_show_details,
                         &self.current_showing_process_detail,
-                        self.sys_info.memory.total_memory,
+                        shown_memory.total_memory,
                         self.state == AppState::Typing,
+                        self.process_show_container,
+                        &self.process_quick_filter,
+                        &self.process_pre_filter,
                         full_frame_view_rect,
                         frame,
                         self.process_graph_shown_range,
@@ -364,97 +590,251 @@ impl App {
                         app_color_info,
                         true,
                     )
+                } else if self.selected_container == SelectedContainer::Battery {
+                    if let Some(battery) = selected_battery {
+                        draw_battery_info(
+                            self.tick as u64,
+                            battery,
+                            full_frame_view_rect,
+                            frame,
+                            self.battery_graph_shown_range,
+                            true,
+                            app_color_info,
+                            true,
+                        )
+                    }
+                } else if self.selected_container == SelectedContainer::Cgroup {
+                    draw_cgroup_info(
+                        self.tick as u64,
+                        self.sys_info.cgroups.as_ref(),
+                        &mut self.cgroup_current_list,
+                        &mut self.cgroup_selected_state,
+                        &mut self.cgroup_selected_path,
+                        &self.cgroup_sort_type,
+                        self.sys_info.memory.total_memory,
+                        full_frame_view_rect,
+                        frame,
+                        self.cgroup_graph_shown_range,
+                        true,
+                        app_color_info,
+                    )
+                } else if self.selected_container == SelectedContainer::Session {
+                    draw_session_info(
+                        self.tick as u64,
+                        &self.sys_info.sessions,
+                        &self.process_info.processes,
+                        full_frame_view_rect,
+                        frame,
+                        true,
+                        app_color_info,
+                    )
+                } else if self.selected_container == SelectedContainer::Interrupt {
+                    draw_interrupt_info(
+                        self.tick as u64,
+                        &self.sys_info.interrupts,
+                        &mut self.interrupt_selected_state,
+                        &self.interrupt_sort_type,
+                        full_frame_view_rect,
+                        frame,
+                        self.interrupt_graph_shown_range,
+                        true,
+                        app_color_info,
+                    )
                 }
             } else {
-                draw_cpu_info(
-                    self.tick as u64,
-                    &self.sys_info.cpus,
-                    cpu_area,
-                    frame,
-                    &mut self.cpu_selected_state,
-                    self.cpu_graph_shown_range,
-                    if self.selected_container == SelectedContainer::Cpu {
-                        true
-                    } else {
-                        false
-                    },
-                    app_color_info,
-                );
+                if panels.cpu {
+                    draw_cpu_info(
+                        self.tick as u64,
+                        shown_cpus,
+                        cpu_area,
+                        frame,
+                        &mut self.cpu_selected_state,
+                        self.cpu_graph_shown_range,
+                        if self.selected_container == SelectedContainer::Cpu {
+                            true
+                        } else {
+                            false
+                        },
+                        app_color_info,
+                        self.sys_info.container_limit.as_ref(),
+                        self.show_host_totals,
+                        &self.sys_info.power_zones,
+                    );
+                }
 
-                draw_memory_info(
-                    self.tick as u64,
-                    &self.sys_info.memory,
-                    memory_area,
-                    frame,
-                    self.memory_graph_shown_range,
-                    if self.selected_container == SelectedContainer::Memory {
-                        true
-                    } else {
-                        false
-                    },
-                    app_color_info,
-                    false,
-                );
+                if panels.memory && self.memory_show_numa && !self.sys_info.numa_nodes.is_empty() {
+                    draw_numa_info(
+                        self.tick as u64,
+                        &self.sys_info.numa_nodes,
+                        &self.sys_info.cpus,
+                        &self.process_info.processes,
+                        memory_area,
+                        frame,
+                        self.memory_graph_shown_range,
+                        if self.selected_container == SelectedContainer::Memory {
+                            true
+                        } else {
+                            false
+                        },
+                        app_color_info,
+                        false,
+                    );
+                } else if panels.memory {
+                    draw_memory_info(
+                        self.tick as u64,
+                        shown_memory,
+                        &self.sys_info.memory,
+                        memory_area,
+                        frame,
+                        self.memory_graph_shown_range,
+                        if self.selected_container == SelectedContainer::Memory {
+                            true
+                        } else {
+                            false
+                        },
+                        app_color_info,
+                        false,
+                        !self.sys_info.numa_nodes.is_empty(),
+                    );
+                }
+
+                if panels.disk {
+                    draw_disk_info(
+                        self.tick as u64,
+                        &selected_disk,
+                        disk_area,
+                        frame,
+                        self.disk_graph_shown_range,
+                        if self.selected_container == SelectedContainer::Disk {
+                            true
+                        } else {
+                            false
+                        },
+                        app_color_info,
+                        false,
+                    );
+                }
+
+                // the network area will be shared with the battery panel when there is a battery
+                let network_area = match selected_battery {
+                    Some(battery) if panels.network => {
+                        let [network_area, battery_area] =
+                            Layout::horizontal([Constraint::Fill(60), Constraint::Fill(40)])
+                                .areas(network_area);
+                        draw_battery_info(
+                            self.tick as u64,
+                            battery,
+                            battery_area,
+                            frame,
+                            self.battery_graph_shown_range,
+                            self.selected_container == SelectedContainer::Battery,
+                            app_color_info,
+                            false,
+                        );
+                        network_area
+                    }
+                    _ => network_area,
+                };
+
+                if panels.network {
+                    draw_network_info(
+                        self.tick as u64,
+                        &selected_network,
+                        network_area,
+                        frame,
+                        self.network_graph_shown_range,
+                        if self.selected_container == SelectedContainer::Network {
+                            true
+                        } else {
+                            false
+                        },
+                        app_color_info,
+                        false,
+                    );
+                }
+
+                if panels.process {
+                    draw_process_info(
+                        self.tick as u64,
+                        &self.process_info.processes,
+                        &mut self.process_current_list,
+                        &mut self.process_selectable_entries,
+                        &mut self.process_selected_state,
+                        &self.process_sort_type,
+                        self.process_sort_is_reversed,
+                        self.process_filter.clone(),
+                        self.process_show_details,
+                        &self.current_showing_process_detail,
+                        shown_memory.total_memory,
+                        self.state == AppState::Typing,
+                        self.process_show_container,
+                        &self.process_quick_filter,
+                        &self.process_pre_filter,
+                        process_area,
+                        frame,
+                        self.process_graph_shown_range,
+                        if self.selected_container == SelectedContainer::Process {
+                            true
+                        } else {
+                            false
+                        },
+                        app_color_info,
+                        false,
+                    )
+                }
+
+                if panels.cgroup {
+                    draw_cgroup_info(
+                        self.tick as u64,
+                        self.sys_info.cgroups.as_ref(),
+                        &mut self.cgroup_current_list,
+                        &mut self.cgroup_selected_state,
+                        &mut self.cgroup_selected_path,
+                        &self.cgroup_sort_type,
+                        self.sys_info.memory.total_memory,
+                        cgroup_area,
+                        frame,
+                        self.cgroup_graph_shown_range,
+                        self.selected_container == SelectedContainer::Cgroup,
+                        app_color_info,
+                    )
+                }
+            }
+
+            if let Some(oom_kill_event) = &self.oom_kill_event {
+                render_oom_kill_banner(full_frame_view_rect, frame, oom_kill_event, app_color_info);
+            }
 
-                draw_disk_info(
-                    self.tick as u64,
-                    &selected_disk,
-                    disk_area,
+            if !self.config_warnings.is_empty() {
+                render_config_warning_banner(
+                    full_frame_view_rect,
                     frame,
-                    self.disk_graph_shown_range,
-                    if self.selected_container == SelectedContainer::Disk {
-                        true
-                    } else {
-                        false
-                    },
+                    &self.config_warnings,
                     app_color_info,
-                    false,
                 );
+            }
 
-                draw_network_info(
-                    self.tick as u64,
-                    &selected_network,
-                    network_area,
+            // render pop up after all the main components are rendered
+            // for the pop up size, it will be decide at the function according to the pop up type
+            if self.state == AppState::Popup && self.pop_up_type == AppPopUpType::OomScoreAdjust {
+                render_oom_score_adj_pop_up(
+                    full_frame_view_rect,
                     frame,
-                    self.network_graph_shown_range,
-                    if self.selected_container == SelectedContainer::Network {
-                        true
-                    } else {
-                        false
-                    },
+                    self.current_process_oom_score_adj_state_data
+                        .as_ref()
+                        .unwrap(),
                     app_color_info,
-                    false,
                 );
-
-                draw_process_info(
-                    self.tick as u64,
-                    &self.process_info.processes,
-                    &mut self.process_current_list,
-                    &mut self.process_selectable_entries,
-                    &mut self.process_selected_state,
-                    &self.process_sort_type,
-                    self.process_sort_is_reversed,
-                    self.process_filter.clone(),
-                    self.process_show_details,
-                    &self.current_showing_process_detail,
-                    self.sys_info.memory.total_memory,
-                    self.state == AppState::Typing,
-                    process_area,
+            } else if self.state == AppState::Popup
+                && self.pop_up_type == AppPopUpType::ThemeSelection
+            {
+                render_theme_selection_pop_up(
+                    full_frame_view_rect,
                     frame,
-                    self.process_graph_shown_range,
-                    if self.selected_container == SelectedContainer::Process {
-                        true
-                    } else {
-                        false
-                    },
+                    self.current_theme_selection_state_data.as_mut().unwrap(),
                     app_color_info,
-                    false,
-                )
-            }
-
-            // render pop up after all the main components are rendered
-            // for the pop up size, it will be decide at the function according to the pop up type
-            if self.state == AppState::Popup && self.pop_up_type != AppPopUpType::None {
+                );
+            } else if self.state == AppState::Popup && self.pop_up_type != AppPopUpType::None {
                 render_pop_up_menu(
                     full_frame_view_rect,
                     frame,
@@ -466,6 +846,96 @@ impl App {
         }
     }
 
+    // name the victim of the OOM kill from the processes that disappeared around the time it was detected,
+    // the process list and the vmstat counters were collected separately, so the victim can disappear a tick later
+    // the OOM kill happened after the previous vmstat sample ( a tick before it was detected ), so the processes
+    // that disappeared before that were not considered, and the victim will only be picked once every process
+    // that disappeared after the OOM kill had the time to show up
+    fn resolve_oom_kill_victim(&mut self) {
+        let lookback = get_oom_kill_lookback(self.tick);
+        let lookahead = get_oom_kill_lookahead(self.tick);
+        if let Some(oom_kill_event) = self.oom_kill_event.as_mut() {
+            if oom_kill_event.is_resolved || oom_kill_event.detected_at.elapsed() < lookahead {
+                return;
+            }
+            let since = oom_kill_event
+                .detected_at
+                .checked_sub(lookback)
+                .unwrap_or(oom_kill_event.detected_at);
+            oom_kill_event.victim = get_oom_kill_victim(
+                &self.process_info.recently_exited,
+                since,
+                oom_kill_event.detected_at + lookahead,
+            )
+            .map(|victim| (victim.pid, victim.name.clone()));
+            oom_kill_event.is_resolved = true;
+        }
+    }
+
+    // walking the whole cgroup tree on every tick is only worth it while the cgroup panel was shown,
+    // either selected or placed in the current layout
+    fn send_cgroup_shown(&mut self) {
+        let is_cgroup_shown = self.selected_container == SelectedContainer::Cgroup
+            || get_layout_shown_panels(&self.layouts[self.layout_selected_entry], &self.panels)
+                .contains(&LayoutPanel::Cgroup);
+        if is_cgroup_shown != self.is_cgroup_shown {
+            self.is_cgroup_shown = is_cgroup_shown;
+            let _ = self.cgroup_shown_tx.send(is_cgroup_shown);
+        }
+    }
+
+    // the cgroup panel will only be shown in full screen when it was not placed in the current layout
+    fn is_selected_container_standalone(&self) -> bool {
+        self.selected_container.is_standalone()
+            && !(self.selected_container == SelectedContainer::Cgroup
+                && get_layout_shown_panels(&self.layouts[self.layout_selected_entry], &self.panels)
+                    .contains(&LayoutPanel::Cgroup))
+    }
+
+    // the panels hidden by the layout will be shown as well, as they were hidden in the main view all the same
+    fn toggle_panel(&mut self, panel: LayoutPanel) {
+        let layout = &mut self.layouts[self.layout_selected_entry];
+        // a panel not placed in the layout would only be hidden without any sign of it, and stay hidden in the other layouts
+        if !layout.get_root_node().get_panels().contains(&panel) {
+            return;
+        }
+        let is_panel_shown = |panels: &PanelConfig, panel: &LayoutPanel| {
+            panels.is_shown(*panel) && !layout.hidden.contains(panel)
+        };
+
+        if !is_panel_shown(&self.panels, &panel) {
+            self.panels.set_shown(panel, true);
+            layout.hidden.retain(|hidden_panel| *hidden_panel != panel);
+            return;
+        }
+
+        // keep at least one panel of the layout shown, the layout will show every panel when there is none
+        let mut panels = self.panels.clone();
+        panels.set_shown(panel, false);
+        if !layout
+            .get_root_node()
+            .get_panels()
+            .iter()
+            .any(|panel| is_panel_shown(&panels, panel))
+        {
+            return;
+        }
+        self.panels = panels;
+
+        // the container can't stay selected when it was no longer shown, unless it was in full screen
+        if panel.is_containing(&self.selected_container) && !self.container_full_screen {
+            self.selected_container = SelectedContainer::None;
+        }
+    }
+
+    // the number of cpu entries shown, which can be limited by the cpuset when running under cgroup limits
+    fn get_shown_cpu_count(&self) -> usize {
+        match &self.sys_info.container_limit {
+            Some(limit) if !self.show_host_totals => limit.cpus.len(),
+            _ => self.sys_info.cpus.len(),
+        }
+    }
+
     fn handle_events(&mut self) {
         if event::poll(std::time::Duration::from_millis(100)).unwrap() {
             match event::read().unwrap() {
@@ -487,10 +957,21 @@ impl App {
 
     fn handle_key_event(&mut self, key_event: KeyEvent) {
         match key_event.code {
+            // the compact view only has the process list to select from, the other containers are not shown
+            KeyCode::Char(
+                'c' | 'C' | 'm' | 'M' | 'd' | 'D' | 'n' | 'N' | 'b' | 'B' | 'u' | 'U' | 'g' | 'G'
+                | 'i' | 'I',
+            ) if self.is_compact => {}
+
             KeyCode::Esc => {
                 if self.state == AppState::View {
+                    // dismiss the OOM kill and settings warning banner first if there is one
+                    if self.oom_kill_event.is_some() {
+                        self.oom_kill_event = None;
+                    } else if !self.config_warnings.is_empty() {
+                        self.config_warnings.clear();
                     // quit the ratatui terminal user interface
-                    if self.selected_container == SelectedContainer::None {
+                    } else if self.selected_container == SelectedContainer::None {
                         self.is_quit = true;
                     } else {
                         if self.container_full_screen {
@@ -504,8 +985,8 @@ impl App {
 
             KeyCode::Char('-') => {
                 if self.state == AppState::View {
-                    if self.tick > 100 {
-                        self.tick -= 100;
+                    if self.tick > MIN_TICK {
+                        self.tick = self.tick.saturating_sub(100).max(MIN_TICK);
                         self.tick_tx.send(self.tick).unwrap();
                         self.process_tick_tx.send(self.tick).unwrap();
                     }
@@ -513,8 +994,8 @@ impl App {
             }
             KeyCode::Char('+') => {
                 if self.state == AppState::View {
-                    if self.tick < 10000 {
-                        self.tick += 100;
+                    if self.tick < MAX_TICK {
+                        self.tick = (self.tick + 100).min(MAX_TICK);
                         self.tick_tx.send(self.tick).unwrap();
                         self.process_tick_tx.send(self.tick).unwrap();
                     }
@@ -529,7 +1010,7 @@ impl App {
                                 self.cpu_selected_state.select(Some(selected - 1));
                             } else {
                                 self.cpu_selected_state
-                                    .select(Some(self.sys_info.cpus.len() - 1))
+                                    .select(Some(self.get_shown_cpu_count() - 1))
                             }
                         }
                     } else if self.selected_container == SelectedContainer::Process {
@@ -540,6 +1021,25 @@ impl App {
                                 self.process_selected_state.select(None);
                             }
                         }
+                    } else if self.selected_container == SelectedContainer::Cgroup {
+                        if let Some(selected) = self.cgroup_selected_state.selected() {
+                            let selected = if selected > 0 {
+                                selected - 1
+                            } else {
+                                self.cgroup_current_list.len().saturating_sub(1)
+                            };
+                            self.cgroup_selected_state.select(Some(selected));
+                            self.cgroup_selected_path =
+                                self.cgroup_current_list.get(selected).cloned();
+                        }
+                    } else if self.selected_container == SelectedContainer::Interrupt {
+                        if let Some(selected) = self.interrupt_selected_state.selected() {
+                            if selected > 0 {
+                                self.interrupt_selected_state.select(Some(selected - 1));
+                            } else {
+                                self.interrupt_selected_state.select(None);
+                            }
+                        }
                     }
                 }
             }
@@ -547,7 +1047,7 @@ impl App {
                 if self.state == AppState::View {
                     if self.selected_container == SelectedContainer::Cpu {
                         if let Some(selected) = self.cpu_selected_state.selected() {
-                            if selected < self.sys_info.cpus.len().saturating_sub(1) {
+                            if selected < self.get_shown_cpu_count().saturating_sub(1) {
                                 self.cpu_selected_state.select(Some(selected + 1));
                             } else {
                                 self.cpu_selected_state.select(Some(0))
@@ -561,47 +1061,88 @@ impl App {
                         } else {
                             self.process_selected_state.select(Some(0))
                         }
+                    } else if self.selected_container == SelectedContainer::Cgroup {
+                        if let Some(selected) = self.cgroup_selected_state.selected() {
+                            let selected =
+                                if selected < self.cgroup_current_list.len().saturating_sub(1) {
+                                    selected + 1
+                                } else {
+                                    0
+                                };
+                            self.cgroup_selected_state.select(Some(selected));
+                            self.cgroup_selected_path =
+                                self.cgroup_current_list.get(selected).cloned();
+                        }
+                    } else if self.selected_container == SelectedContainer::Interrupt {
+                        if let Some(selected) = self.interrupt_selected_state.selected() {
+                            if selected < self.sys_info.interrupts.sources.len().saturating_sub(1) {
+                                self.interrupt_selected_state.select(Some(selected + 1));
+                            }
+                        } else {
+                            self.interrupt_selected_state.select(Some(0))
+                        }
                     }
                 }
             }
             KeyCode::Char('[') => {
                 if self.state == AppState::View {
                     if self.selected_container == SelectedContainer::Cpu {
-                        if self.cpu_graph_shown_range > 100 {
+                        if self.cpu_graph_shown_range > MIN_GRAPH_RANGE {
                             self.cpu_graph_shown_range -= 10;
                         }
                     } else if self.selected_container == SelectedContainer::Memory {
-                        if self.memory_graph_shown_range > 100 {
+                        if self.memory_graph_shown_range > MIN_GRAPH_RANGE {
                             self.memory_graph_shown_range -= 10;
                         }
                     } else if self.selected_container == SelectedContainer::Disk {
-                        if self.disk_graph_shown_range > 100 {
+                        if self.disk_graph_shown_range > MIN_GRAPH_RANGE {
                             self.disk_graph_shown_range -= 10;
                         }
                     } else if self.selected_container == SelectedContainer::Network {
-                        if self.network_graph_shown_range > 100 {
+                        if self.network_graph_shown_range > MIN_GRAPH_RANGE {
                             self.network_graph_shown_range -= 10;
                         }
                     } else if self.selected_container == SelectedContainer::Process {
-                        if self.process_graph_shown_range > 100 {
+                        if self.process_graph_shown_range > MIN_GRAPH_RANGE {
                             self.process_graph_shown_range -= 10;
                         }
+                    } else if self.selected_container == SelectedContainer::Battery {
+                        if self.battery_graph_shown_range > MIN_GRAPH_RANGE {
+                            self.battery_graph_shown_range -= 10;
+                        }
+                    } else if self.selected_container == SelectedContainer::Cgroup {
+                        if self.cgroup_graph_shown_range > MIN_GRAPH_RANGE {
+                            self.cgroup_graph_shown_range -= 10;
+                        }
+                    } else if self.selected_container == SelectedContainer::Interrupt {
+                        if self.interrupt_graph_shown_range > MIN_GRAPH_RANGE {
+                            self.interrupt_graph_shown_range -= 10;
+                        }
                     } else if self.selected_container == SelectedContainer::None {
-                        if self.cpu_graph_shown_range > 100 {
+                        if self.cpu_graph_shown_range > MIN_GRAPH_RANGE {
                             self.cpu_graph_shown_range -= 10;
                         }
-                        if self.memory_graph_shown_range > 100 {
+                        if self.memory_graph_shown_range > MIN_GRAPH_RANGE {
                             self.memory_graph_shown_range -= 10;
                         }
-                        if self.disk_graph_shown_range > 100 {
+                        if self.disk_graph_shown_range > MIN_GRAPH_RANGE {
                             self.disk_graph_shown_range -= 10;
                         }
-                        if self.network_graph_shown_range > 100 {
+                        if self.network_graph_shown_range > MIN_GRAPH_RANGE {
                             self.network_graph_shown_range -= 10;
                         }
-                        if self.process_graph_shown_range > 100 {
+                        if self.process_graph_shown_range > MIN_GRAPH_RANGE {
                             self.process_graph_shown_range -= 10;
                         }
+                        if self.battery_graph_shown_range > MIN_GRAPH_RANGE {
+                            self.battery_graph_shown_range -= 10;
+                        }
+                        if self.cgroup_graph_shown_range > MIN_GRAPH_RANGE {
+                            self.cgroup_graph_shown_range -= 10;
+                        }
+                        if self.interrupt_graph_shown_range > MIN_GRAPH_RANGE {
+                            self.interrupt_graph_shown_range -= 10;
+                        }
                     }
                 }
             }
@@ -609,41 +1150,62 @@ impl App {
             KeyCode::Char(']') => {
                 if self.state == AppState::View {
                     if self.selected_container == SelectedContainer::Cpu {
-                        if self.cpu_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.cpu_graph_shown_range < MAX_GRAPH_RANGE {
                             self.cpu_graph_shown_range += 10;
                         }
                     } else if self.selected_container == SelectedContainer::Memory {
-                        if self.memory_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.memory_graph_shown_range < MAX_GRAPH_RANGE {
                             self.memory_graph_shown_range += 10;
                         }
                     } else if self.selected_container == SelectedContainer::Disk {
-                        if self.disk_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.disk_graph_shown_range < MAX_GRAPH_RANGE {
                             self.disk_graph_shown_range += 10;
                         }
                     } else if self.selected_container == SelectedContainer::Network {
-                        if self.network_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.network_graph_shown_range < MAX_GRAPH_RANGE {
                             self.network_graph_shown_range += 10;
                         }
                     } else if self.selected_container == SelectedContainer::Process {
-                        if self.process_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.process_graph_shown_range < MAX_GRAPH_RANGE {
                             self.process_graph_shown_range += 10;
                         }
+                    } else if self.selected_container == SelectedContainer::Battery {
+                        if self.battery_graph_shown_range < MAX_GRAPH_RANGE {
+                            self.battery_graph_shown_range += 10;
+                        }
+                    } else if self.selected_container == SelectedContainer::Cgroup {
+                        if self.cgroup_graph_shown_range < MAX_GRAPH_RANGE {
+                            self.cgroup_graph_shown_range += 10;
+                        }
+                    } else if self.selected_container == SelectedContainer::Interrupt {
+                        if self.interrupt_graph_shown_range < MAX_GRAPH_RANGE {
+                            self.interrupt_graph_shown_range += 10;
+                        }
                     } else if self.selected_container == SelectedContainer::None {
-                        if self.cpu_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.cpu_graph_shown_range < MAX_GRAPH_RANGE {
                             self.cpu_graph_shown_range += 10;
                         }
-                        if self.memory_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.memory_graph_shown_range < MAX_GRAPH_RANGE {
                             self.memory_graph_shown_range += 10;
                         }
-                        if self.disk_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.disk_graph_shown_range < MAX_GRAPH_RANGE {
                             self.disk_graph_shown_range += 10;
                         }
-                        if self.network_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.network_graph_shown_range < MAX_GRAPH_RANGE {
                             self.network_graph_shown_range += 10;
                         }
-                        if self.process_graph_shown_range < MAX_GRAPH_SHOWN_RANGE {
+                        if self.process_graph_shown_range < MAX_GRAPH_RANGE {
                             self.process_graph_shown_range += 10;
                         }
+                        if self.battery_graph_shown_range < MAX_GRAPH_RANGE {
+                            self.battery_graph_shown_range += 10;
+                        }
+                        if self.cgroup_graph_shown_range < MAX_GRAPH_RANGE {
+                            self.cgroup_graph_shown_range += 10;
+                        }
+                        if self.interrupt_graph_shown_range < MAX_GRAPH_RANGE {
+                            self.interrupt_graph_shown_range += 10;
+                        }
                     }
                 }
             }
@@ -778,6 +1340,62 @@ impl App {
                 }
             }
 
+            // b and B for selecting the Battery Block ( only available on machines with battery )
+            // the battery was drawn within the network panel, so it can't be selected while the network panel was not shown
+            KeyCode::Char('b') | KeyCode::Char('B')
+                if self.state == AppState::View
+                    && !self.sys_info.batteries.is_empty()
+                    && get_layout_shown_panels(
+                        &self.layouts[self.layout_selected_entry],
+                        &self.panels,
+                    )
+                    .contains(&LayoutPanel::Network) =>
+            {
+                if self.selected_container != SelectedContainer::Battery {
+                    self.selected_container = SelectedContainer::Battery;
+                } else {
+                    self.container_full_screen = false;
+                    self.selected_container = SelectedContainer::None;
+                }
+            }
+
+            // u and U for selecting the Users panel ( this will be shown in full screen )
+            KeyCode::Char('u') | KeyCode::Char('U') if self.state == AppState::View => {
+                if self.selected_container != SelectedContainer::Session {
+                    self.selected_container = SelectedContainer::Session;
+                } else {
+                    self.selected_container = SelectedContainer::None;
+                }
+                self.container_full_screen = false;
+            }
+
+            // g and G for selecting the Cgroup panel ( this will be shown in full screen unless it was placed in the layout )
+            KeyCode::Char('g') | KeyCode::Char('G') if self.state == AppState::View => {
+                if self.selected_container != SelectedContainer::Cgroup {
+                    self.selected_container = SelectedContainer::Cgroup;
+                } else {
+                    self.selected_container = SelectedContainer::None;
+                }
+                self.container_full_screen = false;
+            }
+
+            // i and I for selecting the Interrupts panel ( this will be shown in full screen )
+            KeyCode::Char('i') | KeyCode::Char('I') if self.state == AppState::View => {
+                if self.selected_container != SelectedContainer::Interrupt {
+                    self.selected_container = SelectedContainer::Interrupt;
+                } else {
+                    self.selected_container = SelectedContainer::None;
+                }
+                self.container_full_screen = false;
+            }
+
+            // h and H for toggling between the usage relative to the cgroup limits and the host-wide usage
+            KeyCode::Char('h') | KeyCode::Char('H')
+                if self.state == AppState::View && self.sys_info.container_limit.is_some() =>
+            {
+                self.show_host_totals = !self.show_host_totals;
+            }
+
             KeyCode::Char('R') => {
                 if self.state == AppState::View {
                     if self.selected_container == SelectedContainer::Process {
@@ -802,6 +1420,32 @@ impl App {
                 }
             }
 
+            // o and O for toggling the container column in the process list
+            KeyCode::Char('o') | KeyCode::Char('O')
+                if self.state == AppState::View
+                    && self.selected_container == SelectedContainer::Process =>
+            {
+                self.process_show_container = !self.process_show_container;
+            }
+
+            KeyCode::Char('v') | KeyCode::Char('V')
+                if self.state == AppState::View
+                    && self.selected_container == SelectedContainer::Process =>
+            {
+                if self.process_quick_filter_selected_state
+                    == ProcessQuickFilter::total_selection_count() - 1
+                {
+                    self.process_quick_filter_selected_state = 0;
+                } else {
+                    self.process_quick_filter_selected_state += 1;
+                }
+                self.process_quick_filter = ProcessQuickFilter::get_process_quick_filter_from_int(
+                    self.process_quick_filter_selected_state,
+                );
+                // the selected process might not be within the filtered list
+                self.process_selected_state.select(None);
+            }
+
             KeyCode::Char('f') => {
                 if self.state == AppState::View {
                     self.state = AppState::Typing;
@@ -1037,9 +1681,90 @@ impl App {
                 }
             }
 
+            // a and A for adjusting the oom_score_adj of the process showing in the process detail
+            KeyCode::Char('a') | KeyCode::Char('A')
+                if self.state == AppState::View
+                    && self.selected_container == SelectedContainer::Process
+                    && self.process_show_details
+                    && self.process_selected_state.selected().is_none() =>
+            {
+                if let Some((key, value)) = self
+                    .current_showing_process_detail
+                    .as_ref()
+                    .and_then(|detail| detail.iter().next())
+                {
+                    // do nothing if the status is killed or oom_score_adj is not available
+                    if value.status == "killed" || value.oom_score_adj.is_none() {
+                        return;
+                    }
+                    self.current_process_oom_score_adj_state_data =
+                        Some(CurrentProcessOomScoreAdjStateData {
+                            pid: key.clone(),
+                            name: value.name.clone(),
+                            oom_score_adj: value.oom_score_adj.unwrap_or(0),
+                            error: None,
+                        });
+                    self.state = AppState::Popup;
+                    self.pop_up_type = AppPopUpType::OomScoreAdjust;
+                }
+            }
+
+            // 1 to 5 for hiding or showing the cpu, memory, disk, network and process container in the main view
+            KeyCode::Char(c @ '1'..='5') if self.state == AppState::View => {
+                let panel = match c {
+                    '1' => LayoutPanel::Cpu,
+                    '2' => LayoutPanel::Memory,
+                    '3' => LayoutPanel::Disk,
+                    '4' => LayoutPanel::Network,
+                    _ => LayoutPanel::Process,
+                };
+                self.toggle_panel(panel);
+            }
+
+            // l and L for cycling through the layouts of the main view
+            KeyCode::Char('l') | KeyCode::Char('L') if self.state == AppState::View => {
+                self.layout_selected_entry = (self.layout_selected_entry + 1) % self.layouts.len();
+                // the same as b, the battery can't stay selected when the network panel was not shown in the next layout
+                if self.selected_container == SelectedContainer::Battery
+                    && !self.container_full_screen
+                    && !get_layout_shown_panels(
+                        &self.layouts[self.layout_selected_entry],
+                        &self.panels,
+                    )
+                    .contains(&LayoutPanel::Network)
+                {
+                    self.selected_container = SelectedContainer::None;
+                }
+            }
+
+            // e and E for switching the theme, the whole dashboard will be previewed in the theme selected
+            KeyCode::Char('e') | KeyCode::Char('E') if self.state == AppState::View => {
+                let theme_names = get_theme_names();
+                let mut theme_selected_state = ListState::default();
+                theme_selected_state.select(Some(
+                    theme_names
+                        .iter()
+                        .position(|theme_name| *theme_name == self.theme)
+                        .unwrap_or(0),
+                ));
+                self.current_theme_selection_state_data = Some(CurrentThemeSelectionStateData {
+                    theme_names,
+                    theme_selected_state,
+                    original_app_color_info: self.app_color_info.clone(),
+                    warnings: vec![],
+                });
+                self.state = AppState::Popup;
+                self.pop_up_type = AppPopUpType::ThemeSelection;
+            }
+
             KeyCode::Left => {
                 if self.state == AppState::View {
-                    if self.selected_container == SelectedContainer::Disk {
+                    if self.selected_container == SelectedContainer::Memory {
+                        // only two views, so left and right both switch between them
+                        if !self.sys_info.numa_nodes.is_empty() {
+                            self.memory_show_numa = !self.memory_show_numa;
+                        }
+                    } else if self.selected_container == SelectedContainer::Disk {
                         if self.disk_selected_entry == 0 {
                             self.disk_selected_entry = self.sys_info.disks.len() - 1;
                         } else {
@@ -1051,6 +1776,13 @@ impl App {
                         } else {
                             self.network_selected_entry -= 1;
                         }
+                    } else if self.selected_container == SelectedContainer::Battery {
+                        if self.battery_selected_entry == 0 {
+                            self.battery_selected_entry =
+                                self.sys_info.batteries.len().saturating_sub(1);
+                        } else {
+                            self.battery_selected_entry -= 1;
+                        }
                     } else if self.selected_container == SelectedContainer::Process {
                         if self.process_sort_selected_state == 0 {
                             self.process_sort_selected_state =
@@ -1061,12 +1793,39 @@ impl App {
                         self.process_sort_type = ProcessSortType::get_process_sort_type_from_int(
                             self.process_sort_selected_state,
                         )
+                    } else if self.selected_container == SelectedContainer::Cgroup {
+                        if self.cgroup_sort_selected_state == 0 {
+                            self.cgroup_sort_selected_state =
+                                CgroupSortType::total_selection_count() - 1;
+                        } else {
+                            self.cgroup_sort_selected_state -= 1;
+                        }
+                        self.cgroup_sort_type = CgroupSortType::get_cgroup_sort_type_from_int(
+                            self.cgroup_sort_selected_state,
+                        )
+                    } else if self.selected_container == SelectedContainer::Interrupt {
+                        if self.interrupt_sort_selected_state == 0 {
+                            self.interrupt_sort_selected_state =
+                                InterruptSortType::total_selection_count(
+                                    self.sys_info.interrupts.cpu_count,
+                                ) - 1;
+                        } else {
+                            self.interrupt_sort_selected_state -= 1;
+                        }
+                        self.interrupt_sort_type =
+                            InterruptSortType::get_interrupt_sort_type_from_int(
+                                self.interrupt_sort_selected_state,
+                            )
                     }
                 }
             }
             KeyCode::Right => {
                 if self.state == AppState::View {
-                    if self.selected_container == SelectedContainer::Disk {
+                    if self.selected_container == SelectedContainer::Memory {
+                        if !self.sys_info.numa_nodes.is_empty() {
+                            self.memory_show_numa = !self.memory_show_numa;
+                        }
+                    } else if self.selected_container == SelectedContainer::Disk {
                         if self.disk_selected_entry == self.sys_info.disks.len() - 1 {
                             self.disk_selected_entry = 0
                         } else {
@@ -1078,6 +1837,14 @@ impl App {
                         } else {
                             self.network_selected_entry += 1;
                         }
+                    } else if self.selected_container == SelectedContainer::Battery {
+                        if self.battery_selected_entry
+                            >= self.sys_info.batteries.len().saturating_sub(1)
+                        {
+                            self.battery_selected_entry = 0;
+                        } else {
+                            self.battery_selected_entry += 1;
+                        }
                     } else if self.selected_container == SelectedContainer::Process {
                         if self.process_sort_selected_state
                             == ProcessSortType::total_selection_count() - 1
@@ -1089,6 +1856,31 @@ impl App {
                         self.process_sort_type = ProcessSortType::get_process_sort_type_from_int(
                             self.process_sort_selected_state,
                         )
+                    } else if self.selected_container == SelectedContainer::Cgroup {
+                        if self.cgroup_sort_selected_state
+                            == CgroupSortType::total_selection_count() - 1
+                        {
+                            self.cgroup_sort_selected_state = 0;
+                        } else {
+                            self.cgroup_sort_selected_state += 1;
+                        }
+                        self.cgroup_sort_type = CgroupSortType::get_cgroup_sort_type_from_int(
+                            self.cgroup_sort_selected_state,
+                        )
+                    } else if self.selected_container == SelectedContainer::Interrupt {
+                        if self.interrupt_sort_selected_state
+                            >= InterruptSortType::total_selection_count(
+                                self.sys_info.interrupts.cpu_count,
+                            ) - 1
+                        {
+                            self.interrupt_sort_selected_state = 0;
+                        } else {
+                            self.interrupt_sort_selected_state += 1;
+                        }
+                        self.interrupt_sort_type =
+                            InterruptSortType::get_interrupt_sort_type_from_int(
+                                self.interrupt_sort_selected_state,
+                            )
                     }
                 }
             }
@@ -1103,7 +1895,9 @@ impl App {
             KeyCode::Tab => {
                 if self.state == AppState::View {
                     // for a container to be full screen, it need to be selected first
-                    if self.container_full_screen
+                    // standalone containers are always full screen, so there is nothing to toggle
+                    if self.is_selected_container_standalone() {
+                    } else if self.container_full_screen
                         && self.selected_container != SelectedContainer::None
                     {
                         self.container_full_screen = false;
@@ -1126,12 +1920,16 @@ impl App {
                                 self.process_current_list[selected].clone(),
                             );
                             self.current_showing_process_detail = Some(selected_process);
+                            let _ = self
+                                .process_detail_tx
+                                .send(Some(self.process_current_list[selected].pid));
 
                             // unselect current selected process item list to enter the process detail container
                             self.process_selected_state.select(None);
                         } else {
                             self.process_show_details = false;
                             self.current_showing_process_detail = None;
+                            let _ = self.process_detail_tx.send(None);
                         }
                     }
                 }
@@ -1172,6 +1970,14 @@ impl App {
     }
 
     fn handle_pop_up_event(&mut self, key_event: KeyEvent) {
+        if self.pop_up_type == AppPopUpType::OomScoreAdjust {
+            self.handle_oom_score_adj_pop_up_event(key_event);
+            return;
+        }
+        if self.pop_up_type == AppPopUpType::ThemeSelection {
+            self.handle_theme_selection_pop_up_event(key_event);
+            return;
+        }
         match key_event.code {
             KeyCode::Esc => {
                 self.state = AppState::View;
@@ -1396,6 +2202,93 @@ impl App {
             _ => {}
         }
     }
+
+    fn handle_oom_score_adj_pop_up_event(&mut self, key_event: KeyEvent) {
+        let state_data = match self.current_process_oom_score_adj_state_data.as_mut() {
+            Some(state_data) => state_data,
+            None => return,
+        };
+        let step = match key_event.code {
+            KeyCode::Left => -1,
+            KeyCode::Right => 1,
+            KeyCode::Down => -100,
+            KeyCode::Up => 100,
+            _ => 0,
+        };
+        if step != 0 {
+            state_data.oom_score_adj = (state_data.oom_score_adj + step).clamp(-1000, 1000);
+            state_data.error = None;
+        }
+
+        match key_event.code {
+            KeyCode::Esc => {
+                self.state = AppState::View;
+                self.pop_up_type = AppPopUpType::None;
+                self.current_process_oom_score_adj_state_data = None;
+            }
+            KeyCode::Enter => {
+                // keep the pop up open with the reason when it failed
+                match set_oom_score_adj(&state_data.pid, state_data.oom_score_adj) {
+                    Ok(()) => {
+                        self.state = AppState::View;
+                        self.pop_up_type = AppPopUpType::None;
+                        self.current_process_oom_score_adj_state_data = None;
+                    }
+                    Err(e) => state_data.error = Some(e),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_theme_selection_pop_up_event(&mut self, key_event: KeyEvent) {
+        let state_data = match self.current_theme_selection_state_data.as_mut() {
+            Some(state_data) => state_data,
+            None => return,
+        };
+        let theme_count = state_data.theme_names.len();
+        let selected = state_data.theme_selected_state.selected().unwrap_or(0);
+
+        match key_event.code {
+            KeyCode::Up | KeyCode::Down => {
+                let selected = if key_event.code == KeyCode::Up {
+                    (selected + theme_count - 1) % theme_count
+                } else {
+                    (selected + 1) % theme_count
+                };
+                state_data.theme_selected_state.select(Some(selected));
+
+                // preview the theme on the whole dashboard
+                let (app_color_info, warnings) =
+                    get_and_return_app_color_info(&state_data.theme_names[selected]);
+                self.app_color_info = app_color_info;
+                state_data.warnings = warnings;
+            }
+            KeyCode::Esc => {
+                self.app_color_info = state_data.original_app_color_info.clone();
+                self.state = AppState::View;
+                self.pop_up_type = AppPopUpType::None;
+                self.current_theme_selection_state_data = None;
+            }
+            KeyCode::Enter => {
+                // the theme the pop-up was opened on was not previewed, so it was loaded again here
+                let theme = state_data.theme_names[selected].clone();
+                let (app_color_info, theme_warnings) = get_and_return_app_color_info(&theme);
+                self.app_color_info = app_color_info;
+                self.config_warnings.extend(
+                    set_theme(theme.clone())
+                        .into_iter()
+                        .map(|warning| format!("{} {}", get_config_display_path(), warning))
+                        .chain(theme_warnings),
+                );
+                self.theme = theme;
+                self.state = AppState::View;
+                self.pop_up_type = AppPopUpType::None;
+                self.current_theme_selection_state_data = None;
+            }
+            _ => {}
+        }
+    }
 }
 
 fn draw_not_renderable_message(frame: &mut Frame, app_color_info: &AppColorInfo) {
@@ -1418,7 +2311,7 @@ fn draw_not_renderable_message(frame: &mut Frame, app_color_info: &AppColorInfo)
             ),
             Span::styled(
                 format!(" {} ", width),
-                Style::default().fg(if width >= MIN_WIDTH {
+                Style::default().fg(if width >= COMPACT_MIN_WIDTH {
                     Color::Green
                 } else {
                     Color::Red
@@ -1430,7 +2323,7 @@ fn draw_not_renderable_message(frame: &mut Frame, app_color_info: &AppColorInfo)
             ),
             Span::styled(
                 format!(" {} ", height),
-                Style::default().fg(if height >= MIN_HEIGHT {
+                Style::default().fg(if height >= COMPACT_MIN_HEIGHT {
                     Color::Green
                 } else {
                     Color::Red
@@ -1439,8 +2332,11 @@ fn draw_not_renderable_message(frame: &mut Frame, app_color_info: &AppColorInfo)
         ]),
         Line::from(""),
         Line::from("Need Size for current config.").style(app_color_info.base_app_text_color),
-        Line::from(format!("Width = {} Height = {}  ", MIN_WIDTH, MIN_HEIGHT))
-            .style(app_color_info.base_app_text_color),
+        Line::from(format!(
+            "Width = {} Height = {}  ",
+            COMPACT_MIN_WIDTH, COMPACT_MIN_HEIGHT
+        ))
+        .style(app_color_info.base_app_text_color),
     ];
 
     let warning_paragraph = Paragraph::new(text_lines)
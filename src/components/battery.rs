@@ -0,0 +1,258 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Borders},
+    Frame,
+};
+
+use crate::{
+    types::{AppColorInfo, BatteryData},
+    utils::{format_seconds, get_tick_line_ui, render_history_graph},
+};
+
+// width smaller than this will be consider small width for the battery container
+const SMALL_WIDTH: u16 = 20;
+const GRAPH_PERCENTAGE: f64 = 100.0;
+
+pub fn draw_battery_info(
+    tick: u64,
+    battery_data: &BatteryData,
+    area: Rect,
+    frame: &mut Frame,
+    graph_show_range: usize,
+    is_selected: bool,
+    app_color_info: &AppColorInfo,
+    is_full_screen: bool,
+) {
+    let select_instruction = Line::from(vec![
+        Span::styled(" ", Style::default().fg(app_color_info.app_title_color)),
+        Span::styled("B", Style::default().fg(app_color_info.key_text_color))
+            .bold()
+            .underlined(),
+        Span::styled(
+            "attery ",
+            Style::default().fg(app_color_info.app_title_color),
+        )
+        .bold(),
+    ]);
+
+    let battery_switch_instruction = Line::from(vec![
+        Span::styled("  ", Style::default().fg(app_color_info.app_title_color)),
+        Span::styled("<", Style::default().fg(app_color_info.key_text_color)).bold(),
+        Span::styled(
+            format!(" {} ", battery_data.name),
+            Style::default().fg(app_color_info.app_title_color),
+        )
+        .bold(),
+        Span::styled(">", Style::default().fg(app_color_info.key_text_color)).bold(),
+        Span::styled("  ", Style::default().fg(app_color_info.app_title_color)),
+    ]);
+
+    let mut main_block = Block::bordered()
+        .title(select_instruction.left_aligned())
+        .title_bottom(battery_switch_instruction.centered())
+        .style(app_color_info.memory_main_block_color)
+        .border_set(border::ROUNDED);
+    if is_selected {
+        main_block = main_block
+            .style(app_color_info.memory_container_selected_color)
+            .border_set(border::DOUBLE);
+    }
+    if is_full_screen {
+        let refresh_tick = get_tick_line_ui(tick, app_color_info);
+
+        main_block = main_block.title(refresh_tick.right_aligned())
+    }
+
+    let [_, battery_block, _] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+    ])
+    .areas(area);
+
+    // padded the battery block for some space on the left and right
+    let [_, padded_battery_block, _] = Layout::horizontal([
+        Constraint::Length(2),
+        Constraint::Fill(1),
+        Constraint::Length(2),
+    ])
+    .areas(battery_block);
+
+    frame.render_widget(main_block, area);
+
+    // the battery block will be in the follwing order:
+    // status
+    // time to empty / full
+    // health
+    // charge [graph]
+    // power draw [graph]
+    let [status_layout, time_left_layout, health_layout, charge_layout, power_draw_layout] =
+        Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+        ])
+        .areas(padded_battery_block);
+
+    let border_type = if padded_battery_block.width < SMALL_WIDTH {
+        Borders::NONE
+    } else {
+        Borders::TOP
+    };
+
+    // ----------------------------------------
+    //
+    //          FOR BATTERY STATUS LAYOUT
+    //
+    // ----------------------------------------
+    let status_label = if status_layout.width < SMALL_WIDTH {
+        Line::from("S").style(app_color_info.base_app_text_color)
+    } else {
+        Line::from("Status:").style(app_color_info.base_app_text_color)
+    };
+    let status = Line::from(battery_data.status.clone())
+        .style(app_color_info.memory_text_color)
+        .bold();
+    let status_block = Block::new()
+        .title(status_label.left_aligned())
+        .title(status.right_aligned())
+        .style(app_color_info.memory_main_block_color)
+        .borders(Borders::NONE);
+
+    frame.render_widget(status_block, status_layout);
+
+    // ----------------------------------------
+    //
+    //        FOR TIME TO EMPTY/FULL LAYOUT
+    //
+    // ----------------------------------------
+    let (time_left_label, time_left) = match (battery_data.time_to_empty, battery_data.time_to_full)
+    {
+        (Some(time_to_empty), _) => ("To Empty:", format_seconds(time_to_empty)),
+        (None, Some(time_to_full)) => ("To Full:", format_seconds(time_to_full)),
+        (None, None) => ("Time Left:", "-".to_string()),
+    };
+    let time_left_label = if time_left_layout.width < SMALL_WIDTH {
+        Line::from("T").style(app_color_info.base_app_text_color)
+    } else {
+        Line::from(time_left_label).style(app_color_info.base_app_text_color)
+    };
+    let time_left = Line::from(time_left)
+        .style(app_color_info.memory_text_color)
+        .bold();
+    let time_left_block = Block::new()
+        .title(time_left_label.left_aligned())
+        .title(time_left.right_aligned())
+        .style(app_color_info.memory_main_block_color)
+        .borders(Borders::NONE);
+
+    frame.render_widget(time_left_block, time_left_layout);
+
+    // ----------------------------------------
+    //
+    //          FOR BATTERY HEALTH LAYOUT
+    //
+    // ----------------------------------------
+    let health_label = if health_layout.width < SMALL_WIDTH {
+        Line::from("H").style(app_color_info.base_app_text_color)
+    } else {
+        Line::from("Health:").style(app_color_info.base_app_text_color)
+    };
+    let health = Line::from(match battery_data.health {
+        Some(health) => format!("{:.1}%", health),
+        None => "-".to_string(),
+    })
+    .style(app_color_info.memory_text_color)
+    .bold();
+    let health_block = Block::new()
+        .title(health_label.left_aligned())
+        .title(health.right_aligned())
+        .style(app_color_info.memory_main_block_color)
+        .borders(Borders::NONE);
+
+    frame.render_widget(health_block, health_layout);
+
+    // ----------------------------------------
+    //
+    //         FOR BATTERY CHARGE LAYOUT
+    //
+    // ----------------------------------------
+    let [_, charge_graph] =
+        Layout::vertical([Constraint::Length(2), Constraint::Fill(1)]).areas(charge_layout);
+    let charge_label = if charge_layout.width < SMALL_WIDTH {
+        Line::from("C").style(app_color_info.base_app_text_color)
+    } else {
+        Line::from("Charge:").style(app_color_info.base_app_text_color)
+    };
+    let charge = Line::from(format!(
+        "{:.1}%",
+        battery_data.charge_vec.last().unwrap_or(&0.0)
+    ))
+    .style(app_color_info.memory_text_color)
+    .bold();
+    let charge_block = Block::new()
+        .title(charge_label.left_aligned())
+        .title(charge.right_aligned())
+        .style(app_color_info.memory_main_block_color)
+        .borders(border_type);
+
+    frame.render_widget(charge_block, charge_layout);
+    render_history_graph(
+        &battery_data.charge_vec,
+        GRAPH_PERCENTAGE,
+        graph_show_range,
+        app_color_info.available_memory_base_graph_color,
+        app_color_info,
+        charge_graph,
+        frame,
+    );
+
+    // ----------------------------------------
+    //
+    //          FOR POWER DRAW LAYOUT
+    //
+    // ----------------------------------------
+    let [_, power_draw_graph] =
+        Layout::vertical([Constraint::Length(2), Constraint::Fill(1)]).areas(power_draw_layout);
+    let power_draw_label = if power_draw_layout.width < SMALL_WIDTH {
+        Line::from("P").style(app_color_info.base_app_text_color)
+    } else {
+        Line::from("Power:").style(app_color_info.base_app_text_color)
+    };
+    let power_draw = Line::from(format!(
+        "{:.2} W",
+        battery_data.power_draw_vec.last().unwrap_or(&0.0)
+    ))
+    .style(app_color_info.memory_text_color)
+    .bold();
+    let power_draw_block = Block::new()
+        .title(power_draw_label.left_aligned())
+        .title(power_draw.right_aligned())
+        .style(app_color_info.memory_main_block_color)
+        .borders(border_type);
+
+    // the power draw graph will be scaled against the maximum power draw within the shown range
+    let start_idx = battery_data
+        .power_draw_vec
+        .len()
+        .saturating_sub(graph_show_range);
+    let current_max_power_draw = battery_data.power_draw_vec[start_idx..]
+        .iter()
+        .fold(0.0, |max: f64, power| max.max(*power));
+
+    frame.render_widget(power_draw_block, power_draw_layout);
+    render_history_graph(
+        &battery_data.power_draw_vec,
+        current_max_power_draw,
+        graph_show_range,
+        app_color_info.used_memory_base_graph_color,
+        app_color_info,
+        power_draw_graph,
+        frame,
+    );
+}
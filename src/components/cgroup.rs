@@ -0,0 +1,384 @@
+use std::collections::HashMap;
+
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::{
+    get_sys_info::get_sys_path,
+    types::{AppColorInfo, CgroupData, CgroupSortType},
+    utils::{
+        get_tick_line_ui, pad_or_truncate, process_to_kib_mib_gib, render_history_graph,
+        sort_cgroup_tree,
+    },
+};
+
+// width smaller than this will only show the name, cpu and memory column of the cgroup tree
+const MEDIUM_WIDTH: u16 = 60;
+
+pub fn draw_cgroup_info(
+    tick: u64,
+    cgroups: Option<&HashMap<String, CgroupData>>,
+    cgroup_current_list: &mut Vec<String>,
+    cgroup_selected_state: &mut ListState,
+    cgroup_selected_path: &mut Option<String>,
+    cgroup_sort_type: &CgroupSortType,
+    total_memory: f64,
+    area: Rect,
+    frame: &mut Frame,
+    graph_show_range: usize,
+    is_selected: bool,
+    app_color_info: &AppColorInfo,
+) {
+    let select_instruction = Line::from(vec![
+        Span::styled(" C", Style::default().fg(app_color_info.app_title_color)).bold(),
+        Span::styled("G", Style::default().fg(app_color_info.key_text_color))
+            .bold()
+            .underlined(),
+        Span::styled(
+            "roup ",
+            Style::default().fg(app_color_info.app_title_color).bold(),
+        ),
+    ]);
+
+    let cgroup_sort_select_instruction = Line::from(vec![
+        Span::styled("　< ", Style::default().fg(app_color_info.key_text_color)).bold(),
+        Span::styled(
+            cgroup_sort_type.get_sort_string_name(),
+            Style::default().fg(app_color_info.app_title_color).bold(),
+        ),
+        Span::styled(" >　", Style::default().fg(app_color_info.key_text_color)).bold(),
+    ]);
+
+    let cgroup_selection_instruction = Line::from(vec![
+        Span::styled(" ↑ ", Style::default().fg(app_color_info.key_text_color)).bold(),
+        Span::styled(
+            "select",
+            Style::default().fg(app_color_info.app_title_color),
+        )
+        .bold(),
+        Span::styled(" ↓ ", Style::default().fg(app_color_info.key_text_color)).bold(),
+    ]);
+
+    let refresh_tick = get_tick_line_ui(tick, app_color_info);
+
+    let mut main_block = Block::bordered()
+        .title(select_instruction.left_aligned())
+        .title(cgroup_sort_select_instruction.right_aligned())
+        .title(refresh_tick.right_aligned())
+        .title_bottom(cgroup_selection_instruction.left_aligned())
+        .style(app_color_info.process_main_block_color)
+        .border_set(border::ROUNDED);
+    if is_selected {
+        main_block = main_block
+            .style(app_color_info.process_container_selected_color)
+            .border_set(border::DOUBLE);
+    }
+
+    frame.render_widget(main_block, area);
+
+    // padded the inner container
+    let [_, padded_vertical_inner, _] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+    ])
+    .areas(area);
+
+    let [_, cgroup_block, _] = Layout::horizontal([
+        Constraint::Length(2),
+        Constraint::Fill(1),
+        Constraint::Length(2),
+    ])
+    .areas(padded_vertical_inner);
+
+    // the cgroup tree is only walked while the panel was shown, so there is nothing to show until the next refresh,
+    // otherwise cgroup v2 was not mounted ( or it was not a linux system )
+    let cgroups = match cgroups {
+        Some(cgroups) if !cgroups.is_empty() => cgroups,
+        _ => {
+            cgroup_current_list.clear();
+            let message = match cgroups {
+                None => "collecting the cgroup tree...".to_string(),
+                Some(_) => format!(
+                    "cgroup v2 hierarchy not found at {}",
+                    get_sys_path("fs/cgroup").display()
+                ),
+            };
+            let [_, message_layout, _] = Layout::vertical([
+                Constraint::Fill(1),
+                Constraint::Length(1),
+                Constraint::Fill(1),
+            ])
+            .areas(cgroup_block);
+            let message =
+                Paragraph::new(Line::from(message).style(app_color_info.base_app_text_color))
+                    .alignment(Alignment::Center);
+            frame.render_widget(message, message_layout);
+            return;
+        }
+    };
+
+    let [tree_layout, graph_layout] =
+        Layout::horizontal([Constraint::Fill(11), Constraint::Fill(9)]).areas(cgroup_block);
+
+    // ------------------------------------------------------------
+    // Render the cgroup tree on the left
+    // ------------------------------------------------------------
+    let [title_layout, list_layout] =
+        Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(tree_layout);
+
+    let mut io_width = 0;
+    let mut pids_width = 0;
+    let [name, cpu, memory] = Layout::horizontal([
+        Constraint::Fill(3),
+        Constraint::Fill(1),
+        Constraint::Fill(1),
+    ])
+    .areas(title_layout);
+    let mut name_width = name.width as usize;
+    let mut cpu_width = cpu.width as usize;
+    let mut memory_width = memory.width as usize;
+
+    if tree_layout.width > MEDIUM_WIDTH {
+        let [name, cpu, memory, io, pids] = Layout::horizontal([
+            Constraint::Fill(4),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(2),
+            Constraint::Fill(1),
+        ])
+        .areas(title_layout);
+        name_width = name.width as usize;
+        cpu_width = cpu.width as usize;
+        memory_width = memory.width as usize;
+        io_width = io.width as usize;
+        pids_width = pids.width as usize;
+    }
+
+    let title_style = Style::default()
+        .fg(app_color_info.process_title_color)
+        .bold();
+    let cgroup_title = Line::from(vec![
+        Span::styled(pad_or_truncate("Cgroup: ", name_width), title_style),
+        Span::styled(pad_or_truncate("Cpu%: ", cpu_width), title_style),
+        Span::styled(pad_or_truncate("Mem: ", memory_width), title_style),
+        Span::styled(pad_or_truncate("IO R/W: ", io_width), title_style),
+        Span::styled(pad_or_truncate("Pids: ", pids_width), title_style),
+    ]);
+    frame.render_widget(cgroup_title, title_layout);
+
+    let cgroup_tree = sort_cgroup_tree(cgroup_sort_type.clone(), cgroups);
+    *cgroup_current_list = cgroup_tree.iter().map(|(path, _)| path.clone()).collect();
+
+    let cgroup_list: Vec<ListItem> = cgroup_tree
+        .iter()
+        .filter_map(|(path, depth)| cgroups.get(path).map(|cgroup| (cgroup, depth)))
+        .map(|(cgroup, depth)| {
+            let name = format!("{}{}", "  ".repeat(*depth), cgroup.get_name());
+            let cpu_usage = format!("{:.2}%", cgroup.cpu_usage_vec.last().unwrap_or(&0.0));
+            let memory = process_to_kib_mib_gib(*cgroup.memory_current_vec.last().unwrap_or(&0.0));
+            let io = format!(
+                "{}/s / {}/s",
+                process_to_kib_mib_gib(*cgroup.io_read_vec.last().unwrap_or(&0.0)),
+                process_to_kib_mib_gib(*cgroup.io_write_vec.last().unwrap_or(&0.0))
+            );
+            let pids = cgroup.pids.to_string();
+
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    pad_or_truncate(&name, name_width),
+                    Style::default().fg(app_color_info.process_text_color),
+                ),
+                Span::styled(
+                    pad_or_truncate(&cpu_usage, cpu_width),
+                    Style::default().fg(app_color_info.base_app_text_color),
+                ),
+                Span::styled(
+                    pad_or_truncate(&memory, memory_width),
+                    Style::default().fg(app_color_info.process_text_color),
+                ),
+                Span::styled(
+                    pad_or_truncate(&io, io_width),
+                    Style::default().fg(app_color_info.base_app_text_color),
+                ),
+                Span::styled(
+                    pad_or_truncate(&pids, pids_width),
+                    Style::default().fg(app_color_info.base_app_text_color),
+                ),
+            ]))
+        })
+        .collect();
+
+    // follow the selected cgroup to where it was sorted to, or keep the selection within the list
+    // when it was removed between refresh ( eg. a stopped container scope )
+    match cgroup_selected_path.as_ref().and_then(|selected_path| {
+        cgroup_current_list
+            .iter()
+            .position(|path| path == selected_path)
+    }) {
+        Some(selected) => cgroup_selected_state.select(Some(selected)),
+        None => match cgroup_selected_state.selected() {
+            Some(selected) if selected >= cgroup_list.len() => {
+                cgroup_selected_state.select(Some(cgroup_list.len().saturating_sub(1)))
+            }
+            Some(_) => {}
+            None => cgroup_selected_state.select(Some(0)),
+        },
+    }
+    *cgroup_selected_path = cgroup_selected_state
+        .selected()
+        .and_then(|selected| cgroup_current_list.get(selected))
+        .cloned();
+
+    let cgroup_list = List::new(cgroup_list).highlight_style(
+        Style::default()
+            .bg(app_color_info.process_selected_color_bg)
+            .fg(app_color_info.process_selected_color_fg)
+            .bold()
+            .add_modifier(app_color_info.selected_modifier),
+    );
+    frame.render_stateful_widget(cgroup_list, list_layout, cgroup_selected_state);
+
+    // ------------------------------------------------------------
+    // Render the graphs of the selected cgroup on the right
+    // ------------------------------------------------------------
+    let selected_cgroup = cgroup_selected_state
+        .selected()
+        .and_then(|selected| cgroup_current_list.get(selected))
+        .and_then(|path| cgroups.get(path));
+    let selected_cgroup = match selected_cgroup {
+        Some(cgroup) => cgroup,
+        None => return,
+    };
+
+    let selected_cgroup_info_block = Block::new()
+        .borders(Borders::LEFT)
+        .title(
+            Line::from(format!(" {} ", selected_cgroup.path))
+                .style(app_color_info.app_title_color)
+                .bold()
+                .left_aligned(),
+        )
+        .title(
+            Line::from(format!(" Pids: {} ", selected_cgroup.pids))
+                .style(app_color_info.app_title_color)
+                .bold()
+                .right_aligned(),
+        )
+        .style(app_color_info.process_main_block_color);
+    frame.render_widget(selected_cgroup_info_block, graph_layout);
+
+    let [_, padded_graph_layout] =
+        Layout::horizontal([Constraint::Length(2), Constraint::Fill(1)]).areas(graph_layout);
+    let [_, padded_graph_layout] =
+        Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(padded_graph_layout);
+    let [cpu_graph_layout, memory_graph_layout, io_read_graph_layout, io_write_graph_layout] =
+        Layout::vertical([
+            Constraint::Ratio(1, 4),
+            Constraint::Ratio(1, 4),
+            Constraint::Ratio(1, 4),
+            Constraint::Ratio(1, 4),
+        ])
+        .areas(padded_graph_layout);
+
+    // the memory graph will be scaled against memory.max, or the total system memory when there is no limit
+    let memory_max = selected_cgroup.memory_max.unwrap_or(total_memory);
+    let memory_max_label = match selected_cgroup.memory_max {
+        Some(max) => process_to_kib_mib_gib(max),
+        None => "max".to_string(),
+    };
+
+    // io graphs will be scaled against the maximum value within the shown range, same as the network graph
+    let io_read_max = get_shown_range_max(&selected_cgroup.io_read_vec, graph_show_range);
+    let io_write_max = get_shown_range_max(&selected_cgroup.io_write_vec, graph_show_range);
+
+    let graphs = [
+        (
+            "CPU:",
+            format!(
+                "{:.2}%",
+                selected_cgroup.cpu_usage_vec.last().unwrap_or(&0.0)
+            ),
+            &selected_cgroup.cpu_usage_vec,
+            100.0,
+            app_color_info.cpu_base_graph_color,
+            cpu_graph_layout,
+        ),
+        (
+            "Memory:",
+            format!(
+                "{} / {}",
+                process_to_kib_mib_gib(*selected_cgroup.memory_current_vec.last().unwrap_or(&0.0)),
+                memory_max_label
+            ),
+            &selected_cgroup.memory_current_vec,
+            memory_max,
+            app_color_info.used_memory_base_graph_color,
+            memory_graph_layout,
+        ),
+        (
+            "Read:",
+            format!(
+                "{}/s",
+                process_to_kib_mib_gib(*selected_cgroup.io_read_vec.last().unwrap_or(&0.0))
+            ),
+            &selected_cgroup.io_read_vec,
+            io_read_max,
+            app_color_info.disk_bytes_read_base_graph_color,
+            io_read_graph_layout,
+        ),
+        (
+            "Write:",
+            format!(
+                "{}/s",
+                process_to_kib_mib_gib(*selected_cgroup.io_write_vec.last().unwrap_or(&0.0))
+            ),
+            &selected_cgroup.io_write_vec,
+            io_write_max,
+            app_color_info.disk_bytes_written_base_graph_color,
+            io_write_graph_layout,
+        ),
+    ];
+
+    for (label, value, history, max_value, color, layout) in graphs {
+        let [_, graph] =
+            Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(layout);
+        let graph_block = Block::new()
+            .title(
+                Line::from(label)
+                    .style(app_color_info.base_app_text_color)
+                    .left_aligned(),
+            )
+            .title(
+                Line::from(value)
+                    .style(app_color_info.memory_text_color)
+                    .bold()
+                    .right_aligned(),
+            )
+            .style(app_color_info.process_main_block_color)
+            .borders(Borders::TOP);
+        frame.render_widget(graph_block, layout);
+        render_history_graph(
+            history,
+            max_value,
+            graph_show_range,
+            color,
+            app_color_info,
+            graph,
+            frame,
+        );
+    }
+}
+
+fn get_shown_range_max(history: &[f64], graph_show_range: usize) -> f64 {
+    let start_idx = history.len().saturating_sub(graph_show_range);
+    history[start_idx..]
+        .iter()
+        .fold(0.0, |max, value| value.max(max))
+}
@@ -0,0 +1,372 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, LineGauge, List, ListItem},
+    Frame,
+};
+
+use crate::{
+    types::{AppColorInfo, CompactViewData},
+    utils::{get_tick_line_ui, pad_or_truncate, process_to_kib_mib_gib, sort_process},
+};
+
+// the process list will always keep at least this many rows, the network and disk lines will be dropped first
+const MIN_PROCESS_LIST_HEIGHT: u16 = 3;
+// the user column will only be shown from this width onwards
+const USER_COLUMN_WIDTH: u16 = 60;
+
+// the view for terminals that are too small for the main view, every panel was squeezed into a single line
+// except the process list, which takes the rest of the space. the process detail was squeezed into a single line as well,
+// above the process list
+//
+//   ╭ Compact ─────────── - 1000ms + ╮
+//   │ CPU  12.50% ━━━━───────────────│
+//   │ MEM  1.20 GiB ━━━━━────────────│
+//   │ NET  ▼ 1.00 KiB/s  ▲ 512.00 B/s│
+//   │ DSK  R 0.00 B/s  W 4.00 KiB/s  │
+//   │ ↵ 1 systemd  S  0.00%  12 M    │
+//   │ Pid:    Program:   Cpu%:  Mem: │
+//   │ 1       systemd    0.00%  12 M │
+//   ╰────────────────────────────────╯
+pub fn draw_compact_info(
+    compact_view_data: CompactViewData,
+    area: Rect,
+    frame: &mut Frame,
+    app_color_info: &AppColorInfo,
+) {
+    let CompactViewData {
+        tick,
+        cpus,
+        memory,
+        disk_data,
+        network_data,
+        process_data,
+        process_current_list,
+        process_selectable_entries,
+        process_selected_state,
+        process_sort_type,
+        process_sort_is_reversed,
+        process_filter,
+        is_filtering,
+        process_quick_filter,
+        process_pre_filter,
+        process_detail,
+        is_process_selected,
+        panels,
+    } = compact_view_data;
+    let process_filter_without_underscore_extension: String = process_filter
+        .chars()
+        .take(process_filter.chars().count().saturating_sub(1))
+        .collect();
+
+    let title = Line::from(vec![Span::styled(
+        " Compact ",
+        Style::default().fg(app_color_info.app_title_color).bold(),
+    )]);
+    let refresh_tick = get_tick_line_ui(tick, app_color_info);
+    let mut main_block = Block::bordered()
+        .title(title.left_aligned())
+        .title(refresh_tick.right_aligned())
+        .style(app_color_info.process_main_block_color)
+        .border_set(border::ROUNDED);
+    // the process list was the only one that can be selected
+    if is_process_selected {
+        main_block = main_block
+            .style(app_color_info.process_container_selected_color)
+            .border_set(border::DOUBLE);
+    }
+
+    // the sorting and the filter were shown at the bottom, as the top was taken by the refresh tick
+    if panels.process {
+        let sort_direction = if process_sort_is_reversed {
+            "▼"
+        } else {
+            "▲"
+        };
+        let mut bottom_title = vec![Span::styled(
+            format!(
+                " Sort: {} {} ",
+                process_sort_type.get_sort_string_name(),
+                sort_direction
+            ),
+            Style::default().fg(app_color_info.app_title_color).bold(),
+        )];
+        if is_filtering || !process_filter_without_underscore_extension.is_empty() {
+            bottom_title.push(Span::styled(
+                format!(
+                    " Filter: {}{} ",
+                    process_filter_without_underscore_extension,
+                    if is_filtering { "_" } else { "" }
+                ),
+                Style::default().fg(app_color_info.key_text_color).bold(),
+            ));
+        }
+        main_block = main_block.title_bottom(Line::from(bottom_title).right_aligned());
+    }
+
+    let inner_area = main_block.inner(area);
+    frame.render_widget(main_block, area);
+
+    let [_, padded_inner_area, _] = Layout::horizontal([
+        Constraint::Length(1),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+    ])
+    .areas(inner_area);
+
+    // the lines were dropped from the bottom up when there is not enough space for the process list
+    let process_detail = process_detail.filter(|_| panels.process);
+    let mut available_height = if panels.process {
+        padded_inner_area.height.saturating_sub(
+            MIN_PROCESS_LIST_HEIGHT + 1 + if process_detail.is_some() { 1 } else { 0 },
+        )
+    } else {
+        padded_inner_area.height
+    };
+    let mut is_line_shown = |is_panel_shown: bool| {
+        if is_panel_shown && available_height > 0 {
+            available_height -= 1;
+            true
+        } else {
+            false
+        }
+    };
+    let is_cpu_shown = is_line_shown(panels.cpu);
+    let is_memory_shown = is_line_shown(panels.memory);
+    let is_network_shown = is_line_shown(panels.network);
+    let is_disk_shown = is_line_shown(panels.disk);
+
+    let mut constraints = vec![];
+    for is_shown in [
+        is_cpu_shown,
+        is_memory_shown,
+        is_network_shown,
+        is_disk_shown,
+    ] {
+        if is_shown {
+            constraints.push(Constraint::Length(1));
+        }
+    }
+    constraints.push(Constraint::Fill(1));
+    let line_layouts = Layout::vertical(constraints).split(padded_inner_area);
+    let mut line_layouts = line_layouts.iter();
+
+    if is_cpu_shown {
+        // the average was kept along with the individual cpus
+        let average_usage = cpus
+            .iter()
+            .find(|cpu| cpu.id == "CPU-AVG")
+            .map(|cpu| cpu.usage)
+            .unwrap_or(0.0);
+        let cpu_gauge = LineGauge::default()
+            .label(Line::from(vec![
+                Span::styled("CPU  ", Style::default().fg(app_color_info.cpu_text_color)).bold(),
+                Span::styled(
+                    format!("{:>7.2}% ", average_usage),
+                    Style::default().fg(app_color_info.cpu_text_color),
+                ),
+            ]))
+            .filled_style(Style::default().fg(app_color_info.cpu_base_graph_color))
+            .unfilled_style(Style::default().fg(app_color_info.cpu_info_block_color))
+            .ratio((average_usage as f64 / 100.0).clamp(0.0, 1.0));
+        frame.render_widget(cpu_gauge, *line_layouts.next().unwrap());
+    }
+
+    if is_memory_shown {
+        let used_memory = memory.used_memory_vec.last().copied().unwrap_or(0.0);
+        let memory_gauge = LineGauge::default()
+            .label(Line::from(vec![
+                Span::styled(
+                    "MEM  ",
+                    Style::default().fg(app_color_info.memory_text_color),
+                )
+                .bold(),
+                Span::styled(
+                    format!("{:>8} ", process_to_kib_mib_gib(used_memory)),
+                    Style::default().fg(app_color_info.memory_text_color),
+                ),
+            ]))
+            .filled_style(Style::default().fg(app_color_info.used_memory_base_graph_color))
+            .unfilled_style(Style::default().fg(app_color_info.cpu_info_block_color))
+            .ratio(if memory.total_memory > 0.0 {
+                (used_memory / memory.total_memory).clamp(0.0, 1.0)
+            } else {
+                0.0
+            });
+        frame.render_widget(memory_gauge, *line_layouts.next().unwrap());
+    }
+
+    if is_network_shown {
+        let network_line = Line::from(vec![
+            Span::styled(
+                "NET  ",
+                Style::default().fg(app_color_info.network_text_color),
+            )
+            .bold(),
+            Span::styled(
+                format!(
+                    "▼ {}/s  ▲ {}/s",
+                    process_to_kib_mib_gib(
+                        network_data
+                            .current_received_vec
+                            .last()
+                            .copied()
+                            .unwrap_or(0.0)
+                    ),
+                    process_to_kib_mib_gib(
+                        network_data
+                            .current_transmitted_vec
+                            .last()
+                            .copied()
+                            .unwrap_or(0.0)
+                    )
+                ),
+                Style::default().fg(app_color_info.network_text_color),
+            ),
+        ]);
+        frame.render_widget(network_line, *line_layouts.next().unwrap());
+    }
+
+    if is_disk_shown {
+        let disk_line = Line::from(vec![
+            Span::styled("DSK  ", Style::default().fg(app_color_info.disk_text_color)).bold(),
+            Span::styled(
+                format!(
+                    "R {}/s  W {}/s",
+                    process_to_kib_mib_gib(disk_data.bytes_read_vec.last().copied().unwrap_or(0.0)),
+                    process_to_kib_mib_gib(
+                        disk_data.bytes_written_vec.last().copied().unwrap_or(0.0)
+                    )
+                ),
+                Style::default().fg(app_color_info.disk_text_color),
+            ),
+        ]);
+        frame.render_widget(disk_line, *line_layouts.next().unwrap());
+    }
+
+    if !panels.process {
+        return;
+    }
+
+    // ------------------------------------------------------------
+    // Render the process list with the rest of the space
+    // ------------------------------------------------------------
+    let [process_detail_layout, process_title_layout, process_list_layout] = Layout::vertical([
+        Constraint::Length(if process_detail.is_some() { 1 } else { 0 }),
+        Constraint::Length(1),
+        Constraint::Fill(1),
+    ])
+    .areas(*line_layouts.next().unwrap());
+
+    if let Some(process_detail) = process_detail {
+        let detail_line = Line::from(vec![
+            Span::styled(
+                format!("↵ {} {}  ", process_detail.pid, process_detail.name),
+                Style::default().fg(app_color_info.key_text_color).bold(),
+            ),
+            Span::styled(
+                format!(
+                    "{}  {:.2}%  {}  {}",
+                    process_detail.status,
+                    process_detail.cpu_usage.last().copied().unwrap_or(0.0),
+                    process_to_kib_mib_gib(process_detail.memory.last().copied().unwrap_or(0.0)),
+                    process_detail.cmd.join(" ")
+                ),
+                Style::default().fg(app_color_info.process_text_color),
+            ),
+        ]);
+        frame.render_widget(detail_line, process_detail_layout);
+    }
+
+    let is_user_shown = area.width >= USER_COLUMN_WIDTH;
+    let pid_width = 8;
+    let cpu_usage_width = 8;
+    let memory_width = 11;
+    let user_width = if is_user_shown { 10 } else { 0 };
+    let program_width = (process_title_layout.width as usize)
+        .saturating_sub(pid_width + cpu_usage_width + memory_width + user_width);
+
+    let title_style = Style::default()
+        .fg(app_color_info.process_title_color)
+        .bold();
+    let mut process_title = vec![
+        Span::styled(pad_or_truncate("Pid:", pid_width), title_style),
+        Span::styled(pad_or_truncate("Program:", program_width), title_style),
+        Span::styled(pad_or_truncate("Cpu%:", cpu_usage_width), title_style),
+        Span::styled(pad_or_truncate("Mem:", memory_width), title_style),
+    ];
+    if is_user_shown {
+        process_title.insert(
+            2,
+            Span::styled(pad_or_truncate("User:", user_width), title_style),
+        );
+    }
+    frame.render_widget(Line::from(process_title), process_title_layout);
+
+    let sorted_process = sort_process(
+        process_sort_type.clone(),
+        process_sort_is_reversed,
+        process_filter_without_underscore_extension,
+        process_quick_filter,
+        process_pre_filter,
+        process_data,
+    );
+
+    let process_list: Vec<ListItem> = sorted_process
+        .iter()
+        .map(|value| {
+            let mut process_line = vec![
+                Span::styled(
+                    pad_or_truncate(&value.pid.to_string(), pid_width),
+                    Style::default().fg(app_color_info.base_app_text_color),
+                ),
+                Span::styled(
+                    pad_or_truncate(&value.name, program_width),
+                    Style::default().fg(app_color_info.process_text_color),
+                ),
+                Span::styled(
+                    pad_or_truncate(
+                        &format!("{:.2}%", value.cpu_usage.last().copied().unwrap_or(0.0)),
+                        cpu_usage_width,
+                    ),
+                    Style::default().fg(app_color_info.base_app_text_color),
+                ),
+                Span::styled(
+                    pad_or_truncate(
+                        &process_to_kib_mib_gib(value.memory.last().copied().unwrap_or(0.0)),
+                        memory_width,
+                    ),
+                    Style::default().fg(app_color_info.process_text_color),
+                ),
+            ];
+            if is_user_shown {
+                process_line.insert(
+                    2,
+                    Span::styled(
+                        pad_or_truncate(&value.user, user_width),
+                        Style::default().fg(app_color_info.base_app_text_color),
+                    ),
+                );
+            }
+            ListItem::new(Line::from(process_line))
+        })
+        .collect();
+
+    *process_selectable_entries = process_list.len();
+    *process_current_list = sorted_process;
+
+    let process_info_list = List::new(process_list).highlight_style(
+        Style::default()
+            .bg(app_color_info.process_selected_color_bg)
+            .fg(app_color_info.process_selected_color_fg)
+            .bold()
+            .add_modifier(app_color_info.selected_modifier),
+    );
+    frame.render_stateful_widget(
+        process_info_list,
+        process_list_layout,
+        process_selected_state,
+    );
+}
@@ -1,16 +1,18 @@
+use std::collections::HashMap;
+
 use chrono::Local;
 use ratatui::{
     layout::{Constraint, Layout, Rect},
     style::{Style, Stylize},
     symbols::{border, Marker},
     text::{Line, Span},
-    widgets::{Axis, Block, Chart, Dataset, GraphType, List, ListItem, ListState},
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, List, ListItem, ListState},
     Frame,
 };
 
 use crate::{
-    types::{AppColorInfo, CpuData},
-    utils::get_tick_line_ui,
+    types::{AppColorInfo, ContainerLimitData, CpuData, PowerZoneData},
+    utils::{get_tick_line_ui, process_to_kib_mib_gib, render_history_graph},
 };
 
 pub fn draw_cpu_info(
@@ -22,6 +24,9 @@ pub fn draw_cpu_info(
     graph_show_range: usize,
     is_selected: bool,
     app_color_info: &AppColorInfo,
+    container_limit: Option<&ContainerLimitData>,
+    show_host_totals: bool,
+    power_zones: &HashMap<String, PowerZoneData>,
 ) {
     let local_time = Local::now();
 
@@ -54,6 +59,45 @@ pub fn draw_cpu_info(
             .border_set(border::DOUBLE);
     }
 
+    // indicate that the usage shown was relative to the cgroup limits rtop was running under
+    if let Some(limit) = container_limit {
+        let mut limits = vec![];
+        if limit.cpu_limit.is_some() || !limit.cpuset.is_empty() {
+            limits.push(format!(
+                "{:.1} CPUs",
+                limit.get_effective_cpus(cpus.len().saturating_sub(1))
+            ));
+        }
+        if let Some(memory_max) = limit.memory_max {
+            limits.push(process_to_kib_mib_gib(memory_max));
+        }
+        let limit_text = if show_host_totals {
+            " Limit ignored, ".to_string()
+        } else {
+            format!(" Limit {} · ", limits.join(" / "))
+        };
+        let limit_instruction = Line::from(vec![
+            Span::styled(
+                limit_text,
+                Style::default().fg(app_color_info.app_title_color),
+            )
+            .bold(),
+            Span::styled("H", Style::default().fg(app_color_info.key_text_color))
+                .bold()
+                .underlined(),
+            Span::styled(
+                if show_host_totals {
+                    "ost-wide "
+                } else {
+                    "ost "
+                },
+                Style::default().fg(app_color_info.app_title_color),
+            )
+            .bold(),
+        ]);
+        main_block = main_block.title_bottom(limit_instruction.centered());
+    }
+
     // Constrain the block to have space at the right and left
     let [_, cpu_block, _] = Layout::horizontal([
         Constraint::Length(2),
@@ -205,11 +249,101 @@ pub fn draw_cpu_info(
 
     // Render the main cpu block container
     frame.render_widget(main_block, size);
-    // Render the chart in the left area
-    frame.render_widget(chart, constraint_inner_cpu_graph_layout);
+    // Render the chart in the left area, the bottom part will be used for the power draw graph when RAPL was available
+    if power_zones.is_empty() {
+        frame.render_widget(chart, constraint_inner_cpu_graph_layout);
+    } else {
+        let [cpu_usage_graph_layout, power_graph_layout] =
+            Layout::vertical([Constraint::Fill(3), Constraint::Fill(1)])
+                .areas(constraint_inner_cpu_graph_layout);
+        frame.render_widget(chart, cpu_usage_graph_layout);
+        draw_power_draw_graph(
+            power_zones,
+            power_graph_layout,
+            frame,
+            graph_show_range,
+            app_color_info,
+        );
+    }
     // Render the combined list with state
     frame.render_stateful_widget(cpu_info_list, cpu_info_inner_container, cpu_selected_state);
 
     drop(data_points);
     drop(cpu_usage_history);
 }
+
+// power draw of each RAPL zone in the title, with the total power draw of the packages graphed
+fn draw_power_draw_graph(
+    power_zones: &HashMap<String, PowerZoneData>,
+    area: Rect,
+    frame: &mut Frame,
+    graph_show_range: usize,
+    app_color_info: &AppColorInfo,
+) {
+    let mut zones: Vec<(&String, &PowerZoneData)> = power_zones.iter().collect();
+    zones.sort_by(|a, b| a.0.cmp(b.0));
+
+    let power_draw = zones
+        .iter()
+        .map(|(_, zone)| {
+            format!(
+                "{} {:.1}W",
+                zone.name,
+                zone.power_vec.last().unwrap_or(&0.0)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("  ");
+
+    // the packages already include the core, uncore and dram domains, only sum up the other zones without any package
+    let has_package = zones.iter().any(|(_, zone)| zone.is_package());
+    let graphed_zones: Vec<&PowerZoneData> = zones
+        .iter()
+        .filter(|(_, zone)| !has_package || zone.is_package())
+        .map(|(_, zone)| *zone)
+        .collect();
+    let history_len = graphed_zones
+        .iter()
+        .map(|zone| zone.power_vec.len())
+        .max()
+        .unwrap_or(0);
+    let total_power_history: Vec<f64> = (0..history_len)
+        .map(|i| {
+            graphed_zones
+                .iter()
+                .filter_map(|zone| {
+                    (zone.power_vec.len() + i)
+                        .checked_sub(history_len)
+                        .and_then(|index| zone.power_vec.get(index))
+                })
+                .sum()
+        })
+        .collect();
+    let start_idx = total_power_history.len().saturating_sub(graph_show_range);
+    let current_max_power = total_power_history[start_idx..]
+        .iter()
+        .fold(0.0, |max: f64, power| max.max(*power));
+
+    let power_label = Line::from("Power:").style(app_color_info.base_app_text_color);
+    let power_draw = Line::from(power_draw)
+        .style(app_color_info.cpu_text_color)
+        .bold();
+    let power_block = Block::new()
+        .title(power_label.left_aligned())
+        .title(power_draw.right_aligned())
+        .style(app_color_info.cpu_main_block_color)
+        .borders(Borders::TOP);
+
+    let [_, power_graph] =
+        Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(area);
+    frame.render_widget(power_block, area);
+    render_history_graph(
+        &total_power_history,
+        current_max_power,
+        graph_show_range,
+        app_color_info.cpu_base_graph_color,
+        app_color_info,
+        power_graph,
+        frame,
+    );
+}
@@ -0,0 +1,253 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::{
+    types::{AppColorInfo, InterruptData, InterruptSortType},
+    utils::{get_tick_line_ui, pad_or_truncate, render_history_graph, sort_interrupt_sources},
+};
+
+const SOURCE_COLUMN_WIDTH: usize = 10;
+const RATE_COLUMN_WIDTH: usize = 9;
+// minimum width left for the description column before we stop adding more cpu columns
+const MIN_DESCRIPTION_WIDTH: usize = 16;
+
+pub fn draw_interrupt_info(
+    tick: u64,
+    interrupts: &InterruptData,
+    interrupt_selected_state: &mut ListState,
+    interrupt_sort_type: &InterruptSortType,
+    area: Rect,
+    frame: &mut Frame,
+    graph_show_range: usize,
+    is_selected: bool,
+    app_color_info: &AppColorInfo,
+) {
+    let select_instruction = Line::from(vec![
+        Span::styled(" ", Style::default().fg(app_color_info.app_title_color)),
+        Span::styled("I", Style::default().fg(app_color_info.key_text_color))
+            .bold()
+            .underlined(),
+        Span::styled(
+            "nterrupts ",
+            Style::default().fg(app_color_info.app_title_color),
+        )
+        .bold(),
+    ]);
+
+    let interrupt_sort_select_instruction = Line::from(vec![
+        Span::styled("　< ", Style::default().fg(app_color_info.key_text_color)).bold(),
+        Span::styled(
+            interrupt_sort_type.get_sort_string_name(),
+            Style::default().fg(app_color_info.app_title_color).bold(),
+        ),
+        Span::styled(" >　", Style::default().fg(app_color_info.key_text_color)).bold(),
+    ]);
+
+    let refresh_tick = get_tick_line_ui(tick, app_color_info);
+
+    let mut main_block = Block::bordered()
+        .title(select_instruction.left_aligned())
+        .title(interrupt_sort_select_instruction.right_aligned())
+        .title(refresh_tick.right_aligned())
+        .style(app_color_info.cpu_main_block_color)
+        .border_set(border::ROUNDED);
+    if is_selected {
+        main_block = main_block
+            .style(app_color_info.cpu_container_selected_color)
+            .border_set(border::DOUBLE);
+    }
+
+    frame.render_widget(main_block, area);
+
+    // padded the inner container
+    let [_, padded_vertical_inner, _] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+    ])
+    .areas(area);
+
+    let [_, interrupt_block, _] = Layout::horizontal([
+        Constraint::Length(2),
+        Constraint::Fill(1),
+        Constraint::Length(2),
+    ])
+    .areas(padded_vertical_inner);
+
+    // /proc/interrupts was not available ( or it was not a linux system )
+    if interrupts.sources.is_empty() {
+        let [_, message_layout, _] = Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(1),
+            Constraint::Fill(1),
+        ])
+        .areas(interrupt_block);
+        let message = Paragraph::new(
+            Line::from("Interrupt statistics are not available on this system")
+                .style(app_color_info.base_app_text_color),
+        )
+        .alignment(Alignment::Center);
+        frame.render_widget(message, message_layout);
+        return;
+    }
+
+    let [table_layout, graph_layout] =
+        Layout::horizontal([Constraint::Fill(3), Constraint::Fill(2)]).areas(interrupt_block);
+
+    // ------------------------------------------------------------
+    // Render the interrupt sources table on the left
+    // ------------------------------------------------------------
+    let [title_layout, list_layout] =
+        Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(table_layout);
+
+    // show as many cpu columns as the width allows, the rest of the width goes to the description
+    let table_width = table_layout.width as usize;
+    let shown_cpu_count = (table_width
+        .saturating_sub(SOURCE_COLUMN_WIDTH + RATE_COLUMN_WIDTH + MIN_DESCRIPTION_WIDTH)
+        / RATE_COLUMN_WIDTH)
+        .min(interrupts.cpu_count);
+    let description_width =
+        table_width.saturating_sub(SOURCE_COLUMN_WIDTH + RATE_COLUMN_WIDTH * (shown_cpu_count + 1));
+
+    let title_style = Style::default().fg(app_color_info.cpu_text_color).bold();
+    let mut interrupt_title = vec![
+        Span::styled(pad_or_truncate("Source:", SOURCE_COLUMN_WIDTH), title_style),
+        Span::styled(pad_or_truncate("Total/s:", RATE_COLUMN_WIDTH), title_style),
+    ];
+    for cpu in 0..shown_cpu_count {
+        interrupt_title.push(Span::styled(
+            pad_or_truncate(&format!("CPU{}:", cpu), RATE_COLUMN_WIDTH),
+            title_style,
+        ));
+    }
+    interrupt_title.push(Span::styled(
+        pad_or_truncate("Description:", description_width),
+        title_style,
+    ));
+    frame.render_widget(Line::from(interrupt_title), title_layout);
+
+    let sources = sort_interrupt_sources(interrupt_sort_type, &interrupts.sources);
+    let interrupt_list: Vec<ListItem> = sources
+        .iter()
+        .map(|source| {
+            let mut row = vec![
+                Span::styled(
+                    pad_or_truncate(&source.id, SOURCE_COLUMN_WIDTH),
+                    Style::default().fg(app_color_info.base_app_text_color),
+                ),
+                Span::styled(
+                    pad_or_truncate(&format_rate(source.total_rate), RATE_COLUMN_WIDTH),
+                    Style::default().fg(app_color_info.cpu_text_color),
+                ),
+            ];
+            for cpu in 0..shown_cpu_count {
+                let cpu_rate = source.cpu_rate.get(cpu).copied();
+                row.push(Span::styled(
+                    pad_or_truncate(
+                        &cpu_rate.map(format_rate).unwrap_or("-".to_string()),
+                        RATE_COLUMN_WIDTH,
+                    ),
+                    Style::default().fg(app_color_info.base_app_text_color),
+                ));
+            }
+            row.push(Span::styled(
+                pad_or_truncate(&source.description, description_width),
+                Style::default().fg(app_color_info.base_app_text_color),
+            ));
+            ListItem::new(Line::from(row))
+        })
+        .collect();
+
+    // keep the selection within the list as interrupt sources can come and go between refresh
+    if let Some(selected) = interrupt_selected_state.selected() {
+        if selected >= interrupt_list.len() {
+            interrupt_selected_state.select(Some(interrupt_list.len().saturating_sub(1)));
+        }
+    }
+
+    let interrupt_list = List::new(interrupt_list).highlight_style(
+        Style::default()
+            .fg(app_color_info.cpu_selected_color)
+            .bold(),
+    );
+    frame.render_stateful_widget(interrupt_list, list_layout, interrupt_selected_state);
+
+    // ------------------------------------------------------------
+    // Render the context switches, interrupts and forks graphs on the right
+    // ------------------------------------------------------------
+    let graph_info_block = Block::new()
+        .borders(Borders::LEFT)
+        .style(app_color_info.cpu_main_block_color);
+    frame.render_widget(graph_info_block, graph_layout);
+
+    let [_, padded_graph_layout] =
+        Layout::horizontal([Constraint::Length(2), Constraint::Fill(1)]).areas(graph_layout);
+    let [context_switch_layout, interrupt_layout, fork_layout] = Layout::vertical([
+        Constraint::Ratio(1, 3),
+        Constraint::Ratio(1, 3),
+        Constraint::Ratio(1, 3),
+    ])
+    .areas(padded_graph_layout);
+
+    let graphs = [
+        (
+            "Context Switches/s:",
+            &interrupts.context_switch_vec,
+            context_switch_layout,
+        ),
+        ("Interrupts/s:", &interrupts.interrupt_vec, interrupt_layout),
+        ("Forks/s:", &interrupts.fork_vec, fork_layout),
+    ];
+
+    for (label, history, layout) in graphs {
+        let [_, graph] =
+            Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(layout);
+        let graph_block = Block::new()
+            .title(
+                Line::from(label)
+                    .style(app_color_info.base_app_text_color)
+                    .left_aligned(),
+            )
+            .title(
+                Line::from(format_rate(*history.last().unwrap_or(&0.0)))
+                    .style(app_color_info.cpu_text_color)
+                    .bold()
+                    .right_aligned(),
+            )
+            .style(app_color_info.cpu_main_block_color)
+            .borders(Borders::TOP);
+        frame.render_widget(graph_block, layout);
+
+        // scaled against the maximum rate within the shown range
+        let start_idx = history.len().saturating_sub(graph_show_range);
+        let current_max_rate = history[start_idx..]
+            .iter()
+            .fold(0.0, |max: f64, rate| max.max(*rate));
+        render_history_graph(
+            history,
+            current_max_rate,
+            graph_show_range,
+            app_color_info.cpu_base_graph_color,
+            app_color_info,
+            graph,
+            frame,
+        );
+    }
+}
+
+// rates above 10k will be shortened, eg. 12345.0 -> "12.3k"
+fn format_rate(rate: f64) -> String {
+    if rate >= 10_000_000.0 {
+        format!("{:.1}M", rate / 1_000_000.0)
+    } else if rate >= 10_000.0 {
+        format!("{:.1}k", rate / 1_000.0)
+    } else {
+        format!("{:.0}", rate)
+    }
+}
@@ -9,7 +9,7 @@ use ratatui::{
 
 use crate::{
     types::{AppColorInfo, MemoryData},
-    utils::{get_tick_line_ui, process_to_kib_mib_gib},
+    utils::{get_tick_line_ui, process_to_kib_mib_gib, render_history_graph},
 };
 
 // width smaller than this will be consider small width for the memory container
@@ -21,12 +21,14 @@ const GRAPH_PERCENTAGE: f64 = 100.0;
 pub fn draw_memory_info(
     tick: u64,
     memory: &MemoryData,
+    host_memory: &MemoryData, // the virtual memory activity is only available host-wide, even when memory was limited by cgroup
     area: Rect,
     frame: &mut Frame,
     graph_show_range: usize,
     is_selected: bool,
     app_color_info: &AppColorInfo,
     is_full_screen: bool,
+    is_numa_available: bool, // the usage and NUMA view can only be switched when there are NUMA nodes
 ) {
     let select_instruction = Line::from(vec![
         Span::styled(" ", Style::default().fg(app_color_info.app_title_color)),
@@ -49,6 +51,19 @@ pub fn draw_memory_info(
             .border_set(border::DOUBLE);
     }
 
+    if is_numa_available {
+        let view_select_instruction = Line::from(vec![
+            Span::styled("　< ", Style::default().fg(app_color_info.key_text_color)).bold(),
+            Span::styled(
+                "Usage",
+                Style::default().fg(app_color_info.app_title_color).bold(),
+            ),
+            Span::styled(" >　", Style::default().fg(app_color_info.key_text_color)).bold(),
+        ]);
+
+        main_block = main_block.title(view_select_instruction.right_aligned());
+    }
+
     if is_full_screen {
         let refresh_tick = get_tick_line_ui(tick, app_color_info);
 
@@ -71,6 +86,19 @@ pub fn draw_memory_info(
     ])
     .areas(memory_block);
 
+    // the virtual memory activity will only be shown on the right in the full screen view
+    let (padded_memory_block, vm_activity_block) = if is_full_screen {
+        let [memory_usage_block, _, vm_activity_block] = Layout::horizontal([
+            Constraint::Fill(3),
+            Constraint::Length(2),
+            Constraint::Fill(2),
+        ])
+        .areas(padded_memory_block);
+        (memory_usage_block, Some(vm_activity_block))
+    } else {
+        (padded_memory_block, None)
+    };
+
     // top label will be the label for total memory
     // bottom graph will be the statistics for memory usage like used, free, available memory, etc
     let [top_label, bottom_graphs] =
@@ -502,4 +530,156 @@ pub fn draw_memory_info(
         drop(cached_memory_history);
         drop(cached_memory_data_points);
     }
+
+    if let Some(vm_activity_block) = vm_activity_block {
+        draw_vm_activity(
+            host_memory,
+            vm_activity_block,
+            frame,
+            graph_show_range,
+            app_color_info,
+        );
+    }
+}
+
+// paging, swapping and page faults since the previous tick, from /proc/vmstat
+fn draw_vm_activity(
+    memory: &MemoryData,
+    area: Rect,
+    frame: &mut Frame,
+    graph_show_range: usize,
+    app_color_info: &AppColorInfo,
+) {
+    let [oom_kill_label, paging_layout, swapping_layout, page_fault_layout] = Layout::vertical([
+        Constraint::Length(2),
+        Constraint::Ratio(1, 3),
+        Constraint::Ratio(1, 3),
+        Constraint::Ratio(1, 3),
+    ])
+    .areas(area);
+
+    // oom kills are rare, so the total within the shown range is more useful than a graph
+    let start_idx = memory.oom_kill_vec.len().saturating_sub(graph_show_range);
+    let oom_kills: f64 = memory.oom_kill_vec[start_idx..].iter().sum();
+    let oom_kill_block = Block::new()
+        .title(
+            Line::from("OOM Kills:")
+                .style(app_color_info.app_title_color)
+                .bold()
+                .left_aligned(),
+        )
+        .title(
+            Line::from(if memory.oom_kill_vec.is_empty() {
+                "-".to_string()
+            } else {
+                format!("{:.0}", oom_kills)
+            })
+            .style(app_color_info.app_title_color)
+            .bold()
+            .right_aligned(),
+        )
+        .style(app_color_info.memory_main_block_color)
+        .borders(Borders::NONE);
+    frame.render_widget(oom_kill_block, oom_kill_label);
+
+    let [page_in_layout, page_out_layout] =
+        Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).areas(paging_layout);
+    let [swap_in_layout, swap_out_layout] =
+        Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).areas(swapping_layout);
+    let [page_fault_layout, major_page_fault_layout] =
+        Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).areas(page_fault_layout);
+
+    let graphs = [
+        (
+            "Paged In:",
+            &memory.page_in_vec,
+            true,
+            app_color_info.available_memory_base_graph_color,
+            page_in_layout,
+        ),
+        (
+            "Paged Out:",
+            &memory.page_out_vec,
+            true,
+            app_color_info.used_memory_base_graph_color,
+            page_out_layout,
+        ),
+        (
+            "Swapped In:",
+            &memory.swap_in_vec,
+            true,
+            app_color_info.free_memory_base_graph_color,
+            swap_in_layout,
+        ),
+        (
+            "Swapped Out:",
+            &memory.swap_out_vec,
+            true,
+            app_color_info.swap_memory_base_graph_color,
+            swap_out_layout,
+        ),
+        (
+            "Faults:",
+            &memory.page_fault_vec,
+            false,
+            app_color_info.cached_memory_base_graph_color,
+            page_fault_layout,
+        ),
+        (
+            "Major Faults:",
+            &memory.major_page_fault_vec,
+            false,
+            app_color_info.used_memory_base_graph_color,
+            major_page_fault_layout,
+        ),
+    ];
+
+    for (label, history, is_bytes, color, layout) in graphs {
+        // some space between the graphs on the left and on the right
+        let [_, layout, _] = Layout::horizontal([
+            Constraint::Length(1),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(layout);
+        let [_, graph] =
+            Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(layout);
+
+        let label = if layout.width < SMALL_WIDTH {
+            Line::from(&label[..1]).style(app_color_info.base_app_text_color)
+        } else {
+            Line::from(label).style(app_color_info.base_app_text_color)
+        };
+        let value = match history.last() {
+            Some(value) if is_bytes => process_to_kib_mib_gib(*value),
+            Some(value) => format!("{:.0}", value),
+            None => "-".to_string(),
+        };
+        let graph_block = Block::new()
+            .title(label.left_aligned())
+            .title(
+                Line::from(value)
+                    .style(app_color_info.memory_text_color)
+                    .bold()
+                    .right_aligned(),
+            )
+            .style(app_color_info.memory_main_block_color)
+            .borders(Borders::TOP);
+        frame.render_widget(graph_block, layout);
+
+        // scaled against the maximum delta within the shown range
+        let start_idx = history.len().saturating_sub(graph_show_range);
+        let current_max = history[start_idx..]
+            .iter()
+            .fold(0.0, |max: f64, value| max.max(*value));
+        render_history_graph(
+            history,
+            current_max,
+            graph_show_range,
+            color,
+            app_color_info,
+            graph,
+            frame,
+        );
+    }
 }
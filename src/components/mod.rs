@@ -1,7 +1,12 @@
+pub mod battery;
+pub mod cgroup;
+pub mod compact;
 pub mod cpu;
 pub mod disk;
+pub mod interrupt;
 pub mod memory;
 pub mod network;
+pub mod numa;
 pub mod process;
+pub mod session;
 pub mod theme;
-pub mod themes;
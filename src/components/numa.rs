@@ -0,0 +1,343 @@
+use std::collections::HashMap;
+
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    types::{AppColorInfo, CpuData, NumaNodeData, ProcessData},
+    utils::{get_tick_line_ui, pad_or_truncate, process_to_kib_mib_gib, render_history_graph},
+};
+
+// width smaller than these will be consider small/medium width for the numa node layout
+const SMALL_WIDTH: u16 = 20;
+const MEDIUM_WIDTH: u16 = 50;
+const PID_COLUMN_WIDTH: usize = 8;
+const MEMORY_COLUMN_WIDTH: usize = 11;
+const NODE_COLUMN_WIDTH: usize = 7;
+const MIN_PROGRAM_WIDTH: usize = 10;
+
+pub fn draw_numa_info(
+    tick: u64,
+    numa_nodes: &HashMap<usize, NumaNodeData>,
+    cpus: &[CpuData],
+    processes: &HashMap<String, ProcessData>,
+    area: Rect,
+    frame: &mut Frame,
+    graph_show_range: usize,
+    is_selected: bool,
+    app_color_info: &AppColorInfo,
+    is_full_screen: bool,
+) {
+    let select_instruction = Line::from(vec![
+        Span::styled(" ", Style::default().fg(app_color_info.app_title_color)),
+        Span::styled("M", Style::default().fg(app_color_info.key_text_color))
+            .bold()
+            .underlined(),
+        Span::styled(
+            "emory ",
+            Style::default().fg(app_color_info.app_title_color).bold(),
+        ),
+    ]);
+
+    let view_select_instruction = Line::from(vec![
+        Span::styled("　< ", Style::default().fg(app_color_info.key_text_color)).bold(),
+        Span::styled(
+            "NUMA",
+            Style::default().fg(app_color_info.app_title_color).bold(),
+        ),
+        Span::styled(" >　", Style::default().fg(app_color_info.key_text_color)).bold(),
+    ]);
+
+    let mut main_block = Block::bordered()
+        .title(select_instruction.left_aligned())
+        .title(view_select_instruction.right_aligned())
+        .style(app_color_info.memory_main_block_color)
+        .border_set(border::ROUNDED);
+    if is_selected {
+        main_block = main_block
+            .style(app_color_info.memory_container_selected_color)
+            .border_set(border::DOUBLE);
+    }
+
+    if is_full_screen {
+        let refresh_tick = get_tick_line_ui(tick, app_color_info);
+
+        main_block = main_block.title(refresh_tick.right_aligned());
+    }
+
+    frame.render_widget(main_block, area);
+
+    // padded the inner container
+    let [_, padded_vertical_inner, _] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+    ])
+    .areas(area);
+
+    let [_, numa_block, _] = Layout::horizontal([
+        Constraint::Length(2),
+        Constraint::Fill(1),
+        Constraint::Length(2),
+    ])
+    .areas(padded_vertical_inner);
+
+    // the process locality will only be shown on the right in the full screen view
+    let (node_block, locality_block) = if is_full_screen {
+        let [node_block, _, locality_block] = Layout::horizontal([
+            Constraint::Fill(3),
+            Constraint::Length(2),
+            Constraint::Fill(2),
+        ])
+        .areas(numa_block);
+        (node_block, Some(locality_block))
+    } else {
+        (numa_block, None)
+    };
+
+    let mut node_ids: Vec<&usize> = numa_nodes.keys().collect();
+    node_ids.sort();
+
+    // ------------------------------------------------------------
+    // Render each numa node memory usage on the left
+    // ------------------------------------------------------------
+    let node_layouts = Layout::vertical(
+        node_ids
+            .iter()
+            .map(|_| Constraint::Ratio(1, node_ids.len() as u32))
+            .collect::<Vec<Constraint>>(),
+    )
+    .split(node_block);
+
+    for (node_id, node_layout) in node_ids.iter().zip(node_layouts.iter()) {
+        let numa_node = &numa_nodes[node_id];
+        let memory = &numa_node.memory;
+        let used_memory = *memory.used_memory_vec.last().unwrap_or(&0.0);
+
+        // average usage of the cpus belonging to this node, the first entry of the cpus was the overall usage
+        let node_cpu_usages: Vec<f32> = numa_node
+            .cpus
+            .iter()
+            .filter_map(|cpu| cpus.get(cpu + 1).map(|cpu| cpu.usage))
+            .collect();
+        let node_cpu_usage = if node_cpu_usages.is_empty() {
+            "-".to_string()
+        } else {
+            format!(
+                "{:.1}%",
+                node_cpu_usages.iter().sum::<f32>() / node_cpu_usages.len() as f32
+            )
+        };
+
+        let node_label = if node_layout.width < SMALL_WIDTH {
+            format!("N{}:", numa_node.id)
+        } else if node_layout.width < MEDIUM_WIDTH {
+            format!("Node {}:", numa_node.id)
+        } else if numa_node.cpu_list.is_empty() {
+            format!("Node {} ( no cpu ):", numa_node.id)
+        } else {
+            format!(
+                "Node {} ( CPU {} {} ):",
+                numa_node.id, numa_node.cpu_list, node_cpu_usage
+            )
+        };
+        let node_usage = if node_layout.width < SMALL_WIDTH {
+            process_to_kib_mib_gib(used_memory)
+        } else {
+            format!(
+                "{} / {}",
+                process_to_kib_mib_gib(used_memory),
+                process_to_kib_mib_gib(memory.total_memory)
+            )
+        };
+
+        let node_info_block = Block::new()
+            .title(
+                Line::from(node_label)
+                    .style(app_color_info.base_app_text_color)
+                    .left_aligned(),
+            )
+            .title(
+                Line::from(node_usage)
+                    .style(app_color_info.memory_text_color)
+                    .bold()
+                    .right_aligned(),
+            )
+            .style(app_color_info.memory_main_block_color)
+            .borders(Borders::TOP);
+        frame.render_widget(node_info_block, *node_layout);
+
+        let [_, mut graph] =
+            Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(*node_layout);
+
+        // the allocation counters will only be shown in the full screen view
+        if is_full_screen && graph.height > 1 {
+            let [allocation_label, new_graph] =
+                Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(graph);
+            graph = new_graph;
+
+            let last_value = |history: &Vec<f64>| format!("{:.0}", history.last().unwrap_or(&0.0));
+            let allocation_style = Style::default().fg(app_color_info.base_app_text_color);
+            let value_style = Style::default().fg(app_color_info.memory_text_color).bold();
+            let allocation_line = Line::from(vec![
+                Span::styled("Free: ", allocation_style),
+                Span::styled(
+                    process_to_kib_mib_gib(*memory.free_memory_vec.last().unwrap_or(&0.0)),
+                    value_style,
+                ),
+                Span::styled("  Hit: ", allocation_style),
+                Span::styled(last_value(&numa_node.numa_hit_vec), value_style),
+                Span::styled("  Miss: ", allocation_style),
+                Span::styled(last_value(&numa_node.numa_miss_vec), value_style),
+                Span::styled("  Foreign: ", allocation_style),
+                Span::styled(last_value(&numa_node.numa_foreign_vec), value_style),
+                Span::styled("  Other Node: ", allocation_style),
+                Span::styled(last_value(&numa_node.other_node_vec), value_style),
+            ]);
+            frame.render_widget(allocation_line, allocation_label);
+        }
+
+        render_history_graph(
+            &memory.used_memory_vec,
+            memory.total_memory,
+            graph_show_range,
+            app_color_info.used_memory_base_graph_color,
+            app_color_info,
+            graph,
+            frame,
+        );
+    }
+
+    if let Some(locality_block) = locality_block {
+        draw_process_locality(&node_ids, processes, locality_block, frame, app_color_info);
+    }
+}
+
+// how much of the memory of the process shown in the process detail was on each node
+// NOTE: numa_maps was only read for that process, as it is expensive to produce for every process on every tick
+fn draw_process_locality(
+    node_ids: &[&usize],
+    processes: &HashMap<String, ProcessData>,
+    area: Rect,
+    frame: &mut Frame,
+    app_color_info: &AppColorInfo,
+) {
+    let locality_info_block = Block::new()
+        .borders(Borders::LEFT)
+        .style(app_color_info.memory_main_block_color);
+    frame.render_widget(locality_info_block, area);
+
+    let [_, padded_locality_layout] =
+        Layout::horizontal([Constraint::Length(2), Constraint::Fill(1)]).areas(area);
+
+    let mut processes: Vec<&ProcessData> = processes
+        .values()
+        .filter(|process| process.numa_memory.iter().sum::<f64>() > 0.0)
+        .collect();
+
+    if processes.is_empty() {
+        let [_, message_layout, _] = Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(2),
+            Constraint::Fill(1),
+        ])
+        .areas(padded_locality_layout);
+        let message = if node_ids.len() > 1 {
+            "Open a process detail ( ↵ in the process list ) to see its locality, processes of other users need root"
+        } else {
+            "Process locality is only available on multi-node systems"
+        };
+        let message = Paragraph::new(Line::from(message).style(app_color_info.base_app_text_color))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(message, message_layout);
+        return;
+    }
+
+    processes.sort_by(|a, b| {
+        let a_memory: f64 = a.numa_memory.iter().sum();
+        let b_memory: f64 = b.numa_memory.iter().sum();
+        b_memory.total_cmp(&a_memory)
+    });
+
+    let [title_layout, list_layout] =
+        Layout::vertical([Constraint::Length(1), Constraint::Fill(1)])
+            .areas(padded_locality_layout);
+
+    // show as many node columns as the width allows
+    let layout_width = padded_locality_layout.width as usize;
+    let shown_node_count = (layout_width
+        .saturating_sub(PID_COLUMN_WIDTH + MEMORY_COLUMN_WIDTH + MIN_PROGRAM_WIDTH)
+        / NODE_COLUMN_WIDTH)
+        .min(node_ids.len());
+    let program_width = layout_width.saturating_sub(
+        PID_COLUMN_WIDTH + MEMORY_COLUMN_WIDTH + NODE_COLUMN_WIDTH * shown_node_count,
+    );
+
+    let title_style = Style::default().fg(app_color_info.memory_text_color).bold();
+    let mut locality_title = vec![
+        Span::styled(pad_or_truncate("Pid:", PID_COLUMN_WIDTH), title_style),
+        Span::styled(pad_or_truncate("Program:", program_width), title_style),
+        Span::styled(pad_or_truncate("Memory:", MEMORY_COLUMN_WIDTH), title_style),
+    ];
+    for node_id in node_ids.iter().take(shown_node_count) {
+        locality_title.push(Span::styled(
+            pad_or_truncate(&format!("N{}:", node_id), NODE_COLUMN_WIDTH),
+            title_style,
+        ));
+    }
+    frame.render_widget(Line::from(locality_title), title_layout);
+
+    let locality_list: Vec<ListItem> = processes
+        .iter()
+        .take(list_layout.height as usize)
+        .map(|process| {
+            let numa_memory: f64 = process.numa_memory.iter().sum();
+            // the node holding most of the memory was highlighted
+            let local_node = process
+                .numa_memory
+                .iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map(|(node, _)| node);
+
+            let mut row = vec![
+                Span::styled(
+                    pad_or_truncate(&process.pid.to_string(), PID_COLUMN_WIDTH),
+                    Style::default().fg(app_color_info.base_app_text_color),
+                ),
+                Span::styled(
+                    pad_or_truncate(&process.name, program_width),
+                    Style::default().fg(app_color_info.base_app_text_color),
+                ),
+                Span::styled(
+                    pad_or_truncate(&process_to_kib_mib_gib(numa_memory), MEMORY_COLUMN_WIDTH),
+                    Style::default().fg(app_color_info.memory_text_color),
+                ),
+            ];
+            for node_id in node_ids.iter().take(shown_node_count) {
+                let node_memory = process.numa_memory.get(**node_id).copied().unwrap_or(0.0);
+                let mut style = Style::default().fg(app_color_info.base_app_text_color);
+                if local_node == Some(**node_id) {
+                    style = Style::default().fg(app_color_info.memory_text_color).bold();
+                }
+                row.push(Span::styled(
+                    pad_or_truncate(
+                        &format!("{:.0}%", node_memory / numa_memory * 100.0),
+                        NODE_COLUMN_WIDTH,
+                    ),
+                    style,
+                ));
+            }
+            ListItem::new(Line::from(row))
+        })
+        .collect();
+
+    frame.render_widget(List::new(locality_list), list_layout);
+}
@@ -10,10 +10,10 @@ use ratatui::{
 };
 
 use crate::{
-    types::{AppColorInfo, ProcessData, ProcessSortType},
+    types::{AppColorInfo, ProcessData, ProcessPreFilter, ProcessQuickFilter, ProcessSortType},
     utils::{
-        break_line_into_vectors_of_string, format_seconds, get_tick_line_ui,
-        process_to_kib_mib_gib, round_to_2_decimal, sort_process,
+        break_line_into_vectors_of_string, format_capabilities, format_seconds, get_tick_line_ui,
+        process_to_kib_mib_gib, render_history_graph, round_to_2_decimal, sort_process,
     },
 };
 
@@ -48,6 +48,9 @@ pub fn draw_process_info(
     current_showing_process_detail: &Option<HashMap<String, ProcessData>>,
     total_memory: f64,
     is_filtering: bool, // to indicate if the app enter typing state for process filtering
+    process_show_container: bool, // to indicate if the container/cgroup column should be shown in the process list
+    process_quick_filter: &ProcessQuickFilter,
+    process_pre_filter: &ProcessPreFilter,
     area: Rect,
     frame: &mut Frame,
     graph_show_range: usize,
@@ -252,6 +255,38 @@ pub fn draw_process_info(
         ])
     };
 
+    let container_column_instruction = Line::from(vec![
+        Span::styled(" c", Style::default().fg(app_color_info.app_title_color)).bold(),
+        Span::styled("O", Style::default().fg(app_color_info.key_text_color))
+            .bold()
+            .underlined(),
+        Span::styled(
+            "ntainer ",
+            Style::default().fg(app_color_info.app_title_color),
+        )
+        .bold(),
+    ]);
+
+    let quick_filter_instruction = Line::from(vec![
+        Span::styled(" ", Style::default().fg(app_color_info.app_title_color)),
+        Span::styled("V", Style::default().fg(app_color_info.key_text_color))
+            .bold()
+            .underlined(),
+        Span::styled(
+            if process_pre_filter.is_empty() {
+                format!("iew: {} ", process_quick_filter.get_filter_string_name())
+            } else {
+                format!(
+                    "iew: {} ( {} ) ",
+                    process_quick_filter.get_filter_string_name(),
+                    process_pre_filter.get_pre_filter_string_name()
+                )
+            },
+            Style::default().fg(app_color_info.app_title_color),
+        )
+        .bold(),
+    ]);
+
     let mut main_block = Block::bordered()
         .title(select_instruction.left_aligned())
         .title(process_filter_instruction.left_aligned())
@@ -259,6 +294,8 @@ pub fn draw_process_info(
         .title(process_sort_select_instruction.right_aligned())
         .title_bottom(process_list_selection_instruction.left_aligned())
         .title_bottom(able_show_info.left_aligned())
+        .title_bottom(quick_filter_instruction.right_aligned())
+        .title_bottom(container_column_instruction.right_aligned())
         .style(app_color_info.process_main_block_color)
         .border_set(border::ROUNDED);
 
@@ -479,6 +516,43 @@ pub fn draw_process_info(
                             ])
                         };
 
+                    // if user is currently navigating in the process list, the process is killed/terminated
+                    // or the oom_score_adj is not available, dim the adjust trigger to act as like it was disabled
+                    let adjust_instruction = if is_user_navigating_process_list
+                        || is_process_killed_or_terminated
+                        || process_detail.oom_score_adj.is_none()
+                    {
+                        Line::from(vec![
+                            Span::styled(
+                                "A".to_string(),
+                                Style::default().fg(app_color_info.key_text_color),
+                            )
+                            .bold()
+                            .underlined()
+                            .add_modifier(Modifier::DIM),
+                            Span::styled(
+                                "djust OOM".to_string(),
+                                Style::default().fg(app_color_info.app_title_color),
+                            )
+                            .bold()
+                            .add_modifier(Modifier::DIM),
+                        ])
+                    } else {
+                        Line::from(vec![
+                            Span::styled(
+                                "A".to_string(),
+                                Style::default().fg(app_color_info.key_text_color),
+                            )
+                            .bold()
+                            .underlined(),
+                            Span::styled(
+                                "djust OOM".to_string(),
+                                Style::default().fg(app_color_info.app_title_color),
+                            )
+                            .bold(),
+                        ])
+                    };
+
                     // if user is currently navigating in the process list, dim the hide trigger for process detail container to act as like it was disabled
                     let hide_instruction = if is_user_navigating_process_list {
                         Line::from(vec![
@@ -516,6 +590,7 @@ pub fn draw_process_info(
                             .title(termination_instruction.left_aligned())
                             .title(kill_instruction.left_aligned())
                             .title(signal_instruction.left_aligned())
+                            .title(adjust_instruction.left_aligned())
                             .title(hide_instruction.right_aligned())
                     };
 
@@ -533,11 +608,14 @@ pub fn draw_process_info(
                     ])
                     .areas(process_detail_graph_layout);
 
-                    let [_, padded_detail_graph_layout, detail_graph_naming_layout] =
+                    // the disk read and write rate graphs will be below the cpu usage graph
+                    let [_, padded_detail_graph_layout, detail_graph_naming_layout, disk_graph_layout, disk_graph_naming_layout] =
                         Layout::vertical([
                             Constraint::Length(1),
                             Constraint::Fill(1),
                             Constraint::Length(1),
+                            Constraint::Fill(1),
+                            Constraint::Length(1),
                         ])
                         .areas(padded_detail_graph_horizontal);
 
@@ -616,6 +694,72 @@ pub fn draw_process_info(
                         padded_detail_graph_naming_layout,
                     );
 
+                    // ------------------------------------------------------------
+                    // Render process disk read and write rate graphs below the CPU graph
+                    // ------------------------------------------------------------
+                    let [disk_read_graph_layout, _, disk_write_graph_layout] =
+                        Layout::horizontal([
+                            Constraint::Fill(1),
+                            Constraint::Length(1),
+                            Constraint::Fill(1),
+                        ])
+                        .areas(disk_graph_layout);
+                    let [disk_read_naming_layout, _, disk_write_naming_layout] =
+                        Layout::horizontal([
+                            Constraint::Fill(1),
+                            Constraint::Length(1),
+                            Constraint::Fill(1),
+                        ])
+                        .areas(disk_graph_naming_layout);
+
+                    for (title, history, color, graph_layout, naming_layout) in [
+                        (
+                            "R",
+                            &process_detail.disk_read_vec,
+                            app_color_info.disk_bytes_read_base_graph_color,
+                            disk_read_graph_layout,
+                            disk_read_naming_layout,
+                        ),
+                        (
+                            "W",
+                            &process_detail.disk_write_vec,
+                            app_color_info.disk_bytes_written_base_graph_color,
+                            disk_write_graph_layout,
+                            disk_write_naming_layout,
+                        ),
+                    ] {
+                        // there is no upper bound for the rate, so the graph was scaled against the highest rate shown
+                        let start_idx = history.len().saturating_sub(graph_show_range);
+                        let max_rate = history[start_idx..].iter().cloned().fold(0.0, f64::max);
+                        render_history_graph(
+                            history,
+                            max_rate,
+                            graph_show_range,
+                            color,
+                            app_color_info,
+                            graph_layout,
+                            frame,
+                        );
+
+                        let rate_naming = format!(
+                            "{}: {}/s",
+                            title,
+                            process_to_kib_mib_gib(*history.last().unwrap_or(&0.0))
+                        );
+                        let naming_width = naming_layout.width as usize;
+                        let padded_rate_naming = if rate_naming.len() < naming_width {
+                            format!("{:^width$}", rate_naming, width = naming_width)
+                        } else {
+                            rate_naming.chars().take(naming_width).collect::<String>()
+                        };
+                        let rate_naming_line = Line::from(vec![Span::styled(
+                            padded_rate_naming,
+                            Style::default().fg(app_color_info.app_title_color),
+                        )
+                        .bold()]);
+                        frame.render_widget(rate_naming_line, naming_layout);
+                    }
+
                     // ------------------------------------------------------------
                     // Render process detail info on the right
                     // ------------------------------------------------------------
@@ -623,10 +767,22 @@ pub fn draw_process_info(
                         Layout::vertical([Constraint::Length(1), Constraint::Fill(1)])
                             .areas(process_detail_info_layout);
 
-                    let [process_info_layout, process_memory_usage_layout, process_cmd_layout] =
+                    // the deleted files will only be shown when the process needs a restart
+                    let [process_info_layout, process_memory_usage_layout, process_cgroup_layout, process_oom_layout, process_security_layout, process_capability_layout, process_namespace_layout, process_scheduler_layout, process_deleted_file_layout, process_cmd_layout] =
                         Layout::vertical(vec![
                             Constraint::Length(3),
                             Constraint::Fill(1),
+                            Constraint::Length(1),
+                            Constraint::Length(1),
+                            Constraint::Length(1),
+                            Constraint::Length(1),
+                            Constraint::Length(1),
+                            Constraint::Length(1),
+                            Constraint::Length(if process_detail.deleted_files.is_empty() {
+                                0
+                            } else {
+                                1
+                            }),
                             Constraint::Length(3),
                         ])
                         .areas(padded_detail_info_layout);
@@ -1120,6 +1276,191 @@ pub fn draw_process_info(
                         padded_process_memory_usage_bytes_layout,
                     );
 
+                    // ------------------------------------------------------------
+                    // Cgroup and the container/unit derived from it above the CMD
+                    // ------------------------------------------------------------
+                    let [process_cgroup_title_layout, process_cgroup_info_layout] =
+                        Layout::horizontal(vec![Constraint::Fill(2), Constraint::Fill(8)])
+                            .areas(process_cgroup_layout);
+
+                    let process_cgroup_title_line = Line::from(vec![Span::styled(
+                        format!(
+                            "{:^width$}",
+                            "CGROUP:",
+                            width = process_cgroup_title_layout.width as usize
+                        ),
+                        Style::default().fg(app_color_info.process_title_color),
+                    )
+                    .bold()]);
+
+                    let cgroup_detail = if process_detail.cgroup.is_empty() {
+                        "-".to_string()
+                    } else {
+                        format!("{} ({})", process_detail.container, process_detail.cgroup)
+                    };
+                    let cgroup_detail_width = process_cgroup_info_layout.width as usize;
+                    let padded_cgroup_detail = if cgroup_detail.len() < cgroup_detail_width {
+                        format!("{:^width$}", cgroup_detail, width = cgroup_detail_width)
+                    } else {
+                        cgroup_detail
+                            .chars()
+                            .take(cgroup_detail_width)
+                            .collect::<String>()
+                    };
+                    let process_cgroup_info_line = Line::from(vec![Span::styled(
+                        padded_cgroup_detail,
+                        Style::default().fg(app_color_info.base_app_text_color),
+                    )
+                    .bold()]);
+
+                    frame.render_widget(process_cgroup_title_line, process_cgroup_title_layout);
+                    frame.render_widget(process_cgroup_info_line, process_cgroup_info_layout);
+
+                    // ------------------------------------------------------------
+                    // OOM score and adjustment above the CMD
+                    // ------------------------------------------------------------
+                    let [process_oom_title_layout, process_oom_info_layout] =
+                        Layout::horizontal(vec![Constraint::Fill(2), Constraint::Fill(8)])
+                            .areas(process_oom_layout);
+
+                    let process_oom_title_line = Line::from(vec![Span::styled(
+                        format!(
+                            "{:^width$}",
+                            "OOM:",
+                            width = process_oom_title_layout.width as usize
+                        ),
+                        Style::default().fg(app_color_info.process_title_color),
+                    )
+                    .bold()]);
+
+                    let oom_detail = match (process_detail.oom_score, process_detail.oom_score_adj)
+                    {
+                        (Some(oom_score), Some(oom_score_adj)) => {
+                            format!("score {} / adj {}", oom_score, oom_score_adj)
+                        }
+                        (Some(oom_score), None) => format!("score {}", oom_score),
+                        _ => "-".to_string(),
+                    };
+                    let process_oom_info_line = Line::from(vec![Span::styled(
+                        format!(
+                            "{:^width$}",
+                            oom_detail,
+                            width = process_oom_info_layout.width as usize
+                        ),
+                        Style::default().fg(app_color_info.base_app_text_color),
+                    )
+                    .bold()]);
+
+                    frame.render_widget(process_oom_title_line, process_oom_title_layout);
+                    frame.render_widget(process_oom_info_line, process_oom_info_layout);
+
+                    // ------------------------------------------------------------
+                    // Security context ( no_new_privs, seccomp, LSM label ), capabilities and namespaces above the CMD
+                    // ------------------------------------------------------------
+                    let (security_detail, capability_detail, namespace_detail) =
+                        match &process_detail.security {
+                            Some(security) => {
+                                let seccomp = match security.seccomp {
+                                    0 => "disabled",
+                                    1 => "strict",
+                                    _ => "filter",
+                                };
+                                let security_detail = format!(
+                                    "NoNewPrivs: {} | Seccomp: {} | LSM: {}",
+                                    if security.no_new_privs { "yes" } else { "no" },
+                                    seccomp,
+                                    security.lsm_label.as_deref().unwrap_or("-")
+                                );
+                                let capability_detail = format!(
+                                    "eff: {} | prm: {} | bnd: {}",
+                                    format_capabilities(security.cap_effective),
+                                    format_capabilities(security.cap_permitted),
+                                    format_capabilities(security.cap_bounding)
+                                );
+                                // namespaces outside of the init namespaces are marked with "*" and shown first
+                                let mut namespaces: Vec<String> = security
+                                    .namespaces
+                                    .iter()
+                                    .filter(|(name, _)| {
+                                        security.outside_init_namespaces.contains(name)
+                                    })
+                                    .map(|(name, inode)| format!("{}:{}*", name, inode))
+                                    .collect();
+                                namespaces.extend(
+                                    security
+                                        .namespaces
+                                        .iter()
+                                        .filter(|(name, _)| {
+                                            !security.outside_init_namespaces.contains(name)
+                                        })
+                                        .map(|(name, inode)| format!("{}:{}", name, inode)),
+                                );
+                                let namespace_detail = if namespaces.is_empty() {
+                                    "-".to_string()
+                                } else {
+                                    namespaces.join(" ")
+                                };
+                                (security_detail, capability_detail, namespace_detail)
+                            }
+                            None => ("-".to_string(), "-".to_string(), "-".to_string()),
+                        };
+
+                    let scheduler = &process_detail.scheduler;
+                    let scheduler_detail = if process_detail.scheduler_counters.is_some() {
+                        format!(
+                            "Ctx Sw: {:.0}/s ( vol {:.0} / invol {:.0} ) | Faults: {:.0}/s ( major {:.0} ) | Run Delay: {:.1} ms/s",
+                            scheduler.context_switch_rate(),
+                            scheduler.voluntary_context_switch_rate,
+                            scheduler.involuntary_context_switch_rate,
+                            scheduler.page_fault_rate(),
+                            scheduler.major_fault_rate,
+                            scheduler.run_delay_rate
+                        )
+                    } else {
+                        "-".to_string()
+                    };
+
+                    // only the file names, the full paths are mostly the same few library directories
+                    let deleted_file_detail = process_detail
+                        .deleted_files
+                        .iter()
+                        .map(|file| file.rsplit('/').next().unwrap_or(file))
+                        .collect::<Vec<&str>>()
+                        .join(", ");
+
+                    for (title, detail, layout) in [
+                        ("SECURITY:", security_detail, process_security_layout),
+                        ("CAPS:", capability_detail, process_capability_layout),
+                        ("NS:", namespace_detail, process_namespace_layout),
+                        ("SCHED:", scheduler_detail, process_scheduler_layout),
+                        ("DELETED:", deleted_file_detail, process_deleted_file_layout),
+                    ] {
+                        let [title_layout, info_layout] =
+                            Layout::horizontal(vec![Constraint::Fill(2), Constraint::Fill(8)])
+                                .areas(layout);
+
+                        let title_line = Line::from(vec![Span::styled(
+                            format!("{:^width$}", title, width = title_layout.width as usize),
+                            Style::default().fg(app_color_info.process_title_color),
+                        )
+                        .bold()]);
+
+                        let info_width = info_layout.width as usize;
+                        let padded_detail = if detail.chars().count() < info_width {
+                            format!("{:^width$}", detail, width = info_width)
+                        } else {
+                            detail.chars().take(info_width).collect::<String>()
+                        };
+                        let info_line = Line::from(vec![Span::styled(
+                            padded_detail,
+                            Style::default().fg(app_color_info.base_app_text_color),
+                        )
+                        .bold()]);
+
+                        frame.render_widget(title_line, title_layout);
+                        frame.render_widget(info_line, info_layout);
+                    }
+
                     // ------------------------------------------------------------
                     // CMD command on the bottom
                     // ------------------------------------------------------------
@@ -1236,6 +1577,16 @@ pub fn draw_process_info(
         };
     }
 
+    // the container column is optional and will take up space at the end of the process list
+    let mut container_width = 0;
+    let full_title_layout = title_layout;
+    if process_show_container {
+        let [new_title_layout, container] =
+            Layout::horizontal([Constraint::Fill(5), Constraint::Fill(1)]).areas(title_layout);
+        title_layout = new_title_layout;
+        container_width = container.width as usize;
+    }
+
     // for each column of different info of process
     let [pid, program, user, memory, cpu_usage] = Layout::horizontal([
         // Constraint::Ratio(15, 100),
@@ -1258,15 +1609,20 @@ pub fn draw_process_info(
     let mut user_width = user.width as usize;
     let mut memory_width = memory.width as usize;
     let mut cpu_usage_width = cpu_usage.width as usize;
+    let mut oom_score_width = 0;
+    let mut context_switch_width = 0;
+    let mut page_fault_width = 0;
+    let mut run_delay_width = 0;
 
     if area.width > MEDIUM_WIDTH && area.width <= LARGE_WIDTH {
-        let [pid, program, command, user, memory, cpu_usage] = Layout::horizontal([
+        let [pid, program, command, user, memory, cpu_usage, oom_score] = Layout::horizontal([
             Constraint::Fill(1),
             Constraint::Fill(2),
             Constraint::Fill(3),
             Constraint::Fill(1),
             Constraint::Fill(1),
             Constraint::Fill(1),
+            Constraint::Fill(1),
         ])
         .areas(title_layout);
         pid_width = pid.width as usize;
@@ -1275,17 +1631,44 @@ pub fn draw_process_info(
         user_width = user.width as usize;
         memory_width = memory.width as usize;
         cpu_usage_width = cpu_usage.width as usize;
-    } else if area.width > LARGE_WIDTH {
-        let [pid, program, command, thread, user, memory, cpu_usage] = Layout::horizontal([
-            Constraint::Fill(1),
-            Constraint::Fill(1),
-            Constraint::Fill(3),
-            Constraint::Fill(1),
-            Constraint::Fill(1),
-            Constraint::Fill(1),
-            Constraint::Fill(1),
-        ])
-        .areas(title_layout);
+        oom_score_width = oom_score.width as usize;
+    } else if area.width > LARGE_WIDTH && area.width <= X_LARGE_WIDTH {
+        let [pid, program, command, thread, user, memory, cpu_usage, oom_score] =
+            Layout::horizontal([
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(3),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+            ])
+            .areas(title_layout);
+        pid_width = pid.width as usize;
+        program_width = program.width as usize;
+        command_width = command.width as usize;
+        thread_width = thread.width as usize;
+        user_width = user.width as usize;
+        memory_width = memory.width as usize;
+        cpu_usage_width = cpu_usage.width as usize;
+        oom_score_width = oom_score.width as usize;
+    } else if area.width > X_LARGE_WIDTH {
+        let [pid, program, command, thread, user, memory, cpu_usage, context_switch, page_fault, run_delay, oom_score] =
+            Layout::horizontal([
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(3),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+            ])
+            .areas(title_layout);
         pid_width = pid.width as usize;
         program_width = program.width as usize;
         command_width = command.width as usize;
@@ -1293,6 +1676,10 @@ pub fn draw_process_info(
         user_width = user.width as usize;
         memory_width = memory.width as usize;
         cpu_usage_width = cpu_usage.width as usize;
+        context_switch_width = context_switch.width as usize;
+        page_fault_width = page_fault.width as usize;
+        run_delay_width = run_delay.width as usize;
+        oom_score_width = oom_score.width as usize;
     }
 
     // Pad the string to take up respective width
@@ -1303,6 +1690,11 @@ pub fn draw_process_info(
     let user_title = String::from("User: ");
     let memory_title = String::from("Mem: ");
     let cpu_usage_title = String::from("Cpu%: ");
+    let context_switch_title = String::from("Ctx/s: ");
+    let page_fault_title = String::from("Flt/s: ");
+    let run_delay_title = String::from("Wait: ");
+    let oom_score_title = String::from("OOM: ");
+    let container_title = String::from("Container: ");
 
     let padded_pid_title = if pid_title.len() < pid_width {
         format!("{:width$}", pid_title, width = pid_width)
@@ -1355,6 +1747,55 @@ pub fn draw_process_info(
             .collect::<String>()
     };
 
+    let padded_context_switch_title = if context_switch_title.len() < context_switch_width {
+        format!(
+            "{:width$}",
+            context_switch_title,
+            width = context_switch_width
+        )
+    } else {
+        context_switch_title
+            .chars()
+            .take(context_switch_width)
+            .collect::<String>()
+    };
+
+    let padded_page_fault_title = if page_fault_title.len() < page_fault_width {
+        format!("{:width$}", page_fault_title, width = page_fault_width)
+    } else {
+        page_fault_title
+            .chars()
+            .take(page_fault_width)
+            .collect::<String>()
+    };
+
+    let padded_run_delay_title = if run_delay_title.len() < run_delay_width {
+        format!("{:width$}", run_delay_title, width = run_delay_width)
+    } else {
+        run_delay_title
+            .chars()
+            .take(run_delay_width)
+            .collect::<String>()
+    };
+
+    let padded_oom_score_title = if oom_score_title.len() < oom_score_width {
+        format!("{:width$}", oom_score_title, width = oom_score_width)
+    } else {
+        oom_score_title
+            .chars()
+            .take(oom_score_width)
+            .collect::<String>()
+    };
+
+    let padded_container_title = if container_title.len() < container_width {
+        format!("{:width$}", container_title, width = container_width)
+    } else {
+        container_title
+            .chars()
+            .take(container_width)
+            .collect::<String>()
+    };
+
     let process_title = Line::from(vec![
         Span::styled(
             padded_pid_title,
@@ -1398,14 +1839,46 @@ pub fn draw_process_info(
                 .fg(app_color_info.process_title_color)
                 .bold(),
         ),
+        Span::styled(
+            padded_context_switch_title,
+            Style::default()
+                .fg(app_color_info.process_title_color)
+                .bold(),
+        ),
+        Span::styled(
+            padded_page_fault_title,
+            Style::default()
+                .fg(app_color_info.process_title_color)
+                .bold(),
+        ),
+        Span::styled(
+            padded_run_delay_title,
+            Style::default()
+                .fg(app_color_info.process_title_color)
+                .bold(),
+        ),
+        Span::styled(
+            padded_oom_score_title,
+            Style::default()
+                .fg(app_color_info.process_title_color)
+                .bold(),
+        ),
+        Span::styled(
+            padded_container_title,
+            Style::default()
+                .fg(app_color_info.process_title_color)
+                .bold(),
+        ),
     ]);
 
-    frame.render_widget(process_title, title_layout);
+    frame.render_widget(process_title, full_title_layout);
 
     let sorted_process = sort_process(
         process_sort_type.clone(),
         process_sort_is_reversed,
         process_filter_without_underscore_extension,
+        process_quick_filter,
+        process_pre_filter,
         process_data,
     );
 
@@ -1485,6 +1958,54 @@ pub fn draw_process_info(
                 cpu_usage.chars().take(cpu_usage_width).collect::<String>()
             };
 
+            let oom_score = match value.oom_score {
+                Some(oom_score) => oom_score.to_string(),
+                None => "-".to_string(),
+            };
+            let padded_oom_score = if oom_score.len() < oom_score_width {
+                format!("{:width$}", oom_score, width = oom_score_width)
+            } else {
+                oom_score.chars().take(oom_score_width).collect::<String>()
+            };
+
+            // rates since the previous update, the run queue delay in ms per second
+            let context_switch = format!("{:.0}", value.scheduler.context_switch_rate());
+            let padded_context_switch = if context_switch.len() < context_switch_width {
+                format!("{:width$}", context_switch, width = context_switch_width)
+            } else {
+                context_switch
+                    .chars()
+                    .take(context_switch_width)
+                    .collect::<String>()
+            };
+
+            let page_fault = format!("{:.0}", value.scheduler.page_fault_rate());
+            let padded_page_fault = if page_fault.len() < page_fault_width {
+                format!("{:width$}", page_fault, width = page_fault_width)
+            } else {
+                page_fault
+                    .chars()
+                    .take(page_fault_width)
+                    .collect::<String>()
+            };
+
+            let run_delay = format!("{:.1}ms", value.scheduler.run_delay_rate);
+            let padded_run_delay = if run_delay.len() < run_delay_width {
+                format!("{:width$}", run_delay, width = run_delay_width)
+            } else {
+                run_delay.chars().take(run_delay_width).collect::<String>()
+            };
+
+            let padded_container = if value.container.len() < container_width {
+                format!("{:width$}", value.container, width = container_width)
+            } else {
+                value
+                    .container
+                    .chars()
+                    .take(container_width)
+                    .collect::<String>()
+            };
+
             let mut process_inline_content_vec = vec![
                 Span::styled(
                     padded_pid,
@@ -1506,6 +2027,10 @@ pub fn draw_process_info(
                     padded_cpu_usage,
                     Style::default().fg(app_color_info.base_app_text_color),
                 ),
+                Span::styled(
+                    padded_container,
+                    Style::default().fg(app_color_info.process_text_color),
+                ),
             ];
             if area.width > MEDIUM_WIDTH && area.width <= LARGE_WIDTH {
                 process_inline_content_vec.insert(
@@ -1515,7 +2040,7 @@ pub fn draw_process_info(
                         Style::default().fg(app_color_info.base_app_text_color),
                     ),
                 );
-            } else if area.width > LARGE_WIDTH {
+            } else if area.width > LARGE_WIDTH && area.width <= X_LARGE_WIDTH {
                 process_inline_content_vec.insert(
                     2,
                     Span::styled(
@@ -1530,9 +2055,54 @@ pub fn draw_process_info(
                         Style::default().fg(app_color_info.process_text_color),
                     ),
                 );
+            } else if area.width > X_LARGE_WIDTH {
+                process_inline_content_vec.insert(
+                    2,
+                    Span::styled(
+                        padded_command,
+                        Style::default().fg(app_color_info.base_app_text_color),
+                    ),
+                );
+                process_inline_content_vec.insert(
+                    3,
+                    Span::styled(
+                        padded_thread,
+                        Style::default().fg(app_color_info.process_text_color),
+                    ),
+                );
+                // the scheduler columns will be right after the cpu usage column
+                for (index, (padded_value, color)) in [
+                    (padded_context_switch, app_color_info.base_app_text_color),
+                    (padded_page_fault, app_color_info.process_text_color),
+                    (padded_run_delay, app_color_info.base_app_text_color),
+                ]
+                .into_iter()
+                .enumerate()
+                {
+                    process_inline_content_vec.insert(
+                        7 + index,
+                        Span::styled(padded_value, Style::default().fg(color)),
+                    );
+                }
             }
+            // the oom score column will be right before the container column
+            process_inline_content_vec.insert(
+                process_inline_content_vec.len() - 1,
+                Span::styled(
+                    padded_oom_score,
+                    Style::default().fg(app_color_info.process_text_color),
+                ),
+            );
 
-            let process = Line::from(process_inline_content_vec);
+            // processes running deleted or replaced files are shown in italic with the program highlighted
+            let process = if value.deleted_files.is_empty() {
+                Line::from(process_inline_content_vec)
+            } else {
+                process_inline_content_vec[1] = process_inline_content_vec[1]
+                    .clone()
+                    .fg(app_color_info.key_text_color);
+                Line::from(process_inline_content_vec).italic()
+            };
 
             ListItem::new(process)
         })
@@ -1545,7 +2115,8 @@ pub fn draw_process_info(
         Style::default()
             .bg(app_color_info.process_selected_color_bg)
             .fg(app_color_info.process_selected_color_fg)
-            .bold(),
+            .bold()
+            .add_modifier(app_color_info.selected_modifier),
     );
     // Render the combined list with state
     frame.render_stateful_widget(
@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+
+use chrono::{Local, TimeZone};
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::{
+    types::{AppColorInfo, ProcessData, SessionData},
+    utils::{
+        aggregate_user_usage, format_seconds, get_tick_line_ui, pad_or_truncate,
+        process_to_kib_mib_gib,
+    },
+};
+
+pub fn draw_session_info(
+    tick: u64,
+    sessions: &[SessionData],
+    processes: &HashMap<String, ProcessData>,
+    area: Rect,
+    frame: &mut Frame,
+    is_selected: bool,
+    app_color_info: &AppColorInfo,
+) {
+    let select_instruction = Line::from(vec![
+        Span::styled(" ", Style::default().fg(app_color_info.app_title_color)),
+        Span::styled("U", Style::default().fg(app_color_info.key_text_color))
+            .bold()
+            .underlined(),
+        Span::styled("sers ", Style::default().fg(app_color_info.app_title_color)).bold(),
+    ]);
+
+    let refresh_tick = get_tick_line_ui(tick, app_color_info);
+
+    let mut main_block = Block::bordered()
+        .title(select_instruction.left_aligned())
+        .title(refresh_tick.right_aligned())
+        .style(app_color_info.process_main_block_color)
+        .border_set(border::ROUNDED);
+    if is_selected {
+        main_block = main_block
+            .style(app_color_info.process_container_selected_color)
+            .border_set(border::DOUBLE);
+    }
+
+    frame.render_widget(main_block, area);
+
+    // padded the inner container
+    let [_, padded_vertical_inner, _] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+    ])
+    .areas(area);
+
+    let [_, session_block, _] = Layout::horizontal([
+        Constraint::Length(2),
+        Constraint::Fill(1),
+        Constraint::Length(2),
+    ])
+    .areas(padded_vertical_inner);
+
+    let [session_layout, user_layout] =
+        Layout::vertical([Constraint::Fill(1), Constraint::Fill(1)]).areas(session_block);
+
+    // ------------------------------------------------------------
+    // Render the logged-in sessions on the top
+    // ------------------------------------------------------------
+    let session_title_block = Block::new()
+        .title(
+            Line::from(format!(" Sessions ({}) ", sessions.len()))
+                .style(app_color_info.app_title_color)
+                .bold()
+                .left_aligned(),
+        )
+        .style(app_color_info.process_main_block_color)
+        .borders(Borders::TOP);
+    frame.render_widget(session_title_block, session_layout);
+
+    let [_, session_header_layout, session_list_layout] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Fill(1),
+    ])
+    .areas(session_layout);
+
+    if sessions.is_empty() {
+        let message = Paragraph::new(
+            Line::from("No logged-in session found in utmp")
+                .style(app_color_info.base_app_text_color),
+        )
+        .alignment(Alignment::Center);
+        frame.render_widget(message, session_list_layout);
+    } else {
+        let session_columns = get_column_widths(session_header_layout, [3, 2, 4, 3, 2]);
+        render_header(
+            ["User:", "TTY:", "From:", "Login:", "Idle:"],
+            session_columns,
+            session_header_layout,
+            frame,
+            app_color_info,
+        );
+
+        let session_list: Vec<ListItem> = sessions
+            .iter()
+            .map(|session| {
+                let login_time = match Local.timestamp_opt(session.login_time, 0) {
+                    chrono::LocalResult::Single(time) => time.format("%m-%d %H:%M").to_string(),
+                    _ => "-".to_string(),
+                };
+                let idle = match session.idle {
+                    Some(idle) if idle >= 60 => format_seconds(idle),
+                    Some(_) => ".".to_string(),
+                    None => "?".to_string(),
+                };
+                let host = if session.host.is_empty() {
+                    "-".to_string()
+                } else {
+                    session.host.clone()
+                };
+                render_row(
+                    [
+                        session.user.clone(),
+                        session.tty.clone(),
+                        host,
+                        login_time,
+                        idle,
+                    ],
+                    session_columns,
+                    app_color_info,
+                )
+            })
+            .collect();
+        frame.render_widget(List::new(session_list), session_list_layout);
+    }
+
+    // ------------------------------------------------------------
+    // Render the per user usage on the bottom
+    // ------------------------------------------------------------
+    let user_usage = aggregate_user_usage(processes, sessions);
+
+    let user_title_block = Block::new()
+        .title(
+            Line::from(format!(" Users ({}) ", user_usage.len()))
+                .style(app_color_info.app_title_color)
+                .bold()
+                .left_aligned(),
+        )
+        .style(app_color_info.process_main_block_color)
+        .borders(Borders::TOP);
+    frame.render_widget(user_title_block, user_layout);
+
+    let [_, user_header_layout, user_list_layout] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Fill(1),
+    ])
+    .areas(user_layout);
+
+    let user_columns = get_column_widths(user_header_layout, [3, 2, 2, 2, 3]);
+    render_header(
+        ["User:", "Sessions:", "Procs:", "Cpu%:", "Mem:"],
+        user_columns,
+        user_header_layout,
+        frame,
+        app_color_info,
+    );
+
+    let user_list: Vec<ListItem> = user_usage
+        .iter()
+        .map(|usage| {
+            render_row(
+                [
+                    usage.user.clone(),
+                    usage.session_count.to_string(),
+                    usage.process_count.to_string(),
+                    format!("{:.2}%", usage.cpu_usage),
+                    process_to_kib_mib_gib(usage.memory),
+                ],
+                user_columns,
+                app_color_info,
+            )
+        })
+        .collect();
+    frame.render_widget(List::new(user_list), user_list_layout);
+}
+
+fn get_column_widths(area: Rect, ratios: [u32; 5]) -> [usize; 5] {
+    let columns: [Rect; 5] =
+        Layout::horizontal(ratios.map(|ratio| Constraint::Fill(ratio as u16))).areas(area);
+    columns.map(|column| column.width as usize)
+}
+
+fn render_header(
+    titles: [&str; 5],
+    widths: [usize; 5],
+    area: Rect,
+    frame: &mut Frame,
+    app_color_info: &AppColorInfo,
+) {
+    let spans: Vec<Span> = titles
+        .iter()
+        .zip(widths.iter())
+        .map(|(title, width)| {
+            Span::styled(
+                pad_or_truncate(title, *width),
+                Style::default().fg(app_color_info.process_title_color),
+            )
+            .bold()
+        })
+        .collect();
+    frame.render_widget(Line::from(spans), area);
+}
+
+fn render_row<'a>(
+    values: [String; 5],
+    widths: [usize; 5],
+    app_color_info: &AppColorInfo,
+) -> ListItem<'a> {
+    let spans: Vec<Span> = values
+        .iter()
+        .zip(widths.iter())
+        .enumerate()
+        .map(|(index, (value, width))| {
+            let color = if index % 2 == 0 {
+                app_color_info.process_text_color
+            } else {
+                app_color_info.base_app_text_color
+            };
+            Span::styled(pad_or_truncate(value, *width), Style::default().fg(color))
+        })
+        .collect();
+    ListItem::new(Line::from(spans))
+}
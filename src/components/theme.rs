@@ -1,101 +1,719 @@
-use std::fs::{File, OpenOptions};
+use std::{
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    time::Duration,
+};
+
+use ratatui::{
+    crossterm::style::force_color_output,
+    style::{Color, Modifier},
+};
+use serde_json::{Map, Value};
 
 use crate::{
-    components::themes::{
-        ayu::AYU,
-        catppuccin::CATPPUCCIN_MOCHA,
-        default::DEFAULT,
-        dracula::DRACULA,
-        everforest::{EVERFOREST_DARK, EVERFOREST_LIGHT},
-        flatremix::{FLATREMIX, FLATREMIX_LIGHT},
-        github::GITHUB_DARK,
-        grayscale::GRAYSCALE,
-        gruvbox::{GRUVBOX_DARK, GRUVBOX_LIGHT, GRUVBOX_MAT_DARK},
-        horizon::HORIZON,
-        kanagawa::{KANAGAWA_LOTUS, KANAGAWA_WAVE},
-        matcha::MATCHA_DARK_SEA,
-        monokai::MONOKAI,
-        nightowl::NIGHT_OWL,
-        onedark::ONEDARK,
-        paper::PAPER,
-        rosepine::ROSE_PINE,
-        solarized::{SOLARIZED_DARK, SOLARIZED_LIGHT},
-        tokyo::{TOKYO_NIGHT, TOKYO_STORM},
-    },
-    types::{AppColorInfo, ThemeConfig},
-    utils::{create_file_with_dirs, get_user_directory},
+    config::write_theme_to_app_config,
+    types::{AppColorInfo, AutoThemeConfig, ColorMode},
+    utils::get_user_directory,
 };
 
-pub fn get_and_return_app_color_info() -> AppColorInfo {
-    let theme_config_filepath = get_user_directory().join(".rtop/settings.json");
-    if !theme_config_filepath.exists() {
-        let theme_config = ThemeConfig {
-            theme: "default".to_string(),
+static COLOR_MODE: OnceLock<ColorMode> = OnceLock::new();
+static AUTO_THEME: OnceLock<AutoThemeConfig> = OnceLock::new();
+static IS_TERMINAL_BACKGROUND_LIGHT: OnceLock<bool> = OnceLock::new();
+
+// the theme that picks the light or the dark theme in the settings according to the background color of the terminal
+const AUTO_THEME_NAME: &str = "auto";
+// how long to wait for the terminal to answer, only the terminals that answer neither OSC 11 nor DA1 will wait this long
+const TERMINAL_BACKGROUND_QUERY_TIMEOUT: Duration = Duration::from_millis(1000);
+
+// the 16 colors as xterm shows them by default, terminals can be configured to show them differently so this is only the closest guess
+const ANSI_16_COLORS: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+// the levels of each of red, green and blue in the 6x6x6 color cube of the 256 colors ( 16 to 231 )
+const ANSI_256_CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+// the built-in themes, in the order shown in the theme selection
+// they were written in the same format as the user themes, with every color given
+const BUILT_IN_THEMES: [(&str, &str); 26] = [
+    ("default", include_str!("themes/default.json")),
+    ("dracula", include_str!("themes/dracula.json")),
+    ("gruvbox_dark", include_str!("themes/gruvbox_dark.json")),
+    ("gruvbox_light", include_str!("themes/gruvbox_light.json")),
+    (
+        "gruvbox_mat_dark",
+        include_str!("themes/gruvbox_mat_dark.json"),
+    ),
+    ("ayu", include_str!("themes/ayu.json")),
+    (
+        "everforest_dark",
+        include_str!("themes/everforest_dark.json"),
+    ),
+    (
+        "everforest_light",
+        include_str!("themes/everforest_light.json"),
+    ),
+    ("flatremix", include_str!("themes/flatremix.json")),
+    (
+        "flatremix_light",
+        include_str!("themes/flatremix_light.json"),
+    ),
+    ("grayscale", include_str!("themes/grayscale.json")),
+    ("horizon", include_str!("themes/horizon.json")),
+    ("kanagawa_wave", include_str!("themes/kanagawa_wave.json")),
+    ("kanagawa_lotus", include_str!("themes/kanagawa_lotus.json")),
+    ("monokai", include_str!("themes/monokai.json")),
+    ("onedark", include_str!("themes/onedark.json")),
+    ("nightowl", include_str!("themes/nightowl.json")),
+    ("rosepine", include_str!("themes/rosepine.json")),
+    (
+        "matcha_dark_sea",
+        include_str!("themes/matcha_dark_sea.json"),
+    ),
+    ("paper", include_str!("themes/paper.json")),
+    ("solarized_dark", include_str!("themes/solarized_dark.json")),
+    (
+        "solarized_light",
+        include_str!("themes/solarized_light.json"),
+    ),
+    ("tokyo_night", include_str!("themes/tokyo_night.json")),
+    ("tokyo_storm", include_str!("themes/tokyo_storm.json")),
+    (
+        "catppuccin_mocha",
+        include_str!("themes/catppuccin_mocha.json"),
+    ),
+    ("github_dark", include_str!("themes/github_dark.json")),
+];
+
+// the theme that the themes without a "base" inherit from, it is also used when a theme can't be loaded
+const DEFAULT_THEME_NAME: &str = "default";
+
+// user themes will be at ~/.rtop/themes/<name>.json or ~/.rtop/themes/<name>.toml
+pub fn get_user_theme_directory() -> PathBuf {
+    get_user_directory().join(".rtop/themes")
+}
+
+// the built-in themes followed by the user themes sorted by name
+// a user theme with the same name as a built-in theme takes its place instead of being listed twice
+pub fn get_theme_names() -> Vec<String> {
+    let mut theme_names: Vec<String> = [AUTO_THEME_NAME]
+        .iter()
+        .chain(BUILT_IN_THEMES.iter().map(|(theme_name, _)| theme_name))
+        .map(|theme_name| theme_name.to_string())
+        .collect();
+
+    let mut user_theme_names: Vec<String> = std::fs::read_dir(get_user_theme_directory())
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.extension()
+                        .is_some_and(|extension| extension == "json" || extension == "toml")
+                })
+                .filter_map(|path| Some(path.file_stem()?.to_string_lossy().to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    user_theme_names.sort();
+    user_theme_names.dedup();
+
+    for user_theme_name in user_theme_names {
+        if !theme_names.contains(&user_theme_name) {
+            theme_names.push(user_theme_name);
+        }
+    }
+    theme_names
+}
+
+// the color info of the theme along with the problems found in it
+// the colors that are missing or invalid will be taken from the base theme, so a broken theme will never stop rtop from starting
+pub fn get_and_return_app_color_info(theme_str: &str) -> (AppColorInfo, Vec<String>) {
+    let mut warnings = vec![];
+    let theme_str = if theme_str == AUTO_THEME_NAME {
+        get_auto_theme_name()
+    } else {
+        theme_str.to_string()
+    };
+    let mut colors = load_theme(
+        &theme_str,
+        Some(&get_user_theme_directory()),
+        &mut vec![],
+        &mut warnings,
+    );
+
+    // the colors will be mapped to the ones that the terminal can show before they were read
+    let color_mode = get_color_mode();
+    for value in colors.values_mut() {
+        if let Ok(color) = serde_json::from_value::<Color>(value.clone()) {
+            *value = Value::String(get_color_for_color_mode(color, color_mode).to_string());
+        }
+    }
+
+    // every color left was valid on its own and all of them were filled by the base theme, so this should not fail
+    let mut app_color_info: AppColorInfo = serde_json::from_value(Value::Object(colors))
+        .unwrap_or_else(|_| {
+            serde_json::from_value(Value::Object(get_built_in_theme_colors(DEFAULT_THEME_NAME)))
+                .unwrap()
+        });
+    if color_mode == ColorMode::NoColor {
+        app_color_info.selected_modifier = Modifier::REVERSED;
+    }
+    (app_color_info, warnings)
+}
+
+// this need to be called before the theme was loaded, subsequent calls will be ignored
+pub fn set_auto_theme(auto_theme: AutoThemeConfig) {
+    let _ = AUTO_THEME.set(auto_theme);
+}
+
+// this need to be called before the TUI takes over the terminal, so that previewing the "auto" theme in the theme
+// selection will not wait for the terminal to answer while the keys are being read
+pub fn detect_terminal_background() {
+    IS_TERMINAL_BACKGROUND_LIGHT.get_or_init(is_terminal_background_light);
+}
+
+// the background color was asked once on start up, see detect_terminal_background
+fn get_auto_theme_name() -> String {
+    let auto_theme = AUTO_THEME.get_or_init(AutoThemeConfig::default);
+    if *IS_TERMINAL_BACKGROUND_LIGHT.get_or_init(is_terminal_background_light) {
+        auto_theme.light.clone()
+    } else {
+        auto_theme.dark.clone()
+    }
+}
+
+// the terminal will be asked first, then COLORFGBG that some terminals set, most terminals are dark by default if neither was known
+fn is_terminal_background_light() -> bool {
+    if let Some((red, green, blue)) = query_terminal_background_rgb() {
+        return 0.2126 * red + 0.7152 * green + 0.0722 * blue > 0.5;
+    }
+
+    // eg. "15;0" or "15;default;0", the last one is the background color out of the 16 colors
+    std::env::var("COLORFGBG")
+        .ok()
+        .and_then(|colorfgbg| colorfgbg.rsplit(';').next()?.parse::<u8>().ok())
+        .is_some_and(|background| background == 7 || (9..=15).contains(&background))
+}
+
+// ask for the background color with OSC 11 followed by DA1, the answers will be read straight from stdin before the TUI
+// starts reading the keys. terminals answer in order and almost all of them answer DA1, so once the answer of DA1 was read
+// the answer of OSC 11 was either read before it or will never come, instead of coming late and being read as keys
+#[cfg(unix)]
+fn query_terminal_background_rgb() -> Option<(f64, f64, f64)> {
+    use ratatui::crossterm::terminal::{disable_raw_mode, enable_raw_mode, is_raw_mode_enabled};
+    use std::io::Write;
+
+    if unsafe { libc::isatty(libc::STDIN_FILENO) != 1 || libc::isatty(libc::STDOUT_FILENO) != 1 } {
+        return None;
+    }
+
+    // the answer will only be readable without waiting for a new line in raw mode
+    let is_raw_mode = is_raw_mode_enabled().unwrap_or(false);
+    if !is_raw_mode {
+        enable_raw_mode().ok()?;
+    }
+
+    let mut stdout = std::io::stdout();
+    let _ = stdout.write_all(b"\x1b]11;?\x1b\\\x1b[c");
+    let _ = stdout.flush();
+
+    let deadline = std::time::Instant::now() + TERMINAL_BACKGROUND_QUERY_TIMEOUT;
+    let mut response = Vec::new();
+    loop {
+        let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+        let mut stdin_pollfd = libc::pollfd {
+            fd: libc::STDIN_FILENO,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        if remaining.is_zero()
+            || unsafe { libc::poll(&mut stdin_pollfd, 1, remaining.as_millis() as i32) } <= 0
+        {
+            break;
+        }
+
+        let mut buffer = [0u8; 64];
+        let read = unsafe {
+            libc::read(
+                libc::STDIN_FILENO,
+                buffer.as_mut_ptr() as *mut libc::c_void,
+                buffer.len(),
+            )
         };
+        if read <= 0 {
+            break;
+        }
+        response.extend_from_slice(&buffer[..read as usize]);
+
+        if is_device_attributes_response_read(&response) {
+            break;
+        }
+    }
+
+    if !is_raw_mode {
+        let _ = disable_raw_mode();
+    }
+    parse_terminal_background_response(&String::from_utf8_lossy(&response))
+}
+
+#[cfg(not(unix))]
+fn query_terminal_background_rgb() -> Option<(f64, f64, f64)> {
+    None
+}
+
+// the answer of DA1 was in the form of "\x1b[?<attributes>c"
+fn is_device_attributes_response_read(response: &[u8]) -> bool {
+    response
+        .windows(3)
+        .position(|window| window == b"\x1b[?")
+        .is_some_and(|index| response[index..].contains(&b'c'))
+}
+
+// eg. "\x1b]11;rgb:2828/2a2a/3636\x1b\\", each of red, green and blue can have 1 to 4 hex digits
+fn parse_terminal_background_response(response: &str) -> Option<(f64, f64, f64)> {
+    let rgb = response.split("rgb:").nth(1)?;
+    let mut rgb = rgb.split('/').map(|component| {
+        let hex: String = component
+            .chars()
+            .take_while(|character| character.is_ascii_hexdigit())
+            .take(4)
+            .collect();
+        let value = u32::from_str_radix(&hex, 16).ok()?;
+        Some(value as f64 / ((1u32 << (4 * hex.len())) - 1) as f64)
+    });
+    Some((rgb.next()??, rgb.next()??, rgb.next()??))
+}
 
-        create_file_with_dirs(theme_config_filepath.to_str().unwrap());
-        let file = OpenOptions::new()
-            .write(true)
-            .create(true) // Create the file if it doesn't exist
-            .truncate(true) // Truncate the file to ensure it's empty before writing
-            .open(&theme_config_filepath)
-            .unwrap();
-
-        // write the data into the json file
-        let _ = serde_json::to_writer(file, &theme_config);
-
-        return DEFAULT;
-    }
-    // read the json file to configure the settings instead if it exist
-    let file = File::open(theme_config_filepath).unwrap();
-    let theme_config: ThemeConfig = serde_json::from_reader(file).unwrap();
-
-    let theme_str: &str = &theme_config.theme;
-    match theme_str {
-        "default" => return DEFAULT,
-        "dracula" => return DRACULA,
-        "gruvbox_dark" => return GRUVBOX_DARK,
-        "gruvbox_light" => return GRUVBOX_LIGHT,
-        "gruvbox_mat_dark" => return GRUVBOX_MAT_DARK,
-        "ayu" => return AYU,
-        "everforest_dark" => return EVERFOREST_DARK,
-        "everforest_light" => return EVERFOREST_LIGHT,
-        "flatremix" => return FLATREMIX,
-        "flatremix_light" => return FLATREMIX_LIGHT,
-        "grayscale" => return GRAYSCALE,
-        "horizon" => return HORIZON,
-        "kanagawa_wave" => return KANAGAWA_WAVE,
-        "kanagawa_lotus" => return KANAGAWA_LOTUS,
-        "monokai" => return MONOKAI,
-        "onedark" => return ONEDARK,
-        "nightowl" => return NIGHT_OWL,
-        "rosepine" => return ROSE_PINE,
-        "matcha_dark_sea" => return MATCHA_DARK_SEA,
-        "paper" => return PAPER,
-        "solarized_dark" => return SOLARIZED_DARK,
-        "solarized_light" => return SOLARIZED_LIGHT,
-        "tokyo_night" => return TOKYO_NIGHT,
-        "tokyo_storm" => return TOKYO_STORM,
-        "catppuccin_mocha" => return CATPPUCCIN_MOCHA,
-        "github_dark" => return GITHUB_DARK,
-        _ => return DEFAULT,
-    }
-}
-
-pub fn set_theme(theme_string: String) {
-    let theme_config_filepath = get_user_directory().join(".rtop/settings.json");
-    let theme_config = ThemeConfig {
-        theme: theme_string,
+// this need to be called before the theme was loaded, subsequent calls will be ignored
+pub fn set_color_mode(color_mode: ColorMode) {
+    let color_mode = match color_mode {
+        ColorMode::Auto => detect_color_mode(),
+        ColorMode::NoColor => ColorMode::NoColor,
+        // crossterm drops every color by itself when NO_COLOR was set, the colors asked for in the settings need to be forced
+        _ => {
+            force_color_output(true);
+            color_mode
+        }
     };
+    let _ = COLOR_MODE.set(color_mode);
+}
 
-    create_file_with_dirs(theme_config_filepath.to_str().unwrap());
-    let file = OpenOptions::new()
-        .write(true)
-        .create(true) // Create the file if it doesn't exist
-        .truncate(true) // Truncate the file to ensure it's empty before writing
-        .open(&theme_config_filepath)
-        .unwrap();
+fn get_color_mode() -> ColorMode {
+    *COLOR_MODE.get_or_init(detect_color_mode)
+}
+
+// NO_COLOR ( https://no-color.org ) takes precedence over what the terminal supports when it was set to anything but an empty string
+fn detect_color_mode() -> ColorMode {
+    if std::env::var("NO_COLOR").is_ok_and(|no_color| !no_color.is_empty()) {
+        return ColorMode::NoColor;
+    }
 
-    // write the data into the json file
-    let _ = serde_json::to_writer(file, &theme_config);
+    let colorterm = std::env::var("COLORTERM")
+        .unwrap_or_default()
+        .to_lowercase();
+    if colorterm == "truecolor" || colorterm == "24bit" {
+        return ColorMode::TrueColor;
+    }
+
+    // eg. xterm-direct, xterm-256color, screen-256color, linux
+    let term = std::env::var("TERM").unwrap_or_default().to_lowercase();
+    if term.contains("direct") || term.contains("truecolor") {
+        ColorMode::TrueColor
+    } else if term.contains("256color") {
+        ColorMode::Ansi256
+    } else if term == "dumb" {
+        ColorMode::NoColor
+    } else if term.is_empty() && cfg!(target_os = "windows") {
+        // the windows console does not set TERM, both the windows terminal and the console since windows 10 support truecolor
+        ColorMode::TrueColor
+    } else {
+        ColorMode::Ansi16
+    }
+}
+
+// the named colors were left as it is as the terminal can show them in any mode except without colors
+fn get_color_for_color_mode(color: Color, color_mode: ColorMode) -> Color {
+    match (color_mode, color) {
+        (ColorMode::NoColor, _) => Color::Reset,
+        (ColorMode::Ansi256, Color::Rgb(red, green, blue)) => {
+            Color::Indexed(get_nearest_ansi_256_color_index((red, green, blue)))
+        }
+        (ColorMode::Ansi16, Color::Rgb(red, green, blue)) => {
+            get_nearest_ansi_16_color((red, green, blue))
+        }
+        (ColorMode::Ansi16, Color::Indexed(index)) => {
+            get_nearest_ansi_16_color(get_ansi_256_color_rgb(index))
+        }
+        _ => color,
+    }
+}
+
+// only the color cube and the grayscale ramp will be picked, the first 16 colors can be configured by the terminal so they were left out
+fn get_nearest_ansi_256_color_index(rgb: (u8, u8, u8)) -> u8 {
+    let get_nearest_cube_level = |value: u8| -> u8 {
+        (0..ANSI_256_CUBE_LEVELS.len())
+            .min_by_key(|level| (ANSI_256_CUBE_LEVELS[*level] as i32 - value as i32).abs())
+            .unwrap_or(0) as u8
+    };
+    let cube_index = 16
+        + 36 * get_nearest_cube_level(rgb.0)
+        + 6 * get_nearest_cube_level(rgb.1)
+        + get_nearest_cube_level(rgb.2);
+
+    // the grayscale ramp goes from 8 to 238 in steps of 10 ( 232 to 255 )
+    let average = (rgb.0 as u16 + rgb.1 as u16 + rgb.2 as u16) / 3;
+    let grayscale_index = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+
+    if get_color_distance(rgb, get_ansi_256_color_rgb(grayscale_index))
+        < get_color_distance(rgb, get_ansi_256_color_rgb(cube_index))
+    {
+        grayscale_index
+    } else {
+        cube_index
+    }
+}
+
+fn get_nearest_ansi_16_color(rgb: (u8, u8, u8)) -> Color {
+    ANSI_16_COLORS
+        .iter()
+        .min_by_key(|(_, ansi_rgb)| get_color_distance(rgb, *ansi_rgb))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+fn get_ansi_256_color_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI_16_COLORS[index as usize].1,
+        16..=231 => {
+            let index = (index - 16) as usize;
+            (
+                ANSI_256_CUBE_LEVELS[index / 36],
+                ANSI_256_CUBE_LEVELS[(index / 6) % 6],
+                ANSI_256_CUBE_LEVELS[index % 6],
+            )
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+fn get_color_distance(rgb: (u8, u8, u8), other_rgb: (u8, u8, u8)) -> u32 {
+    let red = rgb.0 as i32 - other_rgb.0 as i32;
+    let green = rgb.1 as i32 - other_rgb.1 as i32;
+    let blue = rgb.2 as i32 - other_rgb.2 as i32;
+    (red * red + green * green + blue * blue) as u32
+}
+
+// the user theme in the directory will take the place of the built-in theme with the same name, the directory was not given
+// when it was the built-in theme being asked for
+fn load_theme(
+    theme_name: &str,
+    user_theme_directory: Option<&Path>,
+    inherited_by: &mut Vec<String>,
+    warnings: &mut Vec<String>,
+) -> Map<String, Value> {
+    let user_theme_filepath = user_theme_directory
+        .and_then(|user_theme_directory| get_user_theme_filepath(user_theme_directory, theme_name));
+    let Some(user_theme_filepath) = user_theme_filepath else {
+        if !BUILT_IN_THEMES.iter().any(|(name, _)| *name == theme_name) {
+            warnings.push(format!(
+                "theme `{}` was not found, the {} theme was used",
+                theme_name, DEFAULT_THEME_NAME
+            ));
+            return get_built_in_theme_colors(DEFAULT_THEME_NAME);
+        }
+        return get_built_in_theme_colors(theme_name);
+    };
+
+    let file_name = format!(
+        "~/.rtop/themes/{}",
+        user_theme_filepath
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().to_string())
+            .unwrap_or_default()
+    );
+    let mut user_colors = match read_user_theme(&user_theme_filepath) {
+        Ok(user_colors) => user_colors,
+        Err(error) => {
+            warnings.push(format!(
+                "{} {}, the {} theme was used",
+                file_name, error, DEFAULT_THEME_NAME
+            ));
+            return get_built_in_theme_colors(DEFAULT_THEME_NAME);
+        }
+    };
+
+    let base_theme_name = match user_colors.remove("base") {
+        None => DEFAULT_THEME_NAME.to_string(),
+        Some(Value::String(base_theme_name)) => base_theme_name,
+        Some(base_theme_name) => {
+            warnings.push(format!(
+                "{} `base`: {} is not a theme name, the {} theme was used",
+                file_name, base_theme_name, DEFAULT_THEME_NAME
+            ));
+            DEFAULT_THEME_NAME.to_string()
+        }
+    };
+
+    // eg. a dracula.json with "base": "dracula" will be the built-in dracula with a few colors changed
+    inherited_by.push(theme_name.to_string());
+    let mut colors = if base_theme_name == theme_name {
+        load_theme(&base_theme_name, None, inherited_by, warnings)
+    } else if inherited_by.contains(&base_theme_name) {
+        warnings.push(format!(
+            "{} `base`: {} inherits from {} in a loop, the {} theme was used",
+            file_name, base_theme_name, theme_name, DEFAULT_THEME_NAME
+        ));
+        get_built_in_theme_colors(DEFAULT_THEME_NAME)
+    } else {
+        load_theme(
+            &base_theme_name,
+            user_theme_directory,
+            inherited_by,
+            warnings,
+        )
+    };
+
+    // check each color on its own, so that only the invalid colors will fall back to the base theme
+    for (key, value) in user_colors {
+        if !colors.contains_key(&key) {
+            warnings.push(format!(
+                "{} `{}`: unknown key, it was ignored",
+                file_name, key
+            ));
+        } else if serde_json::from_value::<Color>(value.clone()).is_err() {
+            warnings.push(format!(
+                "{} `{}`: {} is not a valid color, the color of {} was used",
+                file_name, key, value, base_theme_name
+            ));
+        } else {
+            colors.insert(key, value);
+        }
+    }
+    colors
+}
+
+// a theme in JSON takes precedence over the one in TOML with the same name
+fn get_user_theme_filepath(user_theme_directory: &Path, theme_name: &str) -> Option<PathBuf> {
+    ["json", "toml"]
+        .iter()
+        .map(|extension| user_theme_directory.join(format!("{}.{}", theme_name, extension)))
+        .find(|user_theme_filepath| user_theme_filepath.is_file())
+}
+
+// TOML will be read into the same JSON object, so both formats can be checked the same way
+fn read_user_theme(user_theme_filepath: &Path) -> Result<Map<String, Value>, String> {
+    let content = std::fs::read_to_string(user_theme_filepath)
+        .map_err(|error| format!("could not be read ( {} )", error))?;
+    let user_colors = if user_theme_filepath
+        .extension()
+        .is_some_and(|extension| extension == "toml")
+    {
+        toml::from_str::<Value>(&content)
+            .map_err(|error| format!("is not valid TOML ( {} )", error.message()))?
+    } else {
+        serde_json::from_str::<Value>(&content)
+            .map_err(|error| format!("is not valid JSON ( {} )", error))?
+    };
+    match user_colors {
+        Value::Object(user_colors) => Ok(user_colors),
+        _ => Err("is not a JSON object".to_string()),
+    }
+}
+
+// the built-in themes were checked to be complete and valid, so they will not fail to be read
+fn get_built_in_theme_colors(theme_name: &str) -> Map<String, Value> {
+    let (_, content) = BUILT_IN_THEMES
+        .iter()
+        .find(|(name, _)| *name == theme_name)
+        .unwrap_or(&BUILT_IN_THEMES[0]);
+    match serde_json::from_str(content) {
+        Ok(Value::Object(colors)) => colors,
+        _ => Map::new(),
+    }
+}
+
+// the problem will be returned when the theme could not be saved, the problems found in the settings on start up
+// were already reported and will not be returned again
+pub fn set_theme(theme_string: String) -> Vec<String> {
+    match write_theme_to_app_config(&theme_string) {
+        Ok(()) => vec![],
+        Err(warning) => vec![warning],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_fixture_theme(theme_name: &str) -> (Map<String, Value>, Vec<String>) {
+        let fixture_theme_directory =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/themes");
+        let mut warnings = vec![];
+        let colors = load_theme(
+            theme_name,
+            Some(&fixture_theme_directory),
+            &mut vec![],
+            &mut warnings,
+        );
+        (colors, warnings)
+    }
+
+    #[test]
+    fn built_in_themes_are_complete_and_valid() {
+        let mut default_keys: Vec<String> = get_built_in_theme_colors(DEFAULT_THEME_NAME)
+            .keys()
+            .cloned()
+            .collect();
+        default_keys.sort();
+        for (theme_name, content) in BUILT_IN_THEMES {
+            let colors: Map<String, Value> = serde_json::from_str(content).unwrap();
+            let mut keys: Vec<String> = colors.keys().cloned().collect();
+            keys.sort();
+            assert_eq!(keys, default_keys, "{} has different keys", theme_name);
+            if let Err(error) = serde_json::from_str::<AppColorInfo>(content) {
+                panic!("{} is not valid: {}", theme_name, error);
+            }
+        }
+    }
+
+    #[test]
+    fn user_theme_inherits_the_colors_it_does_not_give() {
+        let dracula = get_built_in_theme_colors("dracula");
+        let (colors, warnings) = load_fixture_theme("child");
+        assert_eq!(colors["background_color"], "#000000");
+        // from parent.toml, which inherits the rest from the built-in dracula
+        assert_eq!(colors["cpu_base_graph_color"], "light-magenta");
+        assert_eq!(colors["app_title_color"], dracula["app_title_color"]);
+        assert_eq!(colors.len(), dracula.len());
+        assert_eq!(
+            warnings,
+            vec!["~/.rtop/themes/parent.toml `unknown_color`: unknown key, it was ignored"]
+        );
+    }
+
+    #[test]
+    fn user_theme_can_change_the_built_in_theme_of_the_same_name() {
+        let dracula = get_built_in_theme_colors("dracula");
+        let (colors, warnings) = load_fixture_theme("dracula");
+        assert_eq!(colors["key_text_color"], "#FF0000");
+        assert_eq!(colors["background_color"], dracula["background_color"]);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn inheritance_loop_falls_back_to_the_default_theme() {
+        let default = get_built_in_theme_colors(DEFAULT_THEME_NAME);
+        let (colors, warnings) = load_fixture_theme("loop_a");
+        assert_eq!(colors, default);
+        assert_eq!(
+            warnings,
+            vec![
+                "~/.rtop/themes/loop_b.json `base`: loop_a inherits from loop_b in a loop, the default theme was used"
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_or_missing_theme_falls_back_to_the_default_colors() {
+        let default = get_built_in_theme_colors(DEFAULT_THEME_NAME);
+        let (colors, warnings) = load_fixture_theme("invalid_color");
+        assert_eq!(colors["background_color"], default["background_color"]);
+        assert_eq!(colors["key_text_color"], "42");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("`background_color`: \"not a color\" is not a valid color"));
+
+        let (colors, warnings) = load_fixture_theme("missing");
+        assert_eq!(colors, default);
+        assert_eq!(
+            warnings,
+            vec!["theme `missing` was not found, the default theme was used"]
+        );
+    }
+
+    #[test]
+    fn colors_are_mapped_to_the_color_mode() {
+        let orange = Color::Rgb(255, 135, 0);
+        assert_eq!(
+            get_color_for_color_mode(orange, ColorMode::TrueColor),
+            orange
+        );
+        assert_eq!(
+            get_color_for_color_mode(orange, ColorMode::Ansi256),
+            Color::Indexed(208)
+        );
+        assert_eq!(
+            get_color_for_color_mode(orange, ColorMode::Ansi16),
+            Color::Yellow
+        );
+        assert_eq!(
+            get_color_for_color_mode(Color::Indexed(196), ColorMode::Ansi16),
+            Color::LightRed
+        );
+        assert_eq!(
+            get_color_for_color_mode(Color::LightBlue, ColorMode::Ansi16),
+            Color::LightBlue
+        );
+        assert_eq!(
+            get_color_for_color_mode(orange, ColorMode::NoColor),
+            Color::Reset
+        );
+        // grays are closer to the grayscale ramp than to the color cube
+        assert_eq!(get_nearest_ansi_256_color_index((128, 128, 128)), 244);
+        assert_eq!(get_ansi_256_color_rgb(244), (128, 128, 128));
+        assert_eq!(get_ansi_256_color_rgb(208), (255, 135, 0));
+    }
+
+    #[test]
+    fn terminal_background_response_is_parsed() {
+        let (red, green, blue) =
+            parse_terminal_background_response("\x1b]11;rgb:ffff/8080/0000\x1b\\\x1b[?62;22c")
+                .unwrap();
+        assert_eq!(
+            (red, green, blue),
+            (1.0, 0x8080 as f64 / 0xffff as f64, 0.0)
+        );
+        // 1 to 4 hex digits for each of red, green and blue, ended with BEL
+        assert_eq!(
+            parse_terminal_background_response("\x1b]11;rgb:f/80/000\x07"),
+            Some((1.0, 0x80 as f64 / 0xff as f64, 0.0))
+        );
+        // only the answer of DA1 from a terminal that does not support OSC 11
+        assert_eq!(parse_terminal_background_response("\x1b[?1;2c"), None);
+        assert_eq!(
+            parse_terminal_background_response("\x1b]11;rgb:ffff/8080"),
+            None
+        );
+    }
+
+    #[test]
+    fn device_attributes_response_ends_the_query() {
+        assert!(!is_device_attributes_response_read(
+            b"\x1b]11;rgb:ffff/8080/0000\x1b\\"
+        ));
+        assert!(!is_device_attributes_response_read(
+            b"\x1b]11;rgb:ffff/8080/0000\x1b\\\x1b[?62;2"
+        ));
+        assert!(is_device_attributes_response_read(
+            b"\x1b]11;rgb:ffff/8080/0000\x1b\\\x1b[?62;22c"
+        ));
+        assert!(is_device_attributes_response_read(b"\x1b[?1;2c"));
+    }
 }
@@ -1,147 +0,0 @@
-use ratatui::style::Color;
-
-use crate::types::AppColorInfo;
-
-pub const GRUVBOX_DARK: AppColorInfo = AppColorInfo {
-    background_color: Color::Rgb(29, 32, 33),
-    base_app_text_color: Color::Rgb(168, 153, 132),
-    key_text_color: Color::Rgb(215, 153, 33),
-    app_title_color: Color::Rgb(235, 219, 178),
-    pop_up_color: Color::Rgb(40, 40, 40),
-    pop_up_selected_color_bg: Color::Rgb(250, 189, 47),
-    pop_up_blur_bg: Color::Rgb(40, 40, 40),
-
-    cpu_container_selected_color: Color::Rgb(250, 189, 47),
-    cpu_main_block_color: Color::Rgb(40, 40, 40),
-    cpu_selected_color: Color::Rgb(250, 189, 47),
-    cpu_base_graph_color: Color::Rgb(184, 187, 38),
-    cpu_info_block_color: Color::Rgb(40, 40, 40),
-    cpu_text_color: Color::Rgb(184, 187, 38),
-
-    memory_container_selected_color: Color::Rgb(250, 189, 47),
-    memory_main_block_color: Color::Rgb(40, 40, 40),
-    used_memory_base_graph_color: Color::Rgb(204, 36, 29),
-    available_memory_base_graph_color: Color::Rgb(215, 153, 33),
-    free_memory_base_graph_color: Color::Rgb(152, 151, 26),
-    cached_memory_base_graph_color: Color::Rgb(69, 133, 136),
-    swap_memory_base_graph_color: Color::Rgb(211, 134, 155),
-    memory_text_color: Color::Rgb(69, 133, 136),
-
-    disk_container_selected_color: Color::Rgb(250, 189, 47),
-    disk_main_block_color: Color::Rgb(40, 40, 40),
-    disk_bytes_written_base_graph_color: Color::Rgb(177, 98, 134),
-    disk_bytes_read_base_graph_color: Color::Rgb(108, 113, 196),
-    disk_text_color: Color::Rgb(69, 133, 136),
-
-    network_container_selected_color: Color::Rgb(250, 189, 47),
-    network_main_block_color: Color::Rgb(40, 40, 40),
-    network_received_base_graph_color: Color::Rgb(61, 64, 112),
-    network_transmitted_base_graph_color: Color::Rgb(112, 28, 69),
-    network_info_block_color: Color::Rgb(40, 40, 40),
-    network_text_color: Color::Rgb(108, 113, 196),
-
-    process_container_selected_color: Color::Rgb(250, 189, 47),
-    process_main_block_color: Color::Rgb(40, 40, 40),
-    process_base_graph_color: Color::Rgb(152, 151, 26),
-    process_info_block_color: Color::Rgb(40, 40, 40),
-    process_title_color: Color::Rgb(250, 189, 47),
-    process_text_color: Color::Rgb(152, 151, 26),
-    process_selected_color_bg: Color::Rgb(40, 40, 40),
-    process_selected_color_fg: Color::Rgb(250, 189, 47),
-};
-
-pub const GRUVBOX_LIGHT: AppColorInfo = AppColorInfo {
-    background_color: Color::Rgb(251, 241, 199),
-    base_app_text_color: Color::Rgb(60, 56, 54),
-    key_text_color: Color::Rgb(204, 36, 29),
-    app_title_color: Color::Rgb(60, 56, 54),
-    pop_up_color: Color::Rgb(168, 153, 132),
-    pop_up_selected_color_bg: Color::Rgb(143, 63, 113),
-    pop_up_blur_bg: Color::Rgb(235, 219, 178),
-
-    cpu_container_selected_color: Color::Rgb(143, 63, 113),
-    cpu_main_block_color: Color::Rgb(168, 153, 132),
-    cpu_selected_color: Color::Rgb(143, 63, 113),
-    cpu_base_graph_color: Color::Rgb(66, 123, 88),
-    cpu_info_block_color: Color::Rgb(168, 153, 132),
-    cpu_text_color: Color::Rgb(66, 123, 88),
-
-    memory_container_selected_color: Color::Rgb(143, 63, 113),
-    memory_main_block_color: Color::Rgb(168, 153, 132),
-    used_memory_base_graph_color: Color::Rgb(66, 123, 88),
-    available_memory_base_graph_color: Color::Rgb(204, 36, 29),
-    free_memory_base_graph_color: Color::Rgb(204, 36, 29),
-    cached_memory_base_graph_color: Color::Rgb(69, 133, 136),
-    swap_memory_base_graph_color: Color::Rgb(69, 133, 136),
-    memory_text_color: Color::Rgb(69, 133, 136),
-
-    disk_container_selected_color: Color::Rgb(143, 63, 113),
-    disk_main_block_color: Color::Rgb(168, 153, 132),
-    disk_bytes_written_base_graph_color: Color::Rgb(204, 36, 29),
-    disk_bytes_read_base_graph_color: Color::Rgb(152, 151, 26),
-    disk_text_color: Color::Rgb(69, 133, 136),
-
-    network_container_selected_color: Color::Rgb(143, 63, 113),
-    network_main_block_color: Color::Rgb(168, 153, 132),
-    network_received_base_graph_color: Color::Rgb(152, 151, 26),
-    network_transmitted_base_graph_color: Color::Rgb(204, 36, 29),
-    network_info_block_color: Color::Rgb(168, 153, 132),
-    network_text_color: Color::Rgb(152, 151, 26),
-
-    process_container_selected_color: Color::Rgb(143, 63, 113),
-    process_main_block_color: Color::Rgb(168, 153, 132),
-    process_base_graph_color: Color::Rgb(66, 123, 88),
-    process_info_block_color: Color::Rgb(168, 153, 132),
-    process_title_color: Color::Rgb(152, 151, 26),
-    process_text_color: Color::Rgb(152, 151, 26),
-    process_selected_color_bg: Color::Rgb(242, 229, 188),
-    process_selected_color_fg: Color::Rgb(143, 63, 113),
-};
-
-pub const GRUVBOX_MAT_DARK: AppColorInfo = AppColorInfo {
-    background_color: Color::Rgb(40, 40, 40),
-    base_app_text_color: Color::Rgb(212, 190, 152),
-    key_text_color: Color::Rgb(234, 105, 98),
-    app_title_color: Color::Rgb(212, 190, 152),
-    pop_up_color: Color::Rgb(124, 111, 100),
-    pop_up_selected_color_bg: Color::Rgb(216, 166, 87),
-    pop_up_blur_bg: Color::Rgb(40, 40, 40),
-
-    cpu_container_selected_color: Color::Rgb(216, 166, 87),
-    cpu_main_block_color: Color::Rgb(124, 111, 100),
-    cpu_selected_color: Color::Rgb(216, 166, 87),
-    cpu_base_graph_color: Color::Rgb(169, 182, 101),
-    cpu_info_block_color: Color::Rgb(124, 111, 100),
-    cpu_text_color: Color::Rgb(169, 182, 101),
-
-    memory_container_selected_color: Color::Rgb(216, 166, 87),
-    memory_main_block_color: Color::Rgb(124, 111, 100),
-    used_memory_base_graph_color: Color::Rgb(234, 105, 98),
-    available_memory_base_graph_color: Color::Rgb(216, 166, 87),
-    free_memory_base_graph_color: Color::Rgb(137, 180, 130),
-    cached_memory_base_graph_color: Color::Rgb(125, 174, 163),
-    swap_memory_base_graph_color: Color::Rgb(137, 180, 130),
-    memory_text_color: Color::Rgb(125, 174, 163),
-
-    disk_container_selected_color: Color::Rgb(216, 166, 87),
-    disk_main_block_color: Color::Rgb(124, 111, 100),
-    disk_bytes_written_base_graph_color: Color::Rgb(211, 134, 155),
-    disk_bytes_read_base_graph_color: Color::Rgb(231, 138, 78),
-    disk_text_color: Color::Rgb(125, 174, 163),
-
-    network_container_selected_color: Color::Rgb(216, 166, 87),
-    network_main_block_color: Color::Rgb(124, 111, 100),
-    network_received_base_graph_color: Color::Rgb(231, 138, 78),
-    network_transmitted_base_graph_color: Color::Rgb(211, 134, 155),
-    network_info_block_color: Color::Rgb(124, 111, 100),
-    network_text_color: Color::Rgb(231, 138, 78),
-
-    process_container_selected_color: Color::Rgb(216, 166, 87),
-    process_main_block_color: Color::Rgb(124, 111, 100),
-    process_base_graph_color: Color::Rgb(169, 182, 101),
-    process_info_block_color: Color::Rgb(124, 111, 100),
-    process_title_color: Color::Rgb(216, 166, 87),
-    process_text_color: Color::Rgb(169, 182, 101),
-    process_selected_color_bg: Color::Rgb(216, 166, 87),
-    process_selected_color_fg: Color::Rgb(40, 40, 40),
-};
@@ -1,19 +0,0 @@
-pub mod ayu;
-pub mod catppuccin;
-pub mod default;
-pub mod dracula;
-pub mod everforest;
-pub mod flatremix;
-pub mod github;
-pub mod grayscale;
-pub mod gruvbox;
-pub mod horizon;
-pub mod kanagawa;
-pub mod matcha;
-pub mod monokai;
-pub mod nightowl;
-pub mod onedark;
-pub mod paper;
-pub mod rosepine;
-pub mod solarized;
-pub mod tokyo;
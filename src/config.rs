@@ -0,0 +1,547 @@
+use std::{
+    fs::OpenOptions,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use serde_json::{Map, Value};
+
+use crate::{
+    types::{AppConfig, LayoutConfig, LayoutNode, LayoutPanel, CONFIG_VERSION},
+    utils::{create_file_with_dirs, get_user_directory},
+};
+
+static CONFIG_FILEPATH: OnceLock<PathBuf> = OnceLock::new();
+
+// the fastest and the slowest refresh rate allowed, in ms
+pub const MIN_TICK: u32 = 100;
+pub const MAX_TICK: u32 = 10000;
+// the range of graph that can be adjusted with [ and ], the maximum need to be the same as MAXIMUM_DATA_COLLECTION in types.rs
+pub const MIN_GRAPH_RANGE: usize = 100;
+pub const MAX_GRAPH_RANGE: usize = 500;
+
+// this need to be called before the settings was loaded, subsequent calls will be ignored
+pub fn set_config_filepath(config_filepath: Option<String>) {
+    if let Some(config_filepath) = config_filepath {
+        let _ = CONFIG_FILEPATH.set(PathBuf::from(config_filepath));
+    }
+}
+
+// the settings will be at ~/.rtop/settings.json unless another one was given with --config
+pub fn get_config_filepath() -> PathBuf {
+    CONFIG_FILEPATH
+        .get_or_init(|| get_user_directory().join(".rtop/settings.json"))
+        .clone()
+}
+
+// the path of the settings shown along with its problems, with the home directory shortened to ~
+pub fn get_config_display_path() -> String {
+    let config_filepath = get_config_filepath();
+    match config_filepath.strip_prefix(get_user_directory()) {
+        Ok(relative_path) => format!("~/{}", relative_path.display()),
+        Err(_) => config_filepath.display().to_string(),
+    }
+}
+
+// read the settings, the default settings will be written if the settings does not exist yet
+//
+// the settings will never stop rtop from starting, the keys that are invalid will fall back to the default
+// and the problems found will be returned as warnings to be shown to user.
+// when the settings need to be corrected or migrated, the original file will be kept as a backup before it was rewritten
+pub fn load_app_config() -> (AppConfig, Vec<String>) {
+    let config_filepath = get_config_filepath();
+    if !config_filepath.exists() {
+        let app_config = AppConfig::default();
+        // rtop can still run with the default settings if the settings can't be created
+        let _ = write_app_config(&app_config);
+        return (app_config, vec![]);
+    }
+
+    // the settings will not be rewritten if it can't be read, it might be a permission problem that user can fix
+    let content = match std::fs::read_to_string(&config_filepath) {
+        Ok(content) => content,
+        Err(error) => {
+            return (
+                AppConfig::default(),
+                vec![format!(
+                    "could not be read ( {} ), the default settings were used",
+                    error
+                )],
+            )
+        }
+    };
+
+    let object = match serde_json::from_str::<Value>(&content) {
+        Ok(Value::Object(object)) => object,
+        Ok(_) => {
+            let mut warnings =
+                vec!["is not a JSON object, the default settings were used".to_string()];
+            let app_config = AppConfig::default();
+            backup_and_write_app_config(&config_filepath, &app_config, &mut warnings);
+            return (app_config, warnings);
+        }
+        Err(error) => {
+            let mut warnings = vec![format!(
+                "is not valid JSON ( {} ), the default settings were used",
+                error
+            )];
+            let app_config = AppConfig::default();
+            backup_and_write_app_config(&config_filepath, &app_config, &mut warnings);
+            return (app_config, warnings);
+        }
+    };
+
+    // settings written by a newer rtop will be left as it is, so that the newer rtop can still read it
+    let is_written_by_newer_rtop = object
+        .get("version")
+        .and_then(|version| version.as_u64())
+        .is_some_and(|version| version > CONFIG_VERSION as u64);
+    let is_current_version = object.get("version") == Some(&Value::from(CONFIG_VERSION));
+    let (app_config, mut warnings) = parse_app_config(object);
+    if !is_written_by_newer_rtop && (!is_current_version || !warnings.is_empty()) {
+        backup_and_write_app_config(&config_filepath, &app_config, &mut warnings);
+    }
+
+    (app_config, warnings)
+}
+
+// migrate and check the settings read from the file, the keys that are invalid will fall back to the default
+// along with the problems found
+fn parse_app_config(mut object: Map<String, Value>) -> (AppConfig, Vec<String>) {
+    let mut warnings = vec![];
+
+    // settings without the version were written before the schema was versioned
+    let version = match object.get("version") {
+        None => 1,
+        Some(version) => match version.as_u64() {
+            Some(version) if version >= 1 => version as u32,
+            _ => {
+                warnings.push(format!(
+                    "`version`: {} is not a valid version, assumed to be version {}",
+                    version, CONFIG_VERSION
+                ));
+                CONFIG_VERSION
+            }
+        },
+    };
+    if version > CONFIG_VERSION {
+        warnings.push(format!(
+            "was written by a newer rtop ( version {} ), the keys that are not known were ignored",
+            version
+        ));
+    }
+
+    migrate_app_config(&mut object, version);
+    remove_invalid_keys(&mut object, &[], &mut warnings);
+
+    // every key left was valid on its own, so this should not fail
+    let mut app_config: AppConfig =
+        serde_json::from_value(Value::Object(object)).unwrap_or_default();
+    clamp_app_config(&mut app_config, &mut warnings);
+    remove_invalid_layouts(&mut app_config, &mut warnings);
+
+    (app_config, warnings)
+}
+
+// move the settings forward one version at a time, each step only need to know about the version before it
+// eg. a key renamed in version 3 will be `if from_version < 3 { <move the old key to the new key> }`
+//
+// version 1 to 2: version 1 only had the theme and the proc/sys root, which were kept as it is in version 2,
+// the keys added in version 2 will be filled with the default
+fn migrate_app_config(object: &mut Map<String, Value>, from_version: u32) {
+    if from_version < CONFIG_VERSION {
+        object.insert("version".to_string(), Value::from(CONFIG_VERSION));
+    }
+}
+
+// check each key on its own, so that only the invalid keys will fall back to the default instead of the whole settings
+// nested keys like "process.sort" will be checked individually as well
+fn remove_invalid_keys(
+    object: &mut Map<String, Value>,
+    parent_keys: &[&str],
+    warnings: &mut Vec<String>,
+) {
+    let keys: Vec<String> = object.keys().cloned().collect();
+    for key in keys {
+        let mut path = parent_keys.to_vec();
+        path.push(&key);
+
+        let value = object[&key].clone();
+        let Err(error) =
+            serde_json::from_value::<AppConfig>(wrap_in_parent_keys(&path, value.clone()))
+        else {
+            continue;
+        };
+
+        // the key was known to hold an object, only its children were invalid
+        if let Value::Object(mut child_object) = value {
+            if serde_json::from_value::<AppConfig>(wrap_in_parent_keys(
+                &path,
+                Value::Object(Map::new()),
+            ))
+            .is_ok()
+            {
+                remove_invalid_keys(&mut child_object, &path, warnings);
+                object.insert(key, Value::Object(child_object));
+                continue;
+            }
+        }
+
+        let error = error.to_string();
+        if error.starts_with("unknown field") {
+            warnings.push(format!("`{}`: unknown key, it was ignored", path.join(".")));
+        } else {
+            warnings.push(format!(
+                "`{}`: {}, the default was used",
+                path.join("."),
+                error
+            ));
+        }
+        object.remove(&key);
+    }
+}
+
+// eg. ["process", "sort"] and "name" -> {"process": {"sort": "name"}}
+fn wrap_in_parent_keys(path: &[&str], value: Value) -> Value {
+    path.iter().rev().fold(value, |value, key| {
+        let mut object = Map::new();
+        object.insert(key.to_string(), value);
+        Value::Object(object)
+    })
+}
+
+// keep the values that were valid but out of range within the range that rtop can work with
+fn clamp_app_config(app_config: &mut AppConfig, warnings: &mut Vec<String>) {
+    if app_config.tick < MIN_TICK {
+        warnings.push(format!(
+            "`tick`: {} is faster than the minimum of {}ms, {}ms was used",
+            app_config.tick, MIN_TICK, MIN_TICK
+        ));
+        app_config.tick = MIN_TICK;
+    } else if app_config.tick > MAX_TICK {
+        warnings.push(format!(
+            "`tick`: {} is slower than the maximum of {}ms, {}ms was used",
+            app_config.tick, MAX_TICK, MAX_TICK
+        ));
+        app_config.tick = MAX_TICK;
+    }
+
+    let graph_range = &mut app_config.graph_range;
+    for (name, range) in [
+        ("cpu", &mut graph_range.cpu),
+        ("memory", &mut graph_range.memory),
+        ("disk", &mut graph_range.disk),
+        ("network", &mut graph_range.network),
+        ("process", &mut graph_range.process),
+        ("battery", &mut graph_range.battery),
+        ("cgroup", &mut graph_range.cgroup),
+        ("interrupt", &mut graph_range.interrupt),
+    ] {
+        if *range < MIN_GRAPH_RANGE || *range > MAX_GRAPH_RANGE {
+            let clamped_range = (*range).clamp(MIN_GRAPH_RANGE, MAX_GRAPH_RANGE);
+            warnings.push(format!(
+                "`graph_range.{}`: {} is not within {} to {}, {} was used",
+                name, range, MIN_GRAPH_RANGE, MAX_GRAPH_RANGE, clamped_range
+            ));
+            *range = clamped_range;
+        }
+    }
+}
+
+// the layouts were valid JSON, but only the layouts that can be drawn will be kept
+fn remove_invalid_layouts(app_config: &mut AppConfig, warnings: &mut Vec<String>) {
+    let mut layouts = vec![];
+    for (index, layout) in app_config.layouts.iter().enumerate() {
+        match get_layout_node_problem(&layout.get_root_node(), &mut vec![]) {
+            Some(problem) => warnings.push(format!(
+                "`layouts.{}` ( {} ): {}, the layout was ignored",
+                index, layout.name, problem
+            )),
+            None => layouts.push(layout.clone()),
+        }
+    }
+
+    if layouts.is_empty() {
+        warnings
+            .push("`layouts`: there is no valid layout, the default layouts were used".to_string());
+        layouts = LayoutConfig::get_default_layouts();
+    }
+    app_config.layouts = layouts;
+}
+
+// each node need to be either a panel or a split into rows or columns, and each panel can only be placed once
+fn get_layout_node_problem(
+    node: &LayoutNode,
+    placed_panels: &mut Vec<LayoutPanel>,
+) -> Option<String> {
+    if node.ratio == 0 {
+        return Some("a ratio of 0 takes no space".to_string());
+    }
+
+    let children = match (node.panel, node.rows.is_empty(), node.columns.is_empty()) {
+        (Some(panel), true, true) => {
+            if placed_panels.contains(&panel) {
+                return Some(format!(
+                    "`{}` was placed more than once",
+                    format!("{:?}", panel).to_lowercase()
+                ));
+            }
+            placed_panels.push(panel);
+            return None;
+        }
+        (None, false, true) => &node.rows,
+        (None, true, false) => &node.columns,
+        _ => {
+            return Some(
+                "each node need to have exactly one of \"panel\", \"rows\" or \"columns\""
+                    .to_string(),
+            )
+        }
+    };
+    children
+        .iter()
+        .find_map(|child| get_layout_node_problem(child, placed_panels))
+}
+
+// keep the original settings next to it before rewriting, eg. settings.json.20250101-120000.bak
+fn backup_and_write_app_config(
+    config_filepath: &Path,
+    app_config: &AppConfig,
+    warnings: &mut Vec<String>,
+) {
+    let backup_filepath = config_filepath.with_file_name(format!(
+        "{}.{}.bak",
+        config_filepath
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().to_string())
+            .unwrap_or("settings.json".to_string()),
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    match std::fs::copy(config_filepath, &backup_filepath) {
+        Ok(_) => {
+            // only the problems need to be pointed to the backup, a migration alone was not worth a warning
+            if !warnings.is_empty() {
+                warnings.push(format!(
+                    "was rewritten, the original was backed up to {}",
+                    backup_filepath.display()
+                ));
+            }
+            if let Err(error) = write_app_config(app_config) {
+                warnings.push(format!("could not be rewritten ( {} )", error));
+            }
+        }
+        // the original settings will be left as it is if it can't be backed up
+        Err(error) => warnings.push(format!(
+            "could not be backed up ( {} ), it was not rewritten",
+            error
+        )),
+    }
+}
+
+// only the theme will be changed and the other keys will be kept as it is, including the keys that are not known,
+// settings written by a newer rtop will be left as it is, the same as loading the settings
+pub fn write_theme_to_app_config(theme: &str) -> Result<(), String> {
+    let config_filepath = get_config_filepath();
+    if !config_filepath.exists() {
+        let app_config = AppConfig {
+            theme: theme.to_string(),
+            ..AppConfig::default()
+        };
+        return write_app_config(&app_config)
+            .map_err(|error| format!("could not be written ( {} )", error));
+    }
+
+    let content = std::fs::read_to_string(&config_filepath)
+        .map_err(|error| format!("could not be read ( {} ), the theme was not saved", error))?;
+    let content = set_theme_in_settings(&content, theme)?;
+    std::fs::write(&config_filepath, content)
+        .map_err(|error| format!("could not be written ( {} )", error))
+}
+
+// the settings with only the theme changed, the other keys will be kept in the same order
+fn set_theme_in_settings(content: &str, theme: &str) -> Result<String, String> {
+    let mut object = match serde_json::from_str::<Value>(content) {
+        Ok(Value::Object(object)) => object,
+        _ => return Err("is not a valid JSON object, the theme was not saved".to_string()),
+    };
+    if let Some(version) = object.get("version").and_then(|version| version.as_u64()) {
+        if version > CONFIG_VERSION as u64 {
+            return Err(format!(
+                "was written by a newer rtop ( version {} ), the theme was not saved",
+                version
+            ));
+        }
+    }
+    object.insert("theme".to_string(), Value::from(theme));
+
+    serde_json::to_string_pretty(&Value::Object(object))
+        .map_err(|error| format!("could not be written ( {} )", error))
+}
+
+// missing keys were filled with the default when read, so older settings will be written as the current version
+pub fn write_app_config(app_config: &AppConfig) -> std::io::Result<()> {
+    let app_config = AppConfig {
+        version: CONFIG_VERSION,
+        ..app_config.clone()
+    };
+    let config_filepath = get_config_filepath();
+    create_file_with_dirs(config_filepath.to_str().unwrap());
+    let file = OpenOptions::new()
+        .write(true)
+        .create(true) // Create the file if it doesn't exist
+        .truncate(true) // Truncate the file to ensure it's empty before writing
+        .open(&config_filepath)?;
+
+    // write the data into the json file, pretty printed so that it can be edited by hand
+    serde_json::to_writer_pretty(file, &app_config)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_app_config_content(content: &str) -> (AppConfig, Vec<String>) {
+        match serde_json::from_str::<Value>(content).unwrap() {
+            Value::Object(object) => parse_app_config(object),
+            _ => panic!("{} is not a JSON object", content),
+        }
+    }
+
+    #[test]
+    fn version_1_settings_are_migrated() {
+        let (app_config, warnings) =
+            parse_app_config_content(r#"{ "theme": "dracula", "proc_root": "/host/proc" }"#);
+        assert_eq!(app_config.version, CONFIG_VERSION);
+        assert_eq!(app_config.theme, "dracula");
+        assert_eq!(app_config.proc_root.as_deref(), Some("/host/proc"));
+        assert_eq!(app_config.tick, AppConfig::default().tick);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn only_the_invalid_keys_fall_back_to_the_default() {
+        let (app_config, warnings) = parse_app_config_content(
+            r#"{
+                "version": 2,
+                "theme": "dracula",
+                "tick": "fast",
+                "process": { "sort": "nope", "reverse": false },
+                "unknown": 1
+            }"#,
+        );
+        assert_eq!(app_config.theme, "dracula");
+        assert_eq!(app_config.tick, AppConfig::default().tick);
+        assert_eq!(app_config.process.sort, AppConfig::default().process.sort);
+        assert!(!app_config.process.reverse);
+        assert_eq!(warnings.len(), 3);
+        // in the order of the keys in the settings
+        assert!(warnings[0].starts_with("`tick`: "));
+        assert!(warnings[1].starts_with("`process.sort`: "));
+        assert!(warnings[1].ends_with(", the default was used"));
+        assert_eq!(warnings[2], "`unknown`: unknown key, it was ignored");
+    }
+
+    #[test]
+    fn invalid_and_newer_versions_are_reported() {
+        let (app_config, warnings) = parse_app_config_content(r#"{ "version": "two" }"#);
+        assert_eq!(app_config.tick, AppConfig::default().tick);
+        assert_eq!(
+            warnings[0],
+            "`version`: \"two\" is not a valid version, assumed to be version 2"
+        );
+
+        let (app_config, warnings) =
+            parse_app_config_content(r#"{ "version": 3, "theme": "dracula", "new_key": true }"#);
+        assert_eq!(app_config.theme, "dracula");
+        assert_eq!(
+            warnings,
+            vec![
+                "was written by a newer rtop ( version 3 ), the keys that are not known were ignored",
+                "`new_key`: unknown key, it was ignored",
+            ]
+        );
+    }
+
+    #[test]
+    fn out_of_range_values_are_clamped() {
+        let (app_config, warnings) = parse_app_config_content(
+            r#"{ "version": 2, "tick": 10, "graph_range": { "cpu": 50, "disk": 1000 } }"#,
+        );
+        assert_eq!(app_config.tick, MIN_TICK);
+        assert_eq!(app_config.graph_range.cpu, MIN_GRAPH_RANGE);
+        assert_eq!(app_config.graph_range.disk, MAX_GRAPH_RANGE);
+        assert_eq!(
+            warnings,
+            vec![
+                "`tick`: 10 is faster than the minimum of 100ms, 100ms was used",
+                "`graph_range.cpu`: 50 is not within 100 to 500, 100 was used",
+                "`graph_range.disk`: 1000 is not within 100 to 500, 500 was used",
+            ]
+        );
+
+        let (app_config, warnings) =
+            parse_app_config_content(r#"{ "version": 2, "tick": 4000000000 }"#);
+        assert_eq!(app_config.tick, MAX_TICK);
+        assert_eq!(
+            warnings,
+            vec!["`tick`: 4000000000 is slower than the maximum of 10000ms, 10000ms was used"]
+        );
+    }
+
+    #[test]
+    fn layouts_that_can_not_be_drawn_are_ignored() {
+        let (app_config, warnings) = parse_app_config_content(
+            r#"{
+                "version": 2,
+                "layouts": [
+                    { "name": "twice", "rows": [{ "panel": "cpu" }, { "panel": "cpu" }] },
+                    { "name": "empty", "rows": [{ "ratio": 0, "panel": "cpu" }] },
+                    { "name": "both", "rows": [{ "panel": "cpu", "columns": [{ "panel": "disk" }] }] },
+                    { "name": "valid", "columns": [{ "panel": "process" }, { "panel": "cgroup" }] }
+                ]
+            }"#,
+        );
+        assert_eq!(app_config.layouts.len(), 1);
+        assert_eq!(app_config.layouts[0].name, "valid");
+        assert_eq!(
+            warnings,
+            vec![
+                "`layouts.0` ( twice ): `cpu` was placed more than once, the layout was ignored",
+                "`layouts.1` ( empty ): a ratio of 0 takes no space, the layout was ignored",
+                "`layouts.2` ( both ): each node need to have exactly one of \"panel\", \"rows\" or \"columns\", the layout was ignored",
+            ]
+        );
+
+        let (app_config, warnings) = parse_app_config_content(
+            r#"{ "version": 2, "layouts": [{ "name": "twice", "rows": [{ "panel": "cpu" }, { "panel": "cpu" }] }] }"#,
+        );
+        assert_eq!(
+            app_config.layouts.len(),
+            LayoutConfig::get_default_layouts().len()
+        );
+        assert_eq!(
+            warnings.last().unwrap(),
+            "`layouts`: there is no valid layout, the default layouts were used"
+        );
+    }
+
+    #[test]
+    fn only_the_theme_is_changed_in_the_settings() {
+        let content = set_theme_in_settings(
+            r#"{ "tick": 500, "theme": "default", "version": 2, "unknown": [1, 2] }"#,
+            "dracula",
+        )
+        .unwrap();
+        assert_eq!(
+            content,
+            "{\n  \"tick\": 500,\n  \"theme\": \"dracula\",\n  \"version\": 2,\n  \"unknown\": [\n    1,\n    2\n  ]\n}"
+        );
+
+        assert_eq!(
+            set_theme_in_settings(r#"{ "version": 3, "theme": "default" }"#, "dracula"),
+            Err("was written by a newer rtop ( version 3 ), the theme was not saved".to_string())
+        );
+        assert!(set_theme_in_settings("[]", "dracula").is_err());
+    }
+}
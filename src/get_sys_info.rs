@@ -1,17 +1,77 @@
 use std::{
     collections::HashMap,
-    sync::mpsc::{Receiver, RecvTimeoutError, Sender},
+    path::{Path, PathBuf},
+    sync::{
+        mpsc::{Receiver, RecvTimeoutError, Sender},
+        OnceLock,
+    },
     thread,
     time::{Duration, Instant},
 };
 
 use crate::types::{
-    CCpuData, CDiskData, CMemoryData, CNetworkData, CProcessData, CProcessesInfo, CSysInfo,
+    CBatteryData, CCgroupData, CContainerLimitData, CCpuData, CDiskData, CInterruptData,
+    CMemoryData, CNetworkData, CNumaNodeData, CPowerZoneData, CProcessData, CProcessSchedulerData,
+    CProcessesInfo, CSessionData, CSysInfo, CVmActivityData, InterruptSourceData,
+    ProcessSecurityData,
 };
-use sysinfo::{Disks, Networks, Process, ProcessesToUpdate, System, Users};
+use sysinfo::{Disks, Networks, Pid, Process, ProcessesToUpdate, System, Users};
+
+const DEFAULT_PROC_ROOT: &str = "/proc";
+const DEFAULT_SYS_ROOT: &str = "/sys";
+
+// alternate procfs/sysfs mount points ( eg. the host's /proc mounted at /host/proc inside a container )
+// NOTE: sysinfo always reads from /proc, so the cpu, memory, network and process list are read by rtop itself from
+// the alternate procfs ( see get_proc_root_processes ). the disks are still collected by sysinfo, as their usage can
+// only be queried from the mounted filesystems. rtop's own cgroup and the processes signals are sent to are read
+// with get_local_proc_path
+static PROC_ROOT: OnceLock<PathBuf> = OnceLock::new();
+static SYS_ROOT: OnceLock<PathBuf> = OnceLock::new();
+
+// this need to be called before spawning any of the collector, subsequent calls will be ignored
+pub fn set_proc_and_sys_root(proc_root: Option<String>, sys_root: Option<String>) {
+    let _ = PROC_ROOT.set(PathBuf::from(
+        proc_root.unwrap_or(DEFAULT_PROC_ROOT.to_string()),
+    ));
+    let _ = SYS_ROOT.set(PathBuf::from(
+        sys_root.unwrap_or(DEFAULT_SYS_ROOT.to_string()),
+    ));
+}
+
+// get the path of a file within procfs, eg. get_proc_path("meminfo") -> "/proc/meminfo"
+pub fn get_proc_path(relative_path: &str) -> PathBuf {
+    PROC_ROOT
+        .get_or_init(|| PathBuf::from(DEFAULT_PROC_ROOT))
+        .join(relative_path)
+}
+
+// the cpu, memory, network and process list are only read by rtop itself when procfs was mounted somewhere else
+#[cfg(target_os = "linux")]
+fn is_alternate_proc_root() -> bool {
+    get_proc_path("") != Path::new(DEFAULT_PROC_ROOT)
+}
+
+// get the path of a file within sysfs, eg. get_sys_path("fs/cgroup") -> "/sys/fs/cgroup"
+pub fn get_sys_path(relative_path: &str) -> PathBuf {
+    SYS_ROOT
+        .get_or_init(|| PathBuf::from(DEFAULT_SYS_ROOT))
+        .join(relative_path)
+}
+
+// get the path of a file within the procfs rtop runs in, regardless of the alternate root
+// rtop's own cgroup is needed to tell its limits, and signals can only be sent to the processes in here
+pub fn get_local_proc_path(relative_path: &str) -> PathBuf {
+    Path::new(DEFAULT_PROC_ROOT).join(relative_path)
+}
+
+// get the path of a file within the sysfs rtop runs in, regardless of the alternate root
+pub fn get_local_sys_path(relative_path: &str) -> PathBuf {
+    Path::new(DEFAULT_SYS_ROOT).join(relative_path)
+}
 
 pub fn spawn_system_info_collector(
     tick_receiver: Receiver<u32>,
+    cgroup_shown_receiver: Receiver<bool>,
     tx: Sender<CSysInfo>,
     default_tick: u32,
 ) {
@@ -23,6 +83,27 @@ pub fn spawn_system_info_collector(
         let mut last_refresh = Instant::now();
         let mut tick_value = default_tick; // Current tick in ms
 
+        // cumulative cgroup counters from the previous collection, to calculate the usage since last refresh
+        let mut cgroup_counters: HashMap<String, CgroupCounters> = HashMap::new();
+        let mut cgroup_last_collected = Instant::now();
+        // the cgroup tree will only be walked while the cgroup panel was shown, as it can be large on a busy host
+        let mut is_cgroup_shown = false;
+        // cumulative cpu usage of the cgroup rtop was running in, to calculate the usage relative to its limits
+        let mut container_cpu_usage_usec: Option<u64> = None;
+        // cumulative energy counters of each RAPL zone from the previous collection, to calculate the power draw
+        let mut power_zone_energy_uj: HashMap<String, u64> = HashMap::new();
+        let mut power_zone_last_collected = Instant::now();
+        // cumulative interrupt and /proc/stat counters from the previous collection, to calculate the rates
+        let mut interrupt_counters = InterruptCounters::default();
+        let mut interrupt_last_collected = Instant::now();
+        // cumulative /proc/vmstat counters from the previous collection, to calculate the paging and swapping activity
+        let mut vm_stat_counters: HashMap<String, u64> = HashMap::new();
+        // cumulative numastat counters of each NUMA node from the previous collection
+        let mut numa_stat_counters: HashMap<usize, HashMap<String, u64>> = HashMap::new();
+        // cumulative /proc/stat cpu times and /proc/net/dev bytes from the previous collection, only used with an alternate procfs
+        let mut proc_root_cpu_counters: HashMap<String, CpuTimeCounters> = HashMap::new();
+        let mut proc_root_network_counters: HashMap<String, (u64, u64)> = HashMap::new();
+
         sys.refresh_all();
         disks.refresh(true);
         networks.refresh(true);
@@ -47,28 +128,41 @@ pub fn spawn_system_info_collector(
                     //
                     // -------------------------------------------
 
-                    // Refresh CPU data
-                    sys.refresh_cpu_all();
-                    let cpus = sys.cpus();
-
-                    // Gather CPU data
-                    let mut cpu_data: Vec<CCpuData> = cpus
-                        .iter()
-                        .enumerate()
-                        .map(|(index, cpu)| CCpuData {
-                            id: index as i8,
-                            brand: cpu.brand().to_string(),
-                            usage: cpu.cpu_usage(),
-                        })
-                        .collect();
-
-                    // we later add cpu avg info as the first entry of the collected cpu info vector
-                    let avg_cpu_data = CCpuData {
-                        id: -1 as i8,
-                        brand: cpu_data[0].brand.clone(),
-                        usage: sys.global_cpu_usage(),
+                    // the cpu avg info is the first entry of the collected cpu info vector
+                    let cpu_data = match get_proc_root_cpus(&mut proc_root_cpu_counters) {
+                        Some(cpu_data) => cpu_data,
+                        None => {
+                            // Refresh CPU data
+                            sys.refresh_cpu_all();
+                            let cpus = sys.cpus();
+
+                            // Gather CPU data
+                            let mut cpu_data: Vec<CCpuData> = cpus
+                                .iter()
+                                .enumerate()
+                                .map(|(index, cpu)| CCpuData {
+                                    id: index as i8,
+                                    brand: cpu.brand().to_string(),
+                                    usage: cpu.cpu_usage(),
+                                })
+                                .collect();
+
+                            // we later add cpu avg info as the first entry of the collected cpu info vector
+                            let avg_cpu_data = CCpuData {
+                                id: -1 as i8,
+                                brand: cpu_data[0].brand.clone(),
+                                usage: sys.global_cpu_usage(),
+                            };
+                            cpu_data.insert(0, avg_cpu_data);
+                            cpu_data
+                        }
                     };
-                    cpu_data.insert(0, avg_cpu_data);
+
+                    let power_zone_data = get_power_zones(
+                        &mut power_zone_energy_uj,
+                        power_zone_last_collected.elapsed(),
+                    );
+                    power_zone_last_collected = Instant::now();
 
                     // -------------------------------------------
                     //
@@ -76,13 +170,33 @@ pub fn spawn_system_info_collector(
                     //
                     // -------------------------------------------
 
-                    sys.refresh_memory();
-                    let total_memory = sys.total_memory() as f64;
-                    let available_memory = sys.available_memory() as f64;
-                    let used_memory = sys.used_memory() as f64;
-                    let used_swap = sys.used_swap() as f64;
-                    let free_memory = sys.free_memory() as f64;
+                    let (total_memory, available_memory, used_memory, used_swap, free_memory) =
+                        match get_proc_root_meminfo() {
+                            // the memory that is not available is considered as used, the same as sysinfo does on linux
+                            Some(meminfo) => {
+                                let get_value =
+                                    |key: &str| meminfo.get(key).copied().unwrap_or(0.0);
+                                (
+                                    get_value("MemTotal"),
+                                    get_value("MemAvailable"),
+                                    get_value("MemTotal") - get_value("MemAvailable"),
+                                    get_value("SwapTotal") - get_value("SwapFree"),
+                                    get_value("MemFree"),
+                                )
+                            }
+                            None => {
+                                sys.refresh_memory();
+                                (
+                                    sys.total_memory() as f64,
+                                    sys.available_memory() as f64,
+                                    sys.used_memory() as f64,
+                                    sys.used_swap() as f64,
+                                    sys.free_memory() as f64,
+                                )
+                            }
+                        };
                     let cached_memory = get_cached_memory();
+                    let vm_activity = get_vm_activity(&mut vm_stat_counters);
 
                     let memory_data = CMemoryData {
                         total_memory,
@@ -91,6 +205,7 @@ pub fn spawn_system_info_collector(
                         used_swap,
                         free_memory,
                         cached_memory,
+                        vm_activity,
                     };
 
                     // -------------------------------------------
@@ -123,33 +238,97 @@ pub fn spawn_system_info_collector(
                     //          NETWORK DATA COLLECTION
                     //
                     // -------------------------------------------
-                    networks.refresh(true);
-                    let mut networks_data = Vec::new();
-                    for (interface_name, network_data) in &networks {
-                        let data = CNetworkData {
-                            interface_name: interface_name.to_string(),
-                            ip_network: if network_data.ip_networks().len() > 0 {
-                                let mut ipv4_networks = Vec::new();
-                                for ip in network_data.ip_networks() {
-                                    if ip.addr.is_ipv4() {
-                                        ipv4_networks.push(ip.addr.to_string());
-                                    }
-                                }
-                                if ipv4_networks.is_empty() {
-                                    None
-                                } else {
-                                    Some(ipv4_networks[0].clone())
+                    let networks_data =
+                        match get_proc_root_networks(&mut proc_root_network_counters) {
+                            Some(networks_data) => networks_data,
+                            None => {
+                                networks.refresh(true);
+                                let mut networks_data = Vec::new();
+                                for (interface_name, network_data) in &networks {
+                                    let data = CNetworkData {
+                                        interface_name: interface_name.to_string(),
+                                        ip_network: if network_data.ip_networks().len() > 0 {
+                                            let mut ipv4_networks = Vec::new();
+                                            for ip in network_data.ip_networks() {
+                                                if ip.addr.is_ipv4() {
+                                                    ipv4_networks.push(ip.addr.to_string());
+                                                }
+                                            }
+                                            if ipv4_networks.is_empty() {
+                                                None
+                                            } else {
+                                                Some(ipv4_networks[0].clone())
+                                            }
+                                        } else {
+                                            None
+                                        },
+                                        current_received: network_data.received() as f64,
+                                        current_transmitted: network_data.transmitted() as f64,
+                                        total_received: network_data.total_received() as f64,
+                                        total_transmitted: network_data.total_transmitted() as f64,
+                                    };
+                                    networks_data.push(data);
                                 }
-                            } else {
-                                None
-                            },
-                            current_received: network_data.received() as f64,
-                            current_transmitted: network_data.transmitted() as f64,
-                            total_received: network_data.total_received() as f64,
-                            total_transmitted: network_data.total_transmitted() as f64,
+                                networks_data
+                            }
                         };
-                        networks_data.push(data);
+
+                    // -------------------------------------------
+                    //
+                    //          CGROUP DATA COLLECTION
+                    //
+                    // -------------------------------------------
+                    while let Ok(is_shown) = cgroup_shown_receiver.try_recv() {
+                        is_cgroup_shown = is_shown;
                     }
+                    let cgroup_data = if is_cgroup_shown {
+                        Some(get_cgroups(
+                            &mut cgroup_counters,
+                            cgroup_last_collected.elapsed(),
+                            sys.cpus().len(),
+                        ))
+                    } else {
+                        // the counters would be stale by the time the panel was shown again
+                        cgroup_counters.clear();
+                        None
+                    };
+                    let container_limit_data = get_container_limit(
+                        &mut container_cpu_usage_usec,
+                        cgroup_last_collected.elapsed(),
+                        sys.cpus().len(),
+                        &memory_data,
+                    );
+                    cgroup_last_collected = Instant::now();
+
+                    // -------------------------------------------
+                    //
+                    //          BATTERY DATA COLLECTION
+                    //
+                    // -------------------------------------------
+                    let battery_data = get_batteries();
+
+                    // -------------------------------------------
+                    //
+                    //          SESSION DATA COLLECTION
+                    //
+                    // -------------------------------------------
+                    let session_data = get_sessions();
+
+                    // -------------------------------------------
+                    //
+                    //         INTERRUPT DATA COLLECTION
+                    //
+                    // -------------------------------------------
+                    let interrupt_data =
+                        get_interrupts(&mut interrupt_counters, interrupt_last_collected.elapsed());
+                    interrupt_last_collected = Instant::now();
+
+                    // -------------------------------------------
+                    //
+                    //         NUMA NODE DATA COLLECTION
+                    //
+                    // -------------------------------------------
+                    let numa_node_data = get_numa_nodes(&mut numa_stat_counters);
 
                     // -------------------------------------------
                     //
@@ -161,6 +340,13 @@ pub fn spawn_system_info_collector(
                         memory: memory_data,
                         disks: disk_data,
                         networks: networks_data,
+                        cgroups: cgroup_data,
+                        container_limit: container_limit_data,
+                        batteries: battery_data,
+                        power_zones: power_zone_data,
+                        sessions: session_data,
+                        interrupts: interrupt_data,
+                        numa_nodes: numa_node_data,
                     };
 
                     // Send the data to the main thread
@@ -180,9 +366,13 @@ pub fn spawn_system_info_collector(
     });
 }
 
+// the deleted files of a process are rescanned at most once in this interval, except for the process shown in the detail
+const DELETED_FILES_SCAN_INTERVAL: Duration = Duration::from_secs(30);
+
 // dedicate thread to collect process info only
 pub fn spawn_process_info_collector(
     tick_receiver: Receiver<u32>,
+    detail_pid_receiver: Receiver<Option<u32>>,
     tx: Sender<CProcessesInfo>,
     default_tick: u32,
 ) {
@@ -192,6 +382,17 @@ pub fn spawn_process_info_collector(
         let mut last_refresh = Instant::now();
         let mut tick_value = default_tick; // Current tick in ms
 
+        // the NUMA memory will only be collected for the process shown in the process detail, as the kernel need to
+        // walk through the page tables of the process to produce numa_maps, and only when there is more than one node
+        let numa_node_count = get_numa_node_count();
+        let mut detail_pid: Option<u32> = None;
+        // the deleted files of each process keyed by pid with its start time to tell a reused pid, and when it was scanned,
+        // as reading the whole maps of every process on every tick was expensive
+        let mut deleted_files_cache: HashMap<u32, (u64, Instant, Vec<String>)> = HashMap::new();
+        // cumulative cpu time and io bytes of each process from the previous collection, only used with an alternate procfs
+        let mut proc_root_process_counters: HashMap<u32, ProcessCounters> = HashMap::new();
+        let mut proc_root_last_collected = Instant::now();
+
         sys.refresh_all();
 
         loop {
@@ -208,65 +409,98 @@ pub fn spawn_process_info_collector(
                     continue; // don't collect this cycle, just updated tick
                 }
                 Err(RecvTimeoutError::Timeout) => {
-                    sys.refresh_processes(ProcessesToUpdate::All, true);
+                    while let Ok(pid) = detail_pid_receiver.try_recv() {
+                        detail_pid = pid;
+                    }
                     let users = Users::new_with_refreshed_list();
+                    let base_processes = match get_proc_root_processes(
+                        &mut proc_root_process_counters,
+                        proc_root_last_collected.elapsed(),
+                        &users,
+                    ) {
+                        Some(base_processes) => base_processes,
+                        None => {
+                            sys.refresh_processes(ProcessesToUpdate::All, true);
+                            sys.processes()
+                                .iter()
+                                .map(|(pid, process)| get_process_base_data(*pid, process, &users))
+                                .collect()
+                        }
+                    };
+                    proc_root_last_collected = Instant::now();
+                    // namespaces of the init process, to tell which processes were running outside of them
+                    let init_namespaces = get_process_namespaces(1);
                     let mut processes = vec![];
                     // -------------------------------------------
                     //
                     //          PROCESS INFO COLLECTION
                     //
                     // -------------------------------------------
-                    for (pid, process) in sys.processes() {
-                        let mut user = "root";
-
-                        #[cfg(any(target_os = "linux", target_os = "macos"))]
-                        let thread_count = get_thread_count(pid.as_u32() as i32, &process, None);
-
-                        #[cfg(target_os = "windows")]
-                        // let thread_hashmap_win_only = get_win_thread_counts();
-                        #[cfg(target_os = "windows")]
-                        let thread_count = get_thread_count(pid.as_u32() as i32, &process, None);
-
-                        if process.user_id().is_some() {
-                            let u = users.get_user_by_id(process.user_id().unwrap());
-                            if u.is_some() {
-                                user = u.unwrap().name();
+                    for process in base_processes {
+                        let pid = process.pid;
+                        let cgroup = get_process_cgroup(pid);
+                        let container = get_container_from_cgroup(&cgroup);
+                        let (oom_score, oom_score_adj) = get_process_oom_score(pid);
+                        // the status is shared by the security and the scheduler data
+                        let status = get_process_status(pid);
+                        let security =
+                            get_process_security(pid, status.as_deref(), &init_namespaces);
+                        let deleted_files = match deleted_files_cache.get(&pid) {
+                            Some((start_time, scanned_at, deleted_files))
+                                if *start_time == process.start_time
+                                    && scanned_at.elapsed() < DELETED_FILES_SCAN_INTERVAL
+                                    && detail_pid != Some(pid) =>
+                            {
+                                deleted_files.clone()
                             }
-                        }
-                        let process_disk_usage = process.disk_usage();
+                            _ => {
+                                let deleted_files = get_process_deleted_files(pid);
+                                deleted_files_cache.insert(
+                                    pid,
+                                    (process.start_time, Instant::now(), deleted_files.clone()),
+                                );
+                                deleted_files
+                            }
+                        };
+                        let scheduler = get_process_scheduler(pid, status.as_deref());
+                        let numa_memory = if numa_node_count > 1 && detail_pid == Some(pid) {
+                            get_process_numa_memory(pid, numa_node_count)
+                        } else {
+                            vec![]
+                        };
                         let process_info = CProcessData {
-                            pid: pid.as_u32(),
-                            name: process.name().to_string_lossy().to_string(),
-                            exe_path: if process.exe().is_some() {
-                                Some(process.exe().unwrap().to_string_lossy().to_string())
-                            } else {
-                                None
-                            },
-                            cmd: process
-                                .cmd()
-                                .into_iter()
-                                .map(|osstr| osstr.to_string_lossy().to_string())
-                                .collect(),
-                            user: user.to_string(),
-                            cpu_usage: process.cpu_usage(),
-                            thread_count,
-                            memory: process.memory() as f64,
-                            status: process.status().to_string(),
-                            elapsed: process.run_time(),
-                            parent: if process.parent().is_some() {
-                                format!("{:?}", process.parent().unwrap().as_u32())
-                            } else {
-                                "-".to_string()
-                            },
-                            current_read_disk_usage: process_disk_usage.read_bytes,
-                            total_read_disk_usage: process_disk_usage.total_read_bytes,
-                            current_write_disk_usage: process_disk_usage.written_bytes,
-                            total_write_disk_usage: process_disk_usage.total_written_bytes,
+                            pid,
+                            name: process.name,
+                            exe_path: process.exe_path,
+                            cmd: process.cmd,
+                            user: process.user,
+                            cpu_usage: process.cpu_usage,
+                            thread_count: process.thread_count,
+                            memory: process.memory,
+                            status: process.status,
+                            elapsed: process.elapsed,
+                            parent: process.parent,
+                            current_read_disk_usage: process.current_read_disk_usage,
+                            total_read_disk_usage: process.total_read_disk_usage,
+                            current_write_disk_usage: process.current_write_disk_usage,
+                            total_write_disk_usage: process.total_write_disk_usage,
+                            cgroup,
+                            container,
+                            oom_score,
+                            oom_score_adj,
+                            numa_memory,
+                            security,
+                            deleted_files,
+                            scheduler,
                         };
 
                         processes.push(process_info);
                     }
 
+                    // forget the processes which have exited
+                    deleted_files_cache
+                        .retain(|pid, _| processes.iter().any(|process| process.pid == *pid));
+
                     // -------------------------------------------
                     //
                     //  SEND COLLECTED PROCESS INFO TO MAIN THREAD
@@ -291,6 +525,77 @@ pub fn spawn_process_info_collector(
     });
 }
 
+// the part of the process info that sysinfo collects, or rtop itself when an alternate procfs was set
+struct ProcessBaseData {
+    pid: u32,
+    name: String,
+    exe_path: Option<String>,
+    cmd: Vec<String>,
+    user: String,
+    cpu_usage: f32,
+    thread_count: u32,
+    memory: f64,
+    status: String,
+    elapsed: u64,
+    parent: String,
+    start_time: u64, // to tell a reused pid
+    current_read_disk_usage: u64,
+    total_read_disk_usage: u64,
+    current_write_disk_usage: u64,
+    total_write_disk_usage: u64,
+}
+
+fn get_process_base_data(pid: Pid, process: &Process, users: &Users) -> ProcessBaseData {
+    let mut user = "root";
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    let thread_count = get_thread_count(pid.as_u32() as i32, &process, None);
+
+    #[cfg(target_os = "windows")]
+    // let thread_hashmap_win_only = get_win_thread_counts();
+    #[cfg(target_os = "windows")]
+    let thread_count = get_thread_count(pid.as_u32() as i32, &process, None);
+
+    if process.user_id().is_some() {
+        let u = users.get_user_by_id(process.user_id().unwrap());
+        if u.is_some() {
+            user = u.unwrap().name();
+        }
+    }
+    let process_disk_usage = process.disk_usage();
+
+    ProcessBaseData {
+        pid: pid.as_u32(),
+        name: process.name().to_string_lossy().to_string(),
+        exe_path: if process.exe().is_some() {
+            Some(process.exe().unwrap().to_string_lossy().to_string())
+        } else {
+            None
+        },
+        cmd: process
+            .cmd()
+            .into_iter()
+            .map(|osstr| osstr.to_string_lossy().to_string())
+            .collect(),
+        user: user.to_string(),
+        cpu_usage: process.cpu_usage(),
+        thread_count,
+        memory: process.memory() as f64,
+        status: process.status().to_string(),
+        elapsed: process.run_time(),
+        parent: if process.parent().is_some() {
+            format!("{:?}", process.parent().unwrap().as_u32())
+        } else {
+            "-".to_string()
+        },
+        start_time: process.start_time(),
+        current_read_disk_usage: process_disk_usage.read_bytes,
+        total_read_disk_usage: process_disk_usage.total_read_bytes,
+        current_write_disk_usage: process_disk_usage.written_bytes,
+        total_write_disk_usage: process_disk_usage.total_written_bytes,
+    }
+}
+
 fn get_thread_count(
     pid: i32,
     process: &Process,
@@ -328,158 +633,2105 @@ fn get_thread_count(
     return thread_count;
 }
 
-fn get_cached_memory() -> f64 {
-    let mut cached_memory = 0.0;
+fn get_process_cgroup(pid: u32) -> String {
+    let mut cgroup = String::new();
 
-    #[cfg(target_os = "macos")]
+    #[cfg(target_os = "linux")]
     {
-        let macos_cache = get_macos_cache_memory();
-        if let Some(cache) = macos_cache {
-            cached_memory = cache as f64;
+        if let Some(path) = get_linux_process_cgroup(pid) {
+            cgroup = path;
         }
     }
 
+    #[cfg(not(target_os = "linux"))]
+    let _ = pid;
+
+    cgroup
+}
+
+// the oom_score and oom_score_adj of a process, both will be None if it was not a linux system
+fn get_process_oom_score(pid: u32) -> (Option<i32>, Option<i32>) {
     #[cfg(target_os = "linux")]
-    {
-        let linux_cache = get_linux_cached_memory();
-        if let Some(cache) = linux_cache {
-            cached_memory = cache as f64;
-        }
-    }
+    let oom_score = {
+        let read_value = |file: &str| {
+            std::fs::read_to_string(get_proc_path(&format!("{}/{}", pid, file)))
+                .ok()
+                .and_then(|value| value.trim().parse::<i32>().ok())
+        };
+        (read_value("oom_score"), read_value("oom_score_adj"))
+    };
 
-    #[cfg(target_os = "windows")]
-    {
-        let windows_cache = get_window_cached_memory();
-        if let Some(cache) = windows_cache {
-            cached_memory = cache as f64;
-        }
-    }
+    #[cfg(not(target_os = "linux"))]
+    let oom_score = {
+        let _ = pid;
+        (None, None)
+    };
 
-    return cached_memory;
+    oom_score
 }
 
-// A hack, but it gets the job done
-#[cfg(target_os = "macos")]
-fn get_macos_cache_memory() -> Option<u64> {
-    use libc::sysconf;
-    use libc::_SC_PAGESIZE;
-    use std::process::Command;
+// content of /proc/<pid>/status, None if it was not readable or not a linux system
+fn get_process_status(pid: u32) -> Option<String> {
+    #[cfg(target_os = "linux")]
+    let status = std::fs::read_to_string(get_proc_path(&format!("{}/status", pid))).ok();
 
-    let page_size = unsafe {
-        let size = sysconf(_SC_PAGESIZE);
-        if size <= 0 {
-            16384 // Default page size if sysconf fails
-        } else {
-            size
-        }
+    #[cfg(not(target_os = "linux"))]
+    let status = {
+        let _ = pid;
+        None
     };
 
-    // Execute the vm_stat command to get cached memory information
-    let output = Command::new("sh")
-        .arg("-c")
-        .arg("vm_stat | awk '/File-backed pages/ {print $3*1}'")
-        .output()
-        .ok();
+    status
+}
 
-    match output {
-        Some(output) => {
-            // Parse the output to get the number of cached pages
-            let cache_str = String::from_utf8_lossy(&output.stdout).trim().to_string();
-            let pages = cache_str.parse::<u64>().ok()?;
+// capabilities, no_new_privs, seccomp, namespaces and LSM label of a process, None if it was not a linux system
+fn get_process_security(
+    pid: u32,
+    status: Option<&str>,
+    init_namespaces: &[(String, u64)],
+) -> Option<ProcessSecurityData> {
+    #[cfg(target_os = "linux")]
+    let security = get_linux_process_security(pid, status?, init_namespaces);
 
-            // Calculate the cached memory in bytes
-            return Some(pages * page_size as u64);
-        }
-        None => return None,
-    }
+    #[cfg(not(target_os = "linux"))]
+    let security = {
+        let _ = (pid, status, init_namespaces);
+        None
+    };
+
+    security
+}
+
+// namespace type and inode of each namespace a process belongs to, empty if it was not a linux system
+fn get_process_namespaces(pid: u32) -> Vec<(String, u64)> {
+    #[cfg(target_os = "linux")]
+    let namespaces = get_linux_process_namespaces(pid);
+
+    #[cfg(not(target_os = "linux"))]
+    let namespaces = {
+        let _ = pid;
+        vec![]
+    };
+
+    namespaces
 }
 
 #[cfg(target_os = "linux")]
-fn get_linux_cached_memory() -> Option<u64> {
-    use std::fs;
-    let data = fs::read_to_string("/proc/meminfo").ok()?;
-    for line in data.lines() {
-        if line.starts_with("Cached:") {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-            if let Some(value) = parts.get(1) {
-                return value.parse::<u64>().ok().map(|kb| kb * 1024); // kB to bytes
+fn get_linux_process_security(
+    pid: u32,
+    status: &str,
+    init_namespaces: &[(String, u64)],
+) -> Option<ProcessSecurityData> {
+    let status: HashMap<&str, &str> = status
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            Some((key, value.trim()))
+        })
+        .collect();
+    // the capability sets are in hex, eg. "000001ffffffffff"
+    let capability = |key: &str| {
+        status
+            .get(key)
+            .and_then(|value| u64::from_str_radix(value, 16).ok())
+            .unwrap_or(0)
+    };
+
+    let namespaces = get_linux_process_namespaces(pid);
+    // the namespaces can only be compared when both of them are readable
+    let outside_init_namespaces = namespaces
+        .iter()
+        .filter(|(name, inode)| {
+            init_namespaces
+                .iter()
+                .any(|(init_name, init_inode)| init_name == name && init_inode != inode)
+        })
+        .map(|(name, _)| name.clone())
+        .collect();
+
+    // NOTE: attr/current was "unconfined" for AppArmor without a profile and not readable without any LSM
+    let lsm_label = std::fs::read_to_string(get_proc_path(&format!("{}/attr/current", pid)))
+        .ok()
+        .map(|label| label.trim_end_matches(['\0', '\n']).to_string())
+        .filter(|label| !label.is_empty());
+
+    Some(ProcessSecurityData {
+        cap_effective: capability("CapEff"),
+        cap_permitted: capability("CapPrm"),
+        cap_bounding: capability("CapBnd"),
+        no_new_privs: status.get("NoNewPrivs") == Some(&"1"),
+        seccomp: status
+            .get("Seccomp")
+            .and_then(|value| value.parse::<u8>().ok())
+            .unwrap_or(0),
+        namespaces,
+        outside_init_namespaces,
+        lsm_label,
+    })
+}
+
+// each entry of /proc/<pid>/ns was a symlink in the form of "<type>:[<inode>]"
+// NOTE: reading the links of processes owned by other users requires root
+#[cfg(target_os = "linux")]
+fn get_linux_process_namespaces(pid: u32) -> Vec<(String, u64)> {
+    const NAMESPACE_TYPES: [&str; 8] =
+        ["cgroup", "ipc", "mnt", "net", "pid", "time", "user", "uts"];
+
+    NAMESPACE_TYPES
+        .iter()
+        .filter_map(|name| {
+            let link = std::fs::read_link(get_proc_path(&format!("{}/ns/{}", pid, name))).ok()?;
+            let inode = link
+                .to_string_lossy()
+                .split_once('[')?
+                .1
+                .trim_end_matches(']')
+                .parse::<u64>()
+                .ok()?;
+            Some((name.to_string(), inode))
+        })
+        .collect()
+}
+
+// deleted or replaced executable and shared libraries still in use by a process, empty if it was not a linux system
+fn get_process_deleted_files(pid: u32) -> Vec<String> {
+    #[cfg(target_os = "linux")]
+    let deleted_files = get_linux_process_deleted_files(pid);
+
+    #[cfg(not(target_os = "linux"))]
+    let deleted_files = {
+        let _ = pid;
+        vec![]
+    };
+
+    deleted_files
+}
+
+// the kernel appends " (deleted)" to the path of the exe link and the file mappings once the file was unlinked,
+// which is also the case when a package upgrade replaced the file with a new one
+// NOTE: the exe link and maps of processes owned by other users are only readable by root
+#[cfg(target_os = "linux")]
+fn get_linux_process_deleted_files(pid: u32) -> Vec<String> {
+    const DELETED_SUFFIX: &str = " (deleted)";
+    // shared memory and memfd mappings are never backed by a file on disk, they always show up as deleted
+    const IGNORED_PREFIXES: [&str; 4] = ["/memfd:", "/dev/", "/SYSV", "/run/"];
+
+    let mut deleted_files = vec![];
+    if let Ok(exe) = std::fs::read_link(get_proc_path(&format!("{}/exe", pid))) {
+        if let Some(exe) = exe.to_string_lossy().strip_suffix(DELETED_SUFFIX) {
+            deleted_files.push(exe.to_string());
+        }
+    }
+
+    // each line was in the form of "<address> <perms> <offset> <dev> <inode>    <path>"
+    let maps = std::fs::read_to_string(get_proc_path(&format!("{}/maps", pid))).unwrap_or_default();
+    for line in maps.lines() {
+        let is_executable = line
+            .split_whitespace()
+            .nth(1)
+            .is_some_and(|perms| perms.contains('x'));
+        let path = match line.find('/') {
+            Some(index) if is_executable => &line[index..],
+            _ => continue,
+        };
+        if let Some(path) = path.strip_suffix(DELETED_SUFFIX) {
+            if IGNORED_PREFIXES
+                .iter()
+                .any(|prefix| path.starts_with(prefix))
+            {
+                continue;
+            }
+            if !deleted_files.iter().any(|file| file == path) {
+                deleted_files.push(path.to_string());
             }
         }
     }
-    return None;
+    deleted_files
 }
 
-#[cfg(target_os = "windows")]
-fn get_window_cached_memory() -> Option<u64> {
-    use std::mem;
-    use winapi::um::psapi::{GetPerformanceInfo, PERFORMANCE_INFORMATION};
-    unsafe {
-        let mut perf_info: PERFORMANCE_INFORMATION = mem::zeroed();
-        perf_info.cb = mem::size_of::<PERFORMANCE_INFORMATION>() as u32;
+// context switches, page faults and run queue delay of a process, None if it was not a linux system
+fn get_process_scheduler(pid: u32, status: Option<&str>) -> Option<CProcessSchedulerData> {
+    #[cfg(target_os = "linux")]
+    let scheduler = get_linux_process_scheduler(pid, status?);
 
-        if GetPerformanceInfo(&mut perf_info as *mut PERFORMANCE_INFORMATION, perf_info.cb) != 0 {
-            let page_size = perf_info.PageSize as u64;
-            let cached_pages = perf_info.SystemCache as u64;
-            return Some(page_size * cached_pages);
-        } else {
-            return None;
-        }
-    }
+    #[cfg(not(target_os = "linux"))]
+    let scheduler = {
+        let _ = (pid, status);
+        None
+    };
+
+    scheduler
 }
 
-#[cfg(target_os = "windows")]
-fn get_win_thread_counts() -> HashMap<String, u32> {
-    // not very optimize it seems ( not fast enough, tho was tested in VM )
-    use winapi::shared::minwindef::{DWORD, TRUE};
-    use winapi::shared::ntdef::NULL;
-    use winapi::um::handleapi::CloseHandle;
-    use winapi::um::tlhelp32::{
-        CreateToolhelp32Snapshot, Thread32First, Thread32Next, TH32CS_SNAPTHREAD, THREADENTRY32,
+// context switches from /proc/<pid>/status, page faults from /proc/<pid>/stat and the run queue delay from /proc/<pid>/schedstat
+// NOTE: schedstat is only available when the kernel was built with CONFIG_SCHED_INFO, the run queue delay will be 0 otherwise
+#[cfg(target_os = "linux")]
+fn get_linux_process_scheduler(pid: u32, status: &str) -> Option<CProcessSchedulerData> {
+    let status_value = |key: &str| {
+        status
+            .lines()
+            .find_map(|line| line.strip_prefix(key))
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .unwrap_or(0)
     };
 
-    let mut thread_counts: HashMap<String, u32> = HashMap::new();
+    // the program name within the parentheses can contain spaces, so the fields are counted after the closing one
+    // minflt and majflt are the 10th and 12th fields, the 8th and 10th after the program name
+    let stat = std::fs::read_to_string(get_proc_path(&format!("{}/stat", pid))).ok()?;
+    let stat_fields: Vec<&str> = stat.rsplit_once(')')?.1.split_whitespace().collect();
+    let stat_value = |index: usize| {
+        stat_fields
+            .get(index)
+            .and_then(|value| value.parse::<u64>().ok())
+            .unwrap_or(0)
+    };
 
-    // Create a snapshot of all threads in the system
-    let snapshot = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0) };
-    if snapshot == NULL {
-        // Handle error: Snapshot creation failed.
-        // In a real application, you might want to log this or return a Result.
-        eprintln!("Failed to create thread snapshot.");
-        return thread_counts; // Return an empty HashMap on error
-    }
+    // in the form of "<time on cpu in ns> <time waiting on a run queue in ns> <timeslices>"
+    let run_delay = std::fs::read_to_string(get_proc_path(&format!("{}/schedstat", pid)))
+        .ok()
+        .and_then(|schedstat| {
+            schedstat
+                .split_whitespace()
+                .nth(1)
+                .and_then(|value| value.parse::<u64>().ok())
+        })
+        .unwrap_or(0);
 
-    let mut thread_entry = THREADENTRY32 {
-        dwSize: std::mem::size_of::<THREADENTRY32>() as DWORD,
-        cntUsage: 0,
-        th32ThreadID: 0,
-        th32OwnerProcessID: 0,
-        tpBasePri: 0,
-        tpDeltaPri: 0,
-        dwFlags: 0,
+    Some(CProcessSchedulerData {
+        voluntary_context_switches: status_value("voluntary_ctxt_switches:"),
+        involuntary_context_switches: status_value("nonvoluntary_ctxt_switches:"),
+        minor_faults: stat_value(7),
+        major_faults: stat_value(9),
+        run_delay,
+    })
+}
+
+// memory mapped by a process on each NUMA node from /proc/<pid>/numa_maps, indexed by the node id
+// NOTE: numa_maps of processes owned by other users is only readable by root
+fn get_process_numa_memory(pid: u32, numa_node_count: usize) -> Vec<f64> {
+    #[cfg(target_os = "linux")]
+    let numa_memory = get_linux_process_numa_memory(pid, numa_node_count);
+
+    #[cfg(not(target_os = "linux"))]
+    let numa_memory = {
+        let _ = (pid, numa_node_count);
+        vec![]
     };
 
-    // Iterate through threads
-    if unsafe { Thread32First(snapshot, &mut thread_entry) } == TRUE {
-        loop {
-            let pid = thread_entry.th32OwnerProcessID;
-            let pid_str = pid.to_string();
+    numa_memory
+}
 
-            // Increment the thread count for this PID
-            *thread_counts.entry(pid_str).or_insert(0) += 1;
+// each mapping was in the form of "<address> <policy> ... N<node>=<pages> ... kernelpagesize_kB=<size>"
+#[cfg(target_os = "linux")]
+fn get_linux_process_numa_memory(pid: u32, numa_node_count: usize) -> Vec<f64> {
+    let data = match std::fs::read_to_string(get_proc_path(&format!("{}/numa_maps", pid))) {
+        Ok(data) => data,
+        Err(_) => return vec![],
+    };
 
-            // Move to the next thread
-            if unsafe { Thread32Next(snapshot, &mut thread_entry) } != TRUE {
-                break;
+    let mut numa_memory = vec![0.0; numa_node_count];
+    for line in data.lines() {
+        let mut node_pages = vec![];
+        let mut page_size = 4096.0;
+        for field in line.split_whitespace() {
+            if let Some(size) = field.strip_prefix("kernelpagesize_kB=") {
+                page_size = size.parse::<f64>().unwrap_or(4.0) * 1024.0;
+            } else if let Some((node, pages)) = field
+                .strip_prefix('N')
+                .and_then(|field| field.split_once('='))
+            {
+                if let (Ok(node), Ok(pages)) = (node.parse::<usize>(), pages.parse::<f64>()) {
+                    node_pages.push((node, pages));
+                }
             }
         }
-    } else {
-        // If Thread32First fails, it might mean no threads were found or an error occurred.
-        // Again, more robust error handling might be needed here.
-        eprintln!("Failed to get the first thread.");
+        for (node, pages) in node_pages {
+            // node ids are not guaranteed to be contiguous
+            if node >= numa_memory.len() {
+                numa_memory.resize(node + 1, 0.0);
+            }
+            numa_memory[node] += pages * page_size;
+        }
     }
+    numa_memory
+}
 
-    // Close the snapshot handle
-    unsafe { CloseHandle(snapshot) };
+// each line of /proc/<pid>/cgroup is in the form of "hierarchy-ID:controller-list:cgroup-path"
+// on a cgroup v2 system there will only be the "0::<path>" line, on a v1 system we prefer the
+// systemd named hierarchy as it carries the unit/slice and container scope in its path
+#[cfg(target_os = "linux")]
+fn get_linux_process_cgroup(pid: u32) -> Option<String> {
+    use std::fs;
+    let data = fs::read_to_string(get_proc_path(&format!("{}/cgroup", pid))).ok()?;
+    let mut fallback = None;
+    for line in data.lines() {
+        let mut parts = line.splitn(3, ':');
+        let hierarchy_id = parts.next().unwrap_or("");
+        let controllers = parts.next().unwrap_or("");
+        let path = parts.next().unwrap_or("");
+        if (hierarchy_id == "0" && controllers.is_empty()) || controllers == "name=systemd" {
+            return Some(path.to_string());
+        }
+        if fallback.is_none() && path != "/" {
+            fallback = Some(path.to_string());
+        }
+    }
+    fallback
+}
 
-    return thread_counts;
+// derive a short and readable container id / systemd unit / slice name from a cgroup path
+// eg. "/system.slice/docker-4f1c...e2.scope" -> "docker:4f1c2b3a9d8e"
+//     "/kubepods/burstable/pod<uid>/<container id>" -> "k8s:<container id>"
+//     "/system.slice/nginx.service" -> "nginx.service"
+pub fn get_container_from_cgroup(cgroup: &str) -> String {
+    if cgroup.is_empty() || cgroup == "/" {
+        return "-".to_string();
+    }
+
+    let components: Vec<&str> = cgroup.split('/').filter(|c| !c.is_empty()).collect();
+    let is_kubernetes = components
+        .iter()
+        .any(|component| component.starts_with("kubepods"));
+
+    // walk from the deepest component as it is the most specific one
+    for component in components.iter().rev() {
+        let name = component.trim_end_matches(".scope");
+        for (prefix, runtime) in [
+            ("docker-", "docker"),
+            ("cri-containerd-", "containerd"),
+            ("crio-", "crio"),
+            ("libpod-", "podman"),
+        ] {
+            if let Some(id) = name.strip_prefix(prefix) {
+                if is_container_id(id) {
+                    let runtime = if is_kubernetes { "k8s" } else { runtime };
+                    return format!("{}:{}", runtime, &id[..12]);
+                }
+            }
+        }
+        // cgroupfs driver layout, "/docker/<id>" or "/kubepods/<qos>/pod<uid>/<id>"
+        if is_container_id(name) {
+            let runtime = if is_kubernetes { "k8s" } else { "docker" };
+            return format!("{}:{}", runtime, &name[..12]);
+        }
+    }
+
+    // lxc containers will be named after the container instead of an id
+    if let Some(index) = components
+        .iter()
+        .position(|component| *component == "lxc" || *component == "lxc.payload")
+    {
+        if let Some(name) = components.get(index + 1) {
+            return format!("lxc:{}", name);
+        }
+    }
+    if let Some(name) = components
+        .iter()
+        .find_map(|component| component.strip_prefix("lxc.payload."))
+    {
+        return format!("lxc:{}", name);
+    }
+
+    // otherwise fallback to the systemd unit the process was running under, then the slice
+    if let Some(unit) = components
+        .iter()
+        .rev()
+        .find(|component| component.ends_with(".service") || component.ends_with(".scope"))
+    {
+        return unit.to_string();
+    }
+    if let Some(slice) = components
+        .iter()
+        .rev()
+        .find(|component| component.ends_with(".slice"))
+    {
+        return slice.to_string();
+    }
+
+    components[components.len() - 1].to_string()
+}
+
+fn is_container_id(id: &str) -> bool {
+    id.len() == 64 && id.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+// cumulative counters of a cgroup, cpu.stat usage_usec and the io.stat read/written bytes summed across devices
+struct CgroupCounters {
+    cpu_usage_usec: u64,
+    io_read_bytes: u64,
+    io_write_bytes: u64,
+}
+
+fn get_cgroups(
+    previous_counters: &mut HashMap<String, CgroupCounters>,
+    elapsed: Duration,
+    cpu_count: usize,
+) -> Vec<CCgroupData> {
+    let mut cgroups = vec![];
+
+    #[cfg(target_os = "linux")]
+    {
+        cgroups = get_linux_cgroups(previous_counters, elapsed, cpu_count);
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    let _ = (previous_counters, elapsed, cpu_count);
+
+    cgroups
+}
+
+// walk through the cgroup v2 unified hierarchy, cgroup v1 hierarchies are not supported
+#[cfg(target_os = "linux")]
+fn get_linux_cgroups(
+    previous_counters: &mut HashMap<String, CgroupCounters>,
+    elapsed: Duration,
+    cpu_count: usize,
+) -> Vec<CCgroupData> {
+    let cgroup_root = get_sys_path("fs/cgroup");
+    let cgroup_root = cgroup_root.as_path();
+    if !cgroup_root.join("cgroup.controllers").exists() {
+        return vec![];
+    }
+
+    let elapsed_usec = elapsed.as_micros().max(1) as f64;
+    let mut current_counters = HashMap::new();
+    let mut cgroups = vec![];
+    let mut directories = vec![cgroup_root.to_path_buf()];
+
+    while let Some(directory) = directories.pop() {
+        if let Ok(entries) = std::fs::read_dir(&directory) {
+            for entry in entries.flatten() {
+                if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+                    directories.push(entry.path());
+                }
+            }
+        }
+
+        let relative_path = directory
+            .strip_prefix(cgroup_root)
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let path = format!("/{}", relative_path);
+
+        let cpu_usage_usec = read_cgroup_key_value(&directory.join("cpu.stat"), "usage_usec");
+        let (io_read_bytes, io_write_bytes) = read_cgroup_io_stat(&directory.join("io.stat"));
+        let memory_current = read_cgroup_value(&directory.join("memory.current")).unwrap_or(0);
+        let memory_max = read_cgroup_value(&directory.join("memory.max"));
+        let pids = read_cgroup_value(&directory.join("pids.current")).unwrap_or(0);
+
+        // the first collection of a cgroup will not have any usage as there is nothing to compare with
+        let (cpu_usage, io_read, io_write) = match previous_counters.get(&path) {
+            Some(previous) => (
+                (cpu_usage_usec.saturating_sub(previous.cpu_usage_usec) as f64 / elapsed_usec)
+                    * 100.0
+                    / cpu_count.max(1) as f64,
+                io_read_bytes.saturating_sub(previous.io_read_bytes) as f64 * 1_000_000.0
+                    / elapsed_usec,
+                io_write_bytes.saturating_sub(previous.io_write_bytes) as f64 * 1_000_000.0
+                    / elapsed_usec,
+            ),
+            None => (0.0, 0.0, 0.0),
+        };
+
+        current_counters.insert(
+            path.clone(),
+            CgroupCounters {
+                cpu_usage_usec,
+                io_read_bytes,
+                io_write_bytes,
+            },
+        );
+
+        cgroups.push(CCgroupData {
+            path,
+            cpu_usage: cpu_usage.min(100.0),
+            memory_current: memory_current as f64,
+            memory_max: memory_max.map(|max| max as f64),
+            io_read,
+            io_write,
+            pids,
+        });
+    }
+
+    *previous_counters = current_counters;
+    cgroups
+}
+
+// for single value cgroup files like memory.current, "max" will be returned as None
+#[cfg(target_os = "linux")]
+fn read_cgroup_value(path: &std::path::Path) -> Option<u64> {
+    std::fs::read_to_string(path)
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+}
+
+// for flat keyed cgroup files like cpu.stat, each line is in the form of "key value"
+#[cfg(target_os = "linux")]
+fn read_cgroup_key_value(path: &std::path::Path, key: &str) -> u64 {
+    let data = std::fs::read_to_string(path).unwrap_or_default();
+    for line in data.lines() {
+        let mut parts = line.split_whitespace();
+        if parts.next() == Some(key) {
+            return parts
+                .next()
+                .and_then(|value| value.parse::<u64>().ok())
+                .unwrap_or(0);
+        }
+    }
+    0
+}
+
+// io.stat has a line per device in the form of "8:0 rbytes=1459200 wbytes=314773504 rios=192 ..."
+#[cfg(target_os = "linux")]
+fn read_cgroup_io_stat(path: &std::path::Path) -> (u64, u64) {
+    let data = std::fs::read_to_string(path).unwrap_or_default();
+    let mut read_bytes = 0;
+    let mut write_bytes = 0;
+    for line in data.lines() {
+        for field in line.split_whitespace().skip(1) {
+            if let Some(value) = field.strip_prefix("rbytes=") {
+                read_bytes += value.parse::<u64>().unwrap_or(0);
+            } else if let Some(value) = field.strip_prefix("wbytes=") {
+                write_bytes += value.parse::<u64>().unwrap_or(0);
+            }
+        }
+    }
+    (read_bytes, write_bytes)
+}
+
+fn get_container_limit(
+    previous_cpu_usage_usec: &mut Option<u64>,
+    elapsed: Duration,
+    cpu_count: usize,
+    host_memory: &CMemoryData,
+) -> Option<CContainerLimitData> {
+    #[cfg(target_os = "linux")]
+    let container_limit =
+        get_linux_container_limit(previous_cpu_usage_usec, elapsed, cpu_count, host_memory);
+
+    #[cfg(not(target_os = "linux"))]
+    let container_limit = {
+        let _ = (previous_cpu_usage_usec, elapsed, cpu_count, host_memory);
+        None
+    };
+
+    container_limit
+}
+
+// look for the limits applied on the cgroup rtop was running in ( cgroup v2 only )
+// limits can be set on any of the ancestors ( a kubernetes pod for example ), so we walk up to the root
+// and keep the most restrictive one. None will be returned when there is no limit in effect
+#[cfg(target_os = "linux")]
+fn get_linux_container_limit(
+    previous_cpu_usage_usec: &mut Option<u64>,
+    elapsed: Duration,
+    cpu_count: usize,
+    host_memory: &CMemoryData,
+) -> Option<CContainerLimitData> {
+    let cgroup_root = get_local_sys_path("fs/cgroup");
+    let cgroup_root = cgroup_root.as_path();
+    let self_cgroup = std::fs::read_to_string(get_local_proc_path("self/cgroup")).ok()?;
+    let self_cgroup_path = self_cgroup
+        .lines()
+        .find_map(|line| line.strip_prefix("0::"))?
+        .trim_start_matches('/')
+        .to_string();
+    let cgroup_directory = cgroup_root.join(&self_cgroup_path);
+    if !cgroup_directory.join("cgroup.controllers").exists() {
+        return None;
+    }
+
+    let mut memory_max: Option<u64> = None;
+    let mut cpu_limit: Option<f64> = None;
+    let mut directory = Some(cgroup_directory.as_path());
+    while let Some(current) = directory {
+        if let Some(max) = read_cgroup_value(&current.join("memory.max")) {
+            memory_max = Some(memory_max.map_or(max, |m| m.min(max)));
+        }
+        // cpu.max is in the form of "$MAX $PERIOD", with $MAX being "max" when there is no quota
+        let cpu_max = std::fs::read_to_string(current.join("cpu.max")).unwrap_or_default();
+        let mut cpu_max = cpu_max.split_whitespace();
+        if let (Some(Ok(quota)), Some(Ok(period))) = (
+            cpu_max.next().map(|v| v.parse::<f64>()),
+            cpu_max.next().map(|v| v.parse::<f64>()),
+        ) {
+            if period > 0.0 {
+                let cpus = quota / period;
+                cpu_limit = Some(cpu_limit.map_or(cpus, |c| c.min(cpus)));
+            }
+        }
+        if current == cgroup_root {
+            break;
+        }
+        directory = current.parent();
+    }
+
+    // only keep the cpuset when it was actually restricting the cpus
+    let mut cpuset = parse_cpu_list(
+        &std::fs::read_to_string(cgroup_directory.join("cpuset.cpus.effective"))
+            .unwrap_or_default(),
+    );
+    cpuset.retain(|cpu| *cpu < cpu_count);
+    if cpuset.len() >= cpu_count {
+        cpuset.clear();
+    }
+
+    // a limit bigger than the host itself was not a limit
+    let memory_max = memory_max
+        .map(|max| max as f64)
+        .filter(|max| *max < host_memory.total_memory);
+    let cpu_limit = cpu_limit.filter(|cpus| *cpus < cpu_count as f64);
+    if memory_max.is_none() && cpu_limit.is_none() && cpuset.is_empty() {
+        *previous_cpu_usage_usec = None;
+        return None;
+    }
+
+    // cpu usage relative to the effective cpus the cgroup was allowed to use
+    let mut effective_cpus = if cpuset.is_empty() {
+        cpu_count as f64
+    } else {
+        cpuset.len() as f64
+    };
+    if let Some(cpus) = cpu_limit {
+        effective_cpus = effective_cpus.min(cpus);
+    }
+    let cpu_usage_usec = read_cgroup_key_value(&cgroup_directory.join("cpu.stat"), "usage_usec");
+    let cpu_usage = match previous_cpu_usage_usec {
+        Some(previous) => {
+            (cpu_usage_usec.saturating_sub(*previous) as f64 / elapsed.as_micros().max(1) as f64)
+                * 100.0
+                / effective_cpus.max(0.01)
+        }
+        None => 0.0,
+    };
+    *previous_cpu_usage_usec = Some(cpu_usage_usec);
+
+    // same as what `docker stats` shows, the inactive page cache can be reclaimed so it does not count as used
+    let memory_stat = cgroup_directory.join("memory.stat");
+    let memory_current =
+        read_cgroup_value(&cgroup_directory.join("memory.current")).unwrap_or(0) as f64;
+    let inactive_file = read_cgroup_key_value(&memory_stat, "inactive_file") as f64;
+    let cached_memory = read_cgroup_key_value(&memory_stat, "file") as f64;
+    let used_swap = read_cgroup_value(&cgroup_directory.join("memory.swap.current"))
+        .map(|swap| swap as f64)
+        .unwrap_or(0.0);
+    let total_memory = memory_max.unwrap_or(host_memory.total_memory);
+    let used_memory = (memory_current - inactive_file).max(0.0);
+
+    Some(CContainerLimitData {
+        memory_max,
+        cpu_limit,
+        cpuset,
+        cpu_usage: cpu_usage.min(100.0) as f32,
+        memory: CMemoryData {
+            total_memory,
+            available_memory: (total_memory - used_memory).max(0.0),
+            used_memory,
+            used_swap,
+            free_memory: (total_memory - memory_current).max(0.0),
+            cached_memory,
+            // the virtual memory activity is only available host-wide
+            vm_activity: None,
+        },
+    })
+}
+
+// cpu list format used by cpuset, eg. "0-3,8,10-11"
+#[cfg(target_os = "linux")]
+fn parse_cpu_list(cpu_list: &str) -> Vec<usize> {
+    let mut cpus = vec![];
+    for range in cpu_list.trim().split(',').filter(|r| !r.is_empty()) {
+        let mut bounds = range.splitn(2, '-');
+        let start = bounds.next().and_then(|v| v.trim().parse::<usize>().ok());
+        let end = bounds.next().and_then(|v| v.trim().parse::<usize>().ok());
+        match (start, end) {
+            (Some(start), Some(end)) => cpus.extend(start..=end),
+            (Some(start), None) => cpus.push(start),
+            _ => {}
+        }
+    }
+    cpus
+}
+
+fn get_power_zones(
+    previous_energy_uj: &mut HashMap<String, u64>,
+    elapsed: Duration,
+) -> Vec<CPowerZoneData> {
+    #[cfg(target_os = "linux")]
+    let power_zones = get_linux_power_zones(previous_energy_uj, elapsed);
+
+    #[cfg(not(target_os = "linux"))]
+    let power_zones = {
+        let _ = (previous_energy_uj, elapsed);
+        vec![]
+    };
+
+    power_zones
+}
+
+// RAPL energy counters exposed through the powercap class, AMD cpus were also exposed as intel-rapl
+// zones by the same driver. the zones are in the form of "intel-rapl:<package>" and "intel-rapl:<package>:<sub zone>"
+// NOTE: energy_uj is only readable by root on most recent kernels, no zone will be returned otherwise
+#[cfg(target_os = "linux")]
+fn get_linux_power_zones(
+    previous_energy_uj: &mut HashMap<String, u64>,
+    elapsed: Duration,
+) -> Vec<CPowerZoneData> {
+    let mut power_zones = vec![];
+    let mut current_energy_uj = HashMap::new();
+    let entries = match std::fs::read_dir(get_sys_path("class/powercap")) {
+        Ok(entries) => entries,
+        Err(_) => return power_zones,
+    };
+
+    let elapsed_sec = elapsed.as_secs_f64().max(0.001);
+    for entry in entries.flatten() {
+        let id = entry.file_name().to_string_lossy().to_string();
+        // the mmio zones are a duplicate of the package zones
+        if !(id.starts_with("intel-rapl:") || id.starts_with("amd-rapl:")) {
+            continue;
+        }
+
+        let directory = entry.path();
+        let energy_uj = match std::fs::read_to_string(directory.join("energy_uj"))
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+        {
+            Some(energy_uj) => energy_uj,
+            None => continue,
+        };
+        let max_energy_range_uj = std::fs::read_to_string(directory.join("max_energy_range_uj"))
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .unwrap_or(u64::MAX);
+
+        let zone_name = std::fs::read_to_string(directory.join("name"))
+            .map(|name| name.trim().to_string())
+            .unwrap_or(id.clone());
+        // sub zones are named without the package they belong to ( core, uncore, dram )
+        let id_parts: Vec<&str> = id.split(':').collect();
+        let name = if id_parts.len() > 2 {
+            format!("{}-{}", zone_name, id_parts[1])
+        } else {
+            zone_name
+        };
+
+        // the counter will wrap around at max_energy_range_uj
+        let power = match previous_energy_uj.get(&id) {
+            Some(previous) if energy_uj >= *previous => {
+                (energy_uj - previous) as f64 / 1_000_000.0 / elapsed_sec
+            }
+            Some(previous) => {
+                (max_energy_range_uj.saturating_sub(*previous) + energy_uj) as f64
+                    / 1_000_000.0
+                    / elapsed_sec
+            }
+            None => 0.0,
+        };
+
+        current_energy_uj.insert(id.clone(), energy_uj);
+        power_zones.push(CPowerZoneData { id, name, power });
+    }
+
+    *previous_energy_uj = current_energy_uj;
+    power_zones
+}
+
+#[derive(Default)]
+struct InterruptCounters {
+    sources: HashMap<String, Vec<u64>>,
+    context_switches: Option<u64>,
+    interrupts: Option<u64>,
+    forks: Option<u64>,
+}
+
+fn get_interrupts(
+    previous_counters: &mut InterruptCounters,
+    elapsed: Duration,
+) -> Option<CInterruptData> {
+    #[cfg(target_os = "linux")]
+    let interrupts = get_linux_interrupts(previous_counters, elapsed);
+
+    #[cfg(not(target_os = "linux"))]
+    let interrupts = {
+        let _ = (previous_counters, elapsed);
+        None
+    };
+
+    interrupts
+}
+
+// the rates of each interrupt source from /proc/interrupts and /proc/softirqs, with the
+// context switches ( ctxt ), interrupts ( intr ) and forks ( processes ) from /proc/stat
+#[cfg(target_os = "linux")]
+fn get_linux_interrupts(
+    previous_counters: &mut InterruptCounters,
+    elapsed: Duration,
+) -> Option<CInterruptData> {
+    let elapsed_sec = elapsed.as_secs_f64().max(0.001);
+    let rate = |current: u64, previous: Option<u64>| match previous {
+        Some(previous) => current.saturating_sub(previous) as f64 / elapsed_sec,
+        None => 0.0,
+    };
+
+    let stat = std::fs::read_to_string(get_proc_path("stat")).ok()?;
+    let read_stat_counter = |key: &str| {
+        stat.lines()
+            .find(|line| line.split_whitespace().next() == Some(key))
+            .and_then(|line| line.split_whitespace().nth(1))
+            .and_then(|value| value.parse::<u64>().ok())
+            .unwrap_or(0)
+    };
+    let context_switches = read_stat_counter("ctxt");
+    let interrupts = read_stat_counter("intr");
+    let forks = read_stat_counter("processes");
+
+    let mut cpu_count = 0;
+    let mut sources = vec![];
+    let mut current_sources = HashMap::new();
+    for (file, is_softirq) in [("interrupts", false), ("softirqs", true)] {
+        let data = std::fs::read_to_string(get_proc_path(file)).unwrap_or_default();
+        let mut lines = data.lines();
+        // the first line will be the cpu header, eg. "CPU0       CPU1       CPU2"
+        let file_cpu_count = match lines.next() {
+            Some(header) => header.split_whitespace().count(),
+            None => continue,
+        };
+        cpu_count = cpu_count.max(file_cpu_count);
+
+        for line in lines {
+            let (id, values) = match line.split_once(':') {
+                Some((id, values)) => (id.trim().to_string(), values),
+                None => continue,
+            };
+            let mut tokens = values.split_whitespace().peekable();
+            let mut counts = vec![];
+            // some sources like ERR and MIS only have a single count instead of one for each cpu
+            while counts.len() < file_cpu_count {
+                match tokens.peek().and_then(|token| token.parse::<u64>().ok()) {
+                    Some(count) => {
+                        counts.push(count);
+                        tokens.next();
+                    }
+                    None => break,
+                }
+            }
+            let description = if is_softirq {
+                "softirq".to_string()
+            } else {
+                tokens.collect::<Vec<&str>>().join(" ")
+            };
+
+            let key = format!("{}:{}", file, id);
+            let previous = previous_counters.sources.get(&key);
+            let cpu_rate: Vec<f64> = counts
+                .iter()
+                .enumerate()
+                .map(|(cpu, count)| rate(*count, previous.and_then(|p| p.get(cpu).copied())))
+                .collect();
+            let total_rate = cpu_rate.iter().sum();
+
+            sources.push(InterruptSourceData {
+                id,
+                description,
+                cpu_rate,
+                total_rate,
+            });
+            current_sources.insert(key, counts);
+        }
+    }
+
+    let interrupt_data = CInterruptData {
+        cpu_count,
+        sources,
+        context_switch_rate: rate(context_switches, previous_counters.context_switches),
+        interrupt_rate: rate(interrupts, previous_counters.interrupts),
+        fork_rate: rate(forks, previous_counters.forks),
+    };
+
+    *previous_counters = InterruptCounters {
+        sources: current_sources,
+        context_switches: Some(context_switches),
+        interrupts: Some(interrupts),
+        forks: Some(forks),
+    };
+
+    Some(interrupt_data)
+}
+
+fn get_sessions() -> Vec<CSessionData> {
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    let sessions = get_unix_sessions();
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    let sessions = vec![];
+
+    sessions
+}
+
+// read the logged-in sessions from the utmp database, the idle time is the time since the tty was last accessed
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn get_unix_sessions() -> Vec<CSessionData> {
+    use std::os::unix::fs::MetadataExt;
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or(0);
+
+    let mut sessions = vec![];
+    unsafe {
+        libc::setutxent();
+        loop {
+            let entry = libc::getutxent();
+            if entry.is_null() {
+                break;
+            }
+            let entry = &*entry;
+            if entry.ut_type != libc::USER_PROCESS {
+                continue;
+            }
+
+            // the fields are fixed size char arrays which are not always nul terminated
+            let to_string = |field: &[libc::c_char]| {
+                let bytes: Vec<u8> = field
+                    .iter()
+                    .take_while(|c| **c != 0)
+                    .map(|c| *c as u8)
+                    .collect();
+                String::from_utf8_lossy(&bytes).to_string()
+            };
+            let tty = to_string(&entry.ut_line);
+            let idle = std::fs::metadata(format!("/dev/{}", tty))
+                .ok()
+                .map(|metadata| now.saturating_sub(metadata.atime()).max(0) as u64);
+
+            sessions.push(CSessionData {
+                user: to_string(&entry.ut_user),
+                tty,
+                host: to_string(&entry.ut_host),
+                login_time: entry.ut_tv.tv_sec as i64,
+                idle,
+            });
+        }
+        libc::endutxent();
+    }
+
+    sessions
+}
+
+fn get_batteries() -> Vec<CBatteryData> {
+    #[cfg(target_os = "linux")]
+    let batteries = get_linux_batteries();
+
+    #[cfg(not(target_os = "linux"))]
+    let batteries = vec![];
+
+    batteries
+}
+
+// read the batteries from the power supply class, devices like a wireless mouse will also be listed
+// as a battery but with the "Device" scope, those are skipped
+// values in sysfs are in µW, µWh, µA, µAh and µV
+#[cfg(target_os = "linux")]
+fn get_linux_batteries() -> Vec<CBatteryData> {
+    let mut batteries = vec![];
+    let entries = match std::fs::read_dir(get_sys_path("class/power_supply")) {
+        Ok(entries) => entries,
+        Err(_) => return batteries,
+    };
+
+    for entry in entries.flatten() {
+        let directory = entry.path();
+        let read_string = |file: &str| {
+            std::fs::read_to_string(directory.join(file))
+                .map(|value| value.trim().to_string())
+                .unwrap_or_default()
+        };
+        let read_value = |file: &str| read_string(file).parse::<f64>().ok();
+
+        if read_string("type") != "Battery" || read_string("scope") == "Device" {
+            continue;
+        }
+        if read_string("present") == "0" {
+            continue;
+        }
+
+        // some batteries report the energy ( µWh ), others report the charge ( µAh ) which need the voltage to be converted
+        let voltage = read_value("voltage_min_design")
+            .or(read_value("voltage_now"))
+            .map(|voltage| voltage / 1_000_000.0);
+        let energy = |energy_file: &str, charge_file: &str| {
+            read_value(energy_file)
+                .or(read_value(charge_file)
+                    .and_then(|charge| voltage.map(|voltage| charge * voltage)))
+        };
+        let energy_now = energy("energy_now", "charge_now");
+        let energy_full = energy("energy_full", "charge_full");
+        let energy_full_design = energy("energy_full_design", "charge_full_design");
+
+        let power_draw = read_value("power_now")
+            .or(read_value("current_now").and_then(|current| {
+                read_value("voltage_now").map(|voltage| current * voltage / 1_000_000.0)
+            }))
+            .map(|power| power.abs() / 1_000_000.0)
+            .unwrap_or(0.0);
+
+        let charge = match read_value("capacity") {
+            Some(capacity) => capacity,
+            None => match (energy_now, energy_full) {
+                (Some(now), Some(full)) if full > 0.0 => now / full * 100.0,
+                _ => 0.0,
+            },
+        };
+
+        let status = read_string("status");
+        let status = if status.is_empty() {
+            "Unknown".to_string()
+        } else {
+            status
+        };
+
+        // energy is in µWh and power in W, so the hours left will be (µWh / 1,000,000) / W
+        let mut time_to_empty = None;
+        let mut time_to_full = None;
+        if power_draw > 0.0 {
+            if status == "Discharging" {
+                time_to_empty =
+                    energy_now.map(|now| (now / 1_000_000.0 / power_draw * 3600.0) as u64);
+            } else if status == "Charging" {
+                if let (Some(now), Some(full)) = (energy_now, energy_full) {
+                    time_to_full =
+                        Some(((full - now).max(0.0) / 1_000_000.0 / power_draw * 3600.0) as u64);
+                }
+            }
+        }
+
+        let health = match (energy_full, energy_full_design) {
+            (Some(full), Some(design)) if design > 0.0 => Some(full / design * 100.0),
+            _ => None,
+        };
+
+        batteries.push(CBatteryData {
+            name: entry.file_name().to_string_lossy().to_string(),
+            status,
+            charge: charge.clamp(0.0, 100.0),
+            power_draw,
+            time_to_empty,
+            time_to_full,
+            health,
+        });
+    }
+
+    batteries
+}
+
+fn get_cached_memory() -> f64 {
+    let mut cached_memory = 0.0;
+
+    #[cfg(target_os = "macos")]
+    {
+        let macos_cache = get_macos_cache_memory();
+        if let Some(cache) = macos_cache {
+            cached_memory = cache as f64;
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let linux_cache = get_linux_cached_memory();
+        if let Some(cache) = linux_cache {
+            cached_memory = cache as f64;
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let windows_cache = get_window_cached_memory();
+        if let Some(cache) = windows_cache {
+            cached_memory = cache as f64;
+        }
+    }
+
+    return cached_memory;
+}
+
+fn get_vm_activity(previous_counters: &mut HashMap<String, u64>) -> Option<CVmActivityData> {
+    #[cfg(target_os = "linux")]
+    let vm_activity = get_linux_vm_activity(previous_counters);
+
+    #[cfg(not(target_os = "linux"))]
+    let vm_activity = {
+        let _ = previous_counters;
+        None
+    };
+
+    vm_activity
+}
+
+#[cfg(target_os = "linux")]
+fn get_linux_vm_activity(previous_counters: &mut HashMap<String, u64>) -> Option<CVmActivityData> {
+    let data = std::fs::read_to_string(get_proc_path("vmstat")).ok()?;
+    let current_counters: HashMap<String, u64> = data
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(' ')?;
+            Some((key.to_string(), value.trim().parse::<u64>().ok()?))
+        })
+        .collect();
+
+    // there is nothing to diff against on the first collection
+    let is_first_collection = previous_counters.is_empty();
+    let delta = |key: &str| {
+        let current = current_counters.get(key).copied().unwrap_or(0);
+        let previous = previous_counters.get(key).copied().unwrap_or(current);
+        current.saturating_sub(previous) as f64
+    };
+
+    let page_size = match unsafe { libc::sysconf(libc::_SC_PAGESIZE) } {
+        size if size > 0 => size as f64,
+        _ => 4096.0,
+    };
+
+    // pgpgin and pgpgout are in KiB while pswpin and pswpout are in pages
+    let vm_activity = CVmActivityData {
+        page_in: delta("pgpgin") * 1024.0,
+        page_out: delta("pgpgout") * 1024.0,
+        swap_in: delta("pswpin") * page_size,
+        swap_out: delta("pswpout") * page_size,
+        page_faults: delta("pgfault"),
+        major_page_faults: delta("pgmajfault"),
+        oom_kills: delta("oom_kill"),
+    };
+
+    *previous_counters = current_counters;
+
+    if is_first_collection {
+        return None;
+    }
+    Some(vm_activity)
+}
+
+// the number of NUMA nodes, 0 when the NUMA topology was not exposed ( or it was not a linux system )
+fn get_numa_node_count() -> usize {
+    #[cfg(target_os = "linux")]
+    let numa_node_count = get_linux_numa_node_ids().len();
+
+    #[cfg(not(target_os = "linux"))]
+    let numa_node_count = 0;
+
+    numa_node_count
+}
+
+fn get_numa_nodes(
+    previous_counters: &mut HashMap<usize, HashMap<String, u64>>,
+) -> Vec<CNumaNodeData> {
+    #[cfg(target_os = "linux")]
+    let numa_nodes = get_linux_numa_nodes(previous_counters);
+
+    #[cfg(not(target_os = "linux"))]
+    let numa_nodes = {
+        let _ = previous_counters;
+        vec![]
+    };
+
+    numa_nodes
+}
+
+#[cfg(target_os = "linux")]
+fn get_linux_numa_node_ids() -> Vec<usize> {
+    let entries = match std::fs::read_dir(get_sys_path("devices/system/node")) {
+        Ok(entries) => entries,
+        Err(_) => return vec![],
+    };
+
+    let mut node_ids: Vec<usize> = entries
+        .flatten()
+        .filter_map(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .strip_prefix("node")
+                .and_then(|id| id.parse::<usize>().ok())
+        })
+        .collect();
+    node_ids.sort();
+    node_ids
+}
+
+// per node memory from node<id>/meminfo, allocation counters from node<id>/numastat and the cpus from node<id>/cpulist
+#[cfg(target_os = "linux")]
+fn get_linux_numa_nodes(
+    previous_counters: &mut HashMap<usize, HashMap<String, u64>>,
+) -> Vec<CNumaNodeData> {
+    let mut numa_nodes = vec![];
+    let mut current_counters = HashMap::new();
+
+    for id in get_linux_numa_node_ids() {
+        let directory = get_sys_path(&format!("devices/system/node/node{}", id));
+
+        // each line was in the form of "Node <id> <key>: <value> kB"
+        let meminfo: HashMap<String, f64> = match std::fs::read_to_string(directory.join("meminfo"))
+        {
+            Ok(data) => data
+                .lines()
+                .filter_map(|line| {
+                    let fields: Vec<&str> = line.split_whitespace().collect();
+                    let key = fields.get(2)?.trim_end_matches(':').to_string();
+                    let value = fields.get(3)?.parse::<f64>().ok()?;
+                    Some((key, value * 1024.0))
+                })
+                .collect(),
+            Err(_) => continue,
+        };
+        let total_memory = meminfo.get("MemTotal").copied().unwrap_or(0.0);
+        let free_memory = meminfo.get("MemFree").copied().unwrap_or(0.0);
+        let used_memory = meminfo
+            .get("MemUsed")
+            .copied()
+            .unwrap_or(total_memory - free_memory);
+        let cached_memory = meminfo.get("FilePages").copied().unwrap_or(0.0);
+        // MemAvailable was not exposed per node, the file pages are mostly reclaimable
+        let available_memory = (free_memory + cached_memory).min(total_memory);
+
+        let counters: HashMap<String, u64> = std::fs::read_to_string(directory.join("numastat"))
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| {
+                let (key, value) = line.split_once(' ')?;
+                Some((key.to_string(), value.trim().parse::<u64>().ok()?))
+            })
+            .collect();
+        let previous = previous_counters.get(&id);
+        let delta = |key: &str| {
+            let current = counters.get(key).copied().unwrap_or(0);
+            match previous.and_then(|previous| previous.get(key)) {
+                Some(previous) => current.saturating_sub(*previous) as f64,
+                None => 0.0,
+            }
+        };
+        let (numa_hit, numa_miss, numa_foreign, other_node) = (
+            delta("numa_hit"),
+            delta("numa_miss"),
+            delta("numa_foreign"),
+            delta("other_node"),
+        );
+        current_counters.insert(id, counters);
+
+        let cpu_list = std::fs::read_to_string(directory.join("cpulist"))
+            .map(|cpu_list| cpu_list.trim().to_string())
+            .unwrap_or_default();
+        let cpus = parse_cpu_list(&cpu_list);
+
+        numa_nodes.push(CNumaNodeData {
+            id,
+            cpu_list,
+            cpus,
+            total_memory,
+            available_memory,
+            used_memory,
+            free_memory,
+            cached_memory,
+            numa_hit,
+            numa_miss,
+            numa_foreign,
+            other_node,
+        });
+    }
+
+    *previous_counters = current_counters;
+    numa_nodes
+}
+
+// A hack, but it gets the job done
+#[cfg(target_os = "macos")]
+fn get_macos_cache_memory() -> Option<u64> {
+    use libc::sysconf;
+    use libc::_SC_PAGESIZE;
+    use std::process::Command;
+
+    let page_size = unsafe {
+        let size = sysconf(_SC_PAGESIZE);
+        if size <= 0 {
+            16384 // Default page size if sysconf fails
+        } else {
+            size
+        }
+    };
+
+    // Execute the vm_stat command to get cached memory information
+    let output = Command::new("sh")
+        .arg("-c")
+        .arg("vm_stat | awk '/File-backed pages/ {print $3*1}'")
+        .output()
+        .ok();
+
+    match output {
+        Some(output) => {
+            // Parse the output to get the number of cached pages
+            let cache_str = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            let pages = cache_str.parse::<u64>().ok()?;
+
+            // Calculate the cached memory in bytes
+            return Some(pages * page_size as u64);
+        }
+        None => return None,
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn get_linux_cached_memory() -> Option<u64> {
+    get_linux_meminfo()?.get("Cached").copied()
+}
+
+// each line of /proc/meminfo is in the form of "MemTotal:       16318412 kB", the values are returned in bytes
+#[cfg(target_os = "linux")]
+fn get_linux_meminfo() -> Option<HashMap<String, u64>> {
+    let data = std::fs::read_to_string(get_proc_path("meminfo")).ok()?;
+    let mut meminfo = HashMap::new();
+    for line in data.lines() {
+        let mut parts = line.split_whitespace();
+        if let (Some(key), Some(value)) = (parts.next(), parts.next()) {
+            if let Ok(value) = value.parse::<u64>() {
+                // the HugePages_* counts have no unit
+                let value = if parts.next() == Some("kB") {
+                    value * 1024
+                } else {
+                    value
+                };
+                meminfo.insert(key.trim_end_matches(':').to_string(), value);
+            }
+        }
+    }
+    Some(meminfo)
+}
+
+// -------------------------------------------
+//
+//        ALTERNATE PROCFS COLLECTION
+//
+// -------------------------------------------
+// sysinfo always reads from /proc, so the cpu, memory, network and process list are read by rtop itself when an
+// alternate procfs was set, these return None otherwise and the data from sysinfo is used instead
+
+// the busy and total cpu time of a /proc/stat cpu line, in clock ticks
+struct CpuTimeCounters {
+    busy: u64,
+    total: u64,
+}
+
+fn get_proc_root_cpus(
+    previous_counters: &mut HashMap<String, CpuTimeCounters>,
+) -> Option<Vec<CCpuData>> {
+    #[cfg(target_os = "linux")]
+    if is_alternate_proc_root() {
+        return get_linux_proc_root_cpus(previous_counters);
+    }
+
+    let _ = previous_counters;
+    None
+}
+
+// the memory totals from meminfo in bytes
+fn get_proc_root_meminfo() -> Option<HashMap<String, f64>> {
+    #[cfg(target_os = "linux")]
+    if is_alternate_proc_root() {
+        return get_linux_meminfo().map(|meminfo| {
+            meminfo
+                .into_iter()
+                .map(|(key, value)| (key, value as f64))
+                .collect()
+        });
+    }
+
+    None
+}
+
+fn get_proc_root_networks(
+    previous_counters: &mut HashMap<String, (u64, u64)>,
+) -> Option<Vec<CNetworkData>> {
+    #[cfg(target_os = "linux")]
+    if is_alternate_proc_root() {
+        return get_linux_proc_root_networks(previous_counters);
+    }
+
+    let _ = previous_counters;
+    None
+}
+
+fn get_proc_root_processes(
+    previous_counters: &mut HashMap<u32, ProcessCounters>,
+    elapsed: Duration,
+    users: &Users,
+) -> Option<Vec<ProcessBaseData>> {
+    #[cfg(target_os = "linux")]
+    if is_alternate_proc_root() {
+        return Some(get_linux_proc_root_processes(
+            previous_counters,
+            elapsed,
+            users,
+        ));
+    }
+
+    let _ = (previous_counters, elapsed, users);
+    None
+}
+
+// the "cpu" line is the total of all the cpus, followed by a "cpuN" line for each cpu:
+// "cpu0 user nice system idle iowait irq softirq steal guest guest_nice", the guest time is already part of the user time
+#[cfg(target_os = "linux")]
+fn get_linux_proc_root_cpus(
+    previous_counters: &mut HashMap<String, CpuTimeCounters>,
+) -> Option<Vec<CCpuData>> {
+    let stat = std::fs::read_to_string(get_proc_path("stat")).ok()?;
+    let cpuinfo = std::fs::read_to_string(get_proc_path("cpuinfo")).unwrap_or_default();
+    let brand = cpuinfo
+        .lines()
+        .find(|line| line.starts_with("model name"))
+        .and_then(|line| line.split_once(':'))
+        .map(|(_, brand)| brand.trim().to_string())
+        .unwrap_or_default();
+
+    let mut current_counters = HashMap::new();
+    let mut cpu_data = vec![];
+    for line in stat.lines().filter(|line| line.starts_with("cpu")) {
+        let mut parts = line.split_whitespace();
+        let name = parts.next().unwrap_or_default();
+        let id = match name.strip_prefix("cpu").unwrap_or_default() {
+            "" => -1,
+            id => match id.parse::<i8>() {
+                Ok(id) => id,
+                Err(_) => continue,
+            },
+        };
+        let times: Vec<u64> = parts
+            .take(8)
+            .map(|time| time.parse::<u64>().unwrap_or(0))
+            .collect();
+        let total: u64 = times.iter().sum();
+        let idle = times.get(3).copied().unwrap_or(0) + times.get(4).copied().unwrap_or(0);
+        let counters = CpuTimeCounters {
+            busy: total.saturating_sub(idle),
+            total,
+        };
+
+        // the first collection will not have any usage as there is nothing to compare with
+        let usage = match previous_counters.get(name) {
+            Some(previous) if counters.total > previous.total => {
+                counters.busy.saturating_sub(previous.busy) as f32
+                    / (counters.total - previous.total) as f32
+                    * 100.0
+            }
+            _ => 0.0,
+        };
+        current_counters.insert(name.to_string(), counters);
+
+        cpu_data.push(CCpuData {
+            id,
+            brand: brand.clone(),
+            usage,
+        });
+    }
+
+    *previous_counters = current_counters;
+    // the cpu avg info is expected to be the first entry, followed by at least one cpu
+    if cpu_data.len() < 2 || cpu_data[0].id != -1 {
+        return None;
+    }
+    Some(cpu_data)
+}
+
+// /proc/net/dev follows the network namespace of the process reading it, so the one of the init process is read
+// instead to get the host interfaces, each line is in the form of
+// "  eth0: rx_bytes rx_packets rx_errs rx_drop rx_fifo rx_frame rx_compressed rx_multicast tx_bytes ..."
+#[cfg(target_os = "linux")]
+fn get_linux_proc_root_networks(
+    previous_counters: &mut HashMap<String, (u64, u64)>,
+) -> Option<Vec<CNetworkData>> {
+    let data = std::fs::read_to_string(get_proc_path("1/net/dev")).ok()?;
+    let mut current_counters = HashMap::new();
+    let mut networks_data = vec![];
+    for line in data.lines().skip(2) {
+        let (interface_name, counters) = match line.split_once(':') {
+            Some((interface_name, counters)) => (interface_name.trim(), counters),
+            None => continue,
+        };
+        let counters: Vec<u64> = counters
+            .split_whitespace()
+            .map(|counter| counter.parse::<u64>().unwrap_or(0))
+            .collect();
+        let total_received = counters.first().copied().unwrap_or(0);
+        let total_transmitted = counters.get(8).copied().unwrap_or(0);
+
+        // the same as sysinfo, the current received and transmitted are the bytes since last refresh
+        let (current_received, current_transmitted) = match previous_counters.get(interface_name) {
+            Some((previous_received, previous_transmitted)) => (
+                total_received.saturating_sub(*previous_received),
+                total_transmitted.saturating_sub(*previous_transmitted),
+            ),
+            None => (0, 0),
+        };
+        current_counters.insert(
+            interface_name.to_string(),
+            (total_received, total_transmitted),
+        );
+
+        networks_data.push(CNetworkData {
+            interface_name: interface_name.to_string(),
+            // the addresses of the host interfaces can only be queried from within its network namespace
+            ip_network: None,
+            current_received: current_received as f64,
+            total_received: total_received as f64,
+            current_transmitted: current_transmitted as f64,
+            total_transmitted: total_transmitted as f64,
+        });
+    }
+
+    *previous_counters = current_counters;
+    Some(networks_data)
+}
+
+// cumulative counters of a process from the previous collection, with its start time to tell a reused pid
+struct ProcessCounters {
+    start_time: u64,
+    cpu_ticks: u64,
+    read_bytes: u64,
+    write_bytes: u64,
+}
+
+// the processes are read from /proc/<pid>/stat, status, cmdline, exe and io the same way sysinfo does,
+// the io of the processes owned by other users can only be read with CAP_SYS_PTRACE
+#[cfg(target_os = "linux")]
+fn get_linux_proc_root_processes(
+    previous_counters: &mut HashMap<u32, ProcessCounters>,
+    elapsed: Duration,
+    users: &Users,
+) -> Vec<ProcessBaseData> {
+    let clock_ticks = match unsafe { libc::sysconf(libc::_SC_CLK_TCK) } {
+        ticks if ticks > 0 => ticks as u64,
+        _ => 100,
+    };
+    let boot_time = std::fs::read_to_string(get_proc_path("stat"))
+        .unwrap_or_default()
+        .lines()
+        .find_map(|line| line.strip_prefix("btime "))
+        .and_then(|btime| btime.trim().parse::<u64>().ok())
+        .unwrap_or(0);
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|now| now.as_secs())
+        .unwrap_or(0);
+    let elapsed_secs = elapsed.as_secs_f32().max(0.001);
+
+    let pids: Vec<u32> = match std::fs::read_dir(get_proc_path("")) {
+        Ok(entries) => entries
+            .flatten()
+            .filter_map(|entry| entry.file_name().to_str()?.parse::<u32>().ok())
+            .collect(),
+        Err(_) => vec![],
+    };
+
+    let mut current_counters = HashMap::new();
+    let mut processes = vec![];
+    for pid in pids {
+        // the process could have exited since the directory was listed
+        let stat = match std::fs::read_to_string(get_proc_path(&format!("{}/stat", pid))) {
+            Ok(stat) => stat,
+            Err(_) => continue,
+        };
+        // the name is within the parentheses and can contain spaces and parentheses itself
+        let (name, fields) = match (stat.find('('), stat.rfind(')')) {
+            (Some(start), Some(end)) if start < end => (
+                stat[start + 1..end].to_string(),
+                stat[end + 1..].split_whitespace().collect::<Vec<&str>>(),
+            ),
+            _ => continue,
+        };
+        // the fields are numbered from the state ( field 3 in proc(5) )
+        let get_field = |number: usize| {
+            fields
+                .get(number - 3)
+                .and_then(|field| field.parse::<u64>().ok())
+                .unwrap_or(0)
+        };
+        let state = fields.first().and_then(|state| state.chars().next());
+        let parent = get_field(4);
+        let cpu_ticks = get_field(14) + get_field(15);
+        let thread_count = get_field(20) as u32;
+        // in clock ticks since boot, turned into seconds since the epoch the same as sysinfo's start_time
+        let start_time = boot_time + get_field(22) / clock_ticks;
+
+        let status =
+            std::fs::read_to_string(get_proc_path(&format!("{}/status", pid))).unwrap_or_default();
+        let get_status_value = |key: &str| {
+            status
+                .lines()
+                .find_map(|line| line.strip_prefix(key))
+                .and_then(|value| value.split_whitespace().next())
+                .map(|value| value.to_string())
+        };
+        let user = get_status_value("Uid:")
+            .map(|uid| {
+                uid.parse::<sysinfo::Uid>()
+                    .ok()
+                    .and_then(|id| users.get_user_by_id(&id))
+                    .map(|user| user.name().to_string())
+                    // the users of the host can be missing from the container
+                    .unwrap_or(uid)
+            })
+            .unwrap_or("root".to_string());
+        let memory = get_status_value("VmRSS:")
+            .and_then(|rss| rss.parse::<f64>().ok())
+            .map(|rss| rss * 1024.0) // kB to bytes
+            .unwrap_or(0.0);
+
+        let io = std::fs::read_to_string(get_proc_path(&format!("{}/io", pid))).unwrap_or_default();
+        let get_io_value = |key: &str| {
+            io.lines()
+                .find_map(|line| line.strip_prefix(key))
+                .and_then(|value| value.trim().parse::<u64>().ok())
+                .unwrap_or(0)
+        };
+        let counters = ProcessCounters {
+            start_time,
+            cpu_ticks,
+            read_bytes: get_io_value("read_bytes:"),
+            write_bytes: get_io_value("write_bytes:"),
+        };
+
+        // the first collection of a process will not have any usage as there is nothing to compare with
+        let (cpu_usage, current_read_disk_usage, current_write_disk_usage) =
+            match previous_counters.get(&pid) {
+                Some(previous) if previous.start_time == counters.start_time => (
+                    counters.cpu_ticks.saturating_sub(previous.cpu_ticks) as f32
+                        / clock_ticks as f32
+                        / elapsed_secs
+                        * 100.0,
+                    counters.read_bytes.saturating_sub(previous.read_bytes),
+                    counters.write_bytes.saturating_sub(previous.write_bytes),
+                ),
+                _ => (0.0, 0, 0),
+            };
+
+        let cmd: Vec<String> = std::fs::read_to_string(get_proc_path(&format!("{}/cmdline", pid)))
+            .unwrap_or_default()
+            .split('\0')
+            .filter(|arg| !arg.is_empty())
+            .map(|arg| arg.to_string())
+            .collect();
+        let exe_path = std::fs::read_link(get_proc_path(&format!("{}/exe", pid)))
+            .ok()
+            .map(|exe| exe.to_string_lossy().to_string());
+
+        processes.push(ProcessBaseData {
+            pid,
+            name,
+            exe_path,
+            cmd,
+            user,
+            cpu_usage,
+            thread_count,
+            memory,
+            status: get_process_state_name(state).to_string(),
+            elapsed: now.saturating_sub(start_time),
+            parent: if parent > 0 {
+                parent.to_string()
+            } else {
+                "-".to_string()
+            },
+            start_time,
+            current_read_disk_usage,
+            total_read_disk_usage: counters.read_bytes,
+            current_write_disk_usage,
+            total_write_disk_usage: counters.write_bytes,
+        });
+        current_counters.insert(pid, counters);
+    }
+
+    *previous_counters = current_counters;
+    processes
+}
+
+// the pids read from an alternate procfs only refer to the same processes in the pid namespace rtop runs in when it
+// shares the pid namespace of the host ( eg. docker run --pid=host ), which is told by their start time
+pub fn is_local_process(pid: u32) -> bool {
+    #[cfg(target_os = "linux")]
+    if is_alternate_proc_root() {
+        let get_start_time = |path: PathBuf| {
+            let stat = std::fs::read_to_string(path).ok()?;
+            // the starttime is the 22nd field, the 20th after the name
+            stat[stat.rfind(')')? + 1..]
+                .split_whitespace()
+                .nth(19)
+                .map(|start_time| start_time.to_string())
+        };
+        let start_time = get_start_time(get_proc_path(&format!("{}/stat", pid)));
+        return start_time.is_some()
+            && start_time == get_start_time(get_local_proc_path(&format!("{}/stat", pid)));
+    }
+
+    let _ = pid;
+    true
+}
+
+// the same names as the ones sysinfo gives to the process states
+#[cfg(target_os = "linux")]
+fn get_process_state_name(state: Option<char>) -> &'static str {
+    match state {
+        Some('R') => "Runnable",
+        Some('S') => "Sleeping",
+        Some('I') => "Idle",
+        Some('D') => "UninterruptibleDiskSleep",
+        Some('Z') => "Zombie",
+        Some('T') => "Stopped",
+        Some('t') => "Tracing",
+        Some('X') | Some('x') => "Dead",
+        Some('K') => "Wakekill",
+        Some('W') => "Waking",
+        Some('P') => "Parked",
+        _ => "Unknown",
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn get_window_cached_memory() -> Option<u64> {
+    use std::mem;
+    use winapi::um::psapi::{GetPerformanceInfo, PERFORMANCE_INFORMATION};
+    unsafe {
+        let mut perf_info: PERFORMANCE_INFORMATION = mem::zeroed();
+        perf_info.cb = mem::size_of::<PERFORMANCE_INFORMATION>() as u32;
+
+        if GetPerformanceInfo(&mut perf_info as *mut PERFORMANCE_INFORMATION, perf_info.cb) != 0 {
+            let page_size = perf_info.PageSize as u64;
+            let cached_pages = perf_info.SystemCache as u64;
+            return Some(page_size * cached_pages);
+        } else {
+            return None;
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn get_win_thread_counts() -> HashMap<String, u32> {
+    // not very optimize it seems ( not fast enough, tho was tested in VM )
+    use winapi::shared::minwindef::{DWORD, TRUE};
+    use winapi::shared::ntdef::NULL;
+    use winapi::um::handleapi::CloseHandle;
+    use winapi::um::tlhelp32::{
+        CreateToolhelp32Snapshot, Thread32First, Thread32Next, TH32CS_SNAPTHREAD, THREADENTRY32,
+    };
+
+    let mut thread_counts: HashMap<String, u32> = HashMap::new();
+
+    // Create a snapshot of all threads in the system
+    let snapshot = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0) };
+    if snapshot == NULL {
+        // Handle error: Snapshot creation failed.
+        // In a real application, you might want to log this or return a Result.
+        eprintln!("Failed to create thread snapshot.");
+        return thread_counts; // Return an empty HashMap on error
+    }
+
+    let mut thread_entry = THREADENTRY32 {
+        dwSize: std::mem::size_of::<THREADENTRY32>() as DWORD,
+        cntUsage: 0,
+        th32ThreadID: 0,
+        th32OwnerProcessID: 0,
+        tpBasePri: 0,
+        tpDeltaPri: 0,
+        dwFlags: 0,
+    };
+
+    // Iterate through threads
+    if unsafe { Thread32First(snapshot, &mut thread_entry) } == TRUE {
+        loop {
+            let pid = thread_entry.th32OwnerProcessID;
+            let pid_str = pid.to_string();
+
+            // Increment the thread count for this PID
+            *thread_counts.entry(pid_str).or_insert(0) += 1;
+
+            // Move to the next thread
+            if unsafe { Thread32Next(snapshot, &mut thread_entry) } != TRUE {
+                break;
+            }
+        }
+    } else {
+        // If Thread32First fails, it might mean no threads were found or an error occurred.
+        // Again, more robust error handling might be needed here.
+        eprintln!("Failed to get the first thread.");
+    }
+
+    // Close the snapshot handle
+    unsafe { CloseHandle(snapshot) };
+
+    return thread_counts;
+}
+
+// the collectors that follow --proc-root / --sys-root were checked against the fixture tree in tests/fixtures/host
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    // the roots can only be set once per process, every test in here uses the same fixture tree
+    fn set_fixture_root() {
+        let fixture_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/host");
+        set_proc_and_sys_root(
+            Some(fixture_root.join("proc").to_string_lossy().to_string()),
+            Some(fixture_root.join("sys").to_string_lossy().to_string()),
+        );
+        assert_eq!(get_proc_path("stat"), fixture_root.join("proc/stat"));
+        assert_eq!(
+            get_sys_path("fs/cgroup"),
+            fixture_root.join("sys/fs/cgroup")
+        );
+    }
+
+    #[test]
+    fn local_paths_ignore_the_alternate_root() {
+        set_fixture_root();
+        assert!(is_alternate_proc_root());
+        assert_eq!(
+            get_local_proc_path("self/cgroup"),
+            PathBuf::from("/proc/self/cgroup")
+        );
+        assert_eq!(
+            get_local_sys_path("fs/cgroup"),
+            PathBuf::from("/sys/fs/cgroup")
+        );
+    }
+
+    #[test]
+    fn interrupts_are_read_from_the_proc_root() {
+        set_fixture_root();
+        let mut counters = InterruptCounters::default();
+        let interrupt_data = get_linux_interrupts(&mut counters, Duration::from_secs(1)).unwrap();
+        assert_eq!(interrupt_data.cpu_count, 2);
+        assert_eq!(interrupt_data.context_switch_rate, 0.0);
+        assert_eq!(counters.context_switches, Some(340551297));
+        assert_eq!(counters.interrupts, Some(199292448));
+        assert_eq!(counters.forks, Some(3411528));
+
+        let timer = &interrupt_data.sources[0];
+        assert_eq!(timer.id, "0");
+        assert_eq!(timer.description, "IO-APIC 2-edge timer");
+        // ERR only has a single count instead of one for each cpu
+        let err = interrupt_data
+            .sources
+            .iter()
+            .find(|source| source.id == "ERR")
+            .unwrap();
+        assert_eq!(err.cpu_rate.len(), 1);
+        let net_rx = interrupt_data
+            .sources
+            .iter()
+            .find(|source| source.id == "NET_RX")
+            .unwrap();
+        assert_eq!(net_rx.description, "softirq");
+        assert_eq!(counters.sources["softirqs:NET_RX"], vec![12001, 11013]);
+
+        // rates are calculated against the previous counters
+        counters.context_switches = Some(340551297 - 2000);
+        counters
+            .sources
+            .insert("softirqs:NET_RX".to_string(), vec![11001, 11013]);
+        let interrupt_data = get_linux_interrupts(&mut counters, Duration::from_secs(2)).unwrap();
+        assert_eq!(interrupt_data.context_switch_rate, 1000.0);
+        let net_rx = interrupt_data
+            .sources
+            .iter()
+            .find(|source| source.id == "NET_RX")
+            .unwrap();
+        assert_eq!(net_rx.cpu_rate, vec![500.0, 0.0]);
+        assert_eq!(net_rx.total_rate, 500.0);
+    }
+
+    #[test]
+    fn vm_activity_is_read_from_the_proc_root() {
+        set_fixture_root();
+        let mut counters = HashMap::new();
+        // there is nothing to diff against on the first collection
+        assert!(get_linux_vm_activity(&mut counters).is_none());
+        assert_eq!(counters["pgmajfault"], 4321);
+
+        counters.insert("pgpgin".to_string(), 1048576 - 4);
+        counters.insert("oom_kill".to_string(), 1);
+        let vm_activity = get_linux_vm_activity(&mut counters).unwrap();
+        assert_eq!(vm_activity.page_in, 4.0 * 1024.0);
+        assert_eq!(vm_activity.page_out, 0.0);
+        assert_eq!(vm_activity.oom_kills, 1.0);
+    }
+
+    #[test]
+    fn cgroups_are_read_from_the_sys_root() {
+        set_fixture_root();
+        let mut counters = HashMap::new();
+        let mut cgroups = get_linux_cgroups(&mut counters, Duration::from_secs(1), 2);
+        cgroups.sort_by(|a, b| a.path.cmp(&b.path));
+        let paths: Vec<&str> = cgroups.iter().map(|cgroup| cgroup.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["/", "/system.slice", "/system.slice/nginx.service"]
+        );
+
+        let root = &cgroups[0];
+        assert_eq!(root.memory_current, 4294967296.0);
+        assert_eq!(root.memory_max, None);
+        assert_eq!(root.pids, 312);
+        // "max" means there is no limit
+        assert_eq!(cgroups[1].memory_max, None);
+        assert_eq!(cgroups[2].memory_max, Some(268435456.0));
+        // the first collection of a cgroup will not have any usage
+        assert_eq!(cgroups[2].io_read, 0.0);
+
+        // io.stat has a line per device, the bytes are summed up and turned into rates per second
+        let nginx = counters.get_mut("/system.slice/nginx.service").unwrap();
+        assert_eq!(
+            (nginx.io_read_bytes, nginx.io_write_bytes),
+            (2000000, 500000)
+        );
+        nginx.cpu_usage_usec = 0;
+        nginx.io_read_bytes = 0;
+        nginx.io_write_bytes = 0;
+        let cgroups = get_linux_cgroups(&mut counters, Duration::from_secs(2), 2);
+        let nginx = cgroups
+            .iter()
+            .find(|cgroup| cgroup.path == "/system.slice/nginx.service")
+            .unwrap();
+        assert_eq!(nginx.io_read, 1000000.0);
+        assert_eq!(nginx.io_write, 250000.0);
+        assert_eq!(nginx.cpu_usage, 50.0);
+    }
+
+    #[test]
+    fn batteries_are_read_from_the_sys_root() {
+        set_fixture_root();
+        // the AC adapter is not a battery
+        let batteries = get_linux_batteries();
+        assert_eq!(batteries.len(), 1);
+
+        let battery = &batteries[0];
+        assert_eq!(battery.name, "BAT0");
+        assert_eq!(battery.status, "Discharging");
+        assert_eq!(battery.charge, 50.0);
+        assert_eq!(battery.power_draw, 10.0);
+        // 25 Wh left at 10 W
+        assert_eq!(battery.time_to_empty, Some(9000));
+        assert_eq!(battery.health, Some(80.0));
+    }
+
+    #[test]
+    fn cpus_are_read_from_the_proc_root() {
+        set_fixture_root();
+        let mut counters = HashMap::new();
+        let cpus = get_proc_root_cpus(&mut counters).unwrap();
+        assert_eq!(
+            cpus.iter().map(|cpu| cpu.id).collect::<Vec<i8>>(),
+            vec![-1, 0, 1]
+        );
+        assert_eq!(cpus[0].brand, "Intel(R) Xeon(R) CPU E5-2680 v4 @ 2.40GHz");
+        // the first collection will not have any usage
+        assert_eq!(cpus[1].usage, 0.0);
+
+        // the iowait is idle time as well, 300 of the 400 ticks since the previous collection were busy
+        let cpu0 = counters.get_mut("cpu0").unwrap();
+        assert_eq!((cpu0.busy, cpu0.total), (2016177, 15365599));
+        cpu0.busy -= 300;
+        cpu0.total -= 400;
+        let cpus = get_proc_root_cpus(&mut counters).unwrap();
+        assert_eq!(cpus[1].usage, 75.0);
+    }
+
+    #[test]
+    fn memory_is_read_from_the_proc_root() {
+        set_fixture_root();
+        let meminfo = get_proc_root_meminfo().unwrap();
+        assert_eq!(meminfo["MemTotal"], 16318412.0 * 1024.0);
+        assert_eq!(meminfo["MemAvailable"], 9874456.0 * 1024.0);
+        assert_eq!(
+            meminfo["SwapTotal"] - meminfo["SwapFree"],
+            524288.0 * 1024.0
+        );
+        // the counts without an unit are not turned into bytes
+        assert_eq!(meminfo["HugePages_Total"], 0.0);
+        assert_eq!(get_linux_cached_memory(), Some(7651220 * 1024));
+    }
+
+    #[test]
+    fn networks_are_read_from_the_proc_root() {
+        set_fixture_root();
+        let mut counters = HashMap::new();
+        let networks = get_proc_root_networks(&mut counters).unwrap();
+        assert_eq!(
+            networks
+                .iter()
+                .map(|network| network.interface_name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["lo", "eth0"]
+        );
+        assert_eq!(networks[1].total_received, 987654321.0);
+        assert_eq!(networks[1].total_transmitted, 123456789.0);
+        assert_eq!(networks[1].current_received, 0.0);
+
+        // the current received and transmitted are the bytes since the previous collection
+        counters.insert("eth0".to_string(), (987654321 - 1000, 123456789 - 500));
+        let networks = get_proc_root_networks(&mut counters).unwrap();
+        assert_eq!(networks[1].current_received, 1000.0);
+        assert_eq!(networks[1].current_transmitted, 500.0);
+    }
+
+    #[test]
+    fn processes_are_read_from_the_proc_root() {
+        set_fixture_root();
+        let mut counters = HashMap::new();
+        let users = Users::new();
+        let mut processes =
+            get_proc_root_processes(&mut counters, Duration::from_secs(1), &users).unwrap();
+        processes.sort_by_key(|process| process.pid);
+        assert_eq!(
+            processes
+                .iter()
+                .map(|process| process.pid)
+                .collect::<Vec<u32>>(),
+            vec![1, 4242]
+        );
+
+        let init = &processes[0];
+        assert_eq!(init.name, "systemd");
+        assert_eq!(init.cmd, vec!["/sbin/init", "splash"]);
+        assert_eq!(init.exe_path.as_deref(), Some("/usr/lib/systemd/systemd"));
+        assert_eq!(init.status, "Sleeping");
+        assert_eq!(init.parent, "-");
+        assert_eq!(init.memory, 12804.0 * 1024.0);
+        assert_eq!(init.total_read_disk_usage, 409600);
+        assert_eq!(init.total_write_disk_usage, 204800);
+        // the first collection of a process will not have any usage
+        assert_eq!(init.cpu_usage, 0.0);
+
+        // the name can contain parentheses, and the users of the host can be missing from the container
+        let worker = &processes[1];
+        assert_eq!(worker.name, "nginx: worker (1)");
+        assert_eq!(worker.user, "1000");
+        assert_eq!(worker.status, "Runnable");
+        assert_eq!(worker.parent, "1");
+        assert_eq!(worker.thread_count, 4);
+        assert_eq!(worker.start_time, 1700000000 + 3600);
+        assert_eq!(worker.exe_path, None);
+
+        // 50 clock ticks of cpu time in a second, a reused pid starts over
+        counters.get_mut(&4242).unwrap().cpu_ticks -= 50;
+        counters.get_mut(&1).unwrap().start_time -= 1;
+        let processes =
+            get_proc_root_processes(&mut counters, Duration::from_secs(1), &users).unwrap();
+        let get_cpu_usage = |pid: u32| {
+            processes
+                .iter()
+                .find(|process| process.pid == pid)
+                .map(|process| process.cpu_usage)
+        };
+        assert_eq!(get_cpu_usage(4242), Some(50.0));
+        assert_eq!(get_cpu_usage(1), Some(0.0));
+    }
 }
@@ -1,70 +1,191 @@
 pub mod app;
 pub mod components;
+pub mod config;
 pub mod get_sys_info;
 pub mod types;
 pub mod utils;
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use components::*;
 
 use app::*;
 use inquire::Select;
+use serde_json::Value;
 
-use crate::components::theme::set_theme;
+use crate::{
+    components::theme::{
+        detect_terminal_background, get_theme_names, set_auto_theme, set_color_mode, set_theme,
+    },
+    config::{get_config_display_path, load_app_config, set_config_filepath, MAX_TICK, MIN_TICK},
+    get_sys_info::set_proc_and_sys_root,
+    types::{ProcessPreFilter, ProcessSortType, SelectedContainer},
+    utils::set_byte_unit,
+};
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Arg {
+    /// Color theme for this run, eg. --theme dracula ( see --list-themes ).
+    /// Without a name, prompt for a theme to be saved in the settings instead
+    #[arg(long, value_name = "NAME", num_args = 0..=1)]
+    theme: Option<Option<String>>,
+
+    /// Print the available color themes and exit
     #[arg(long)]
-    theme: bool,
+    list_themes: bool,
+
+    /// Refresh rate of the metrics in ms, from 100 to 10000
+    #[arg(long, value_name = "MS", value_parser = clap::value_parser!(u32).range(MIN_TICK as i64..=MAX_TICK as i64))]
+    tick: Option<u32>,
+
+    /// Sorting of the process list: thread, memory, cpu, pid, name, command, user, container,
+    /// oom_score, context_switch, page_fault, run_delay, disk_read or disk_write
+    #[arg(long, value_name = "TYPE", value_parser = parse_process_sort_type)]
+    sort: Option<ProcessSortType>,
+
+    /// Sort the process list in descending order, --reverse false for ascending order
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, default_missing_value = "true")]
+    reverse: Option<bool>,
+
+    /// Filter the process list by name, command, user or container, the same as typing it with "f"
+    #[arg(long, value_name = "TEXT")]
+    filter: Option<String>,
+
+    /// Only show the process with this PID, can be repeated
+    #[arg(long, value_name = "PID")]
+    pid: Vec<u32>,
+
+    /// Only show the processes of this user, can be repeated
+    #[arg(long, value_name = "USER")]
+    user: Vec<String>,
+
+    /// Start with this panel selected in full screen
+    #[arg(long, value_name = "PANEL")]
+    fullscreen: Option<FullScreenPanel>,
+
+    /// Settings file to use instead of ~/.rtop/settings.json, it will be created if it does not exist
+    #[arg(long, value_name = "PATH")]
+    config: Option<String>,
+
+    /// Alternate procfs mount point, eg. the host's /proc mounted at /host/proc inside a container.
+    /// The process list and the cpu/memory/network metrics are read from it, the disks are
+    /// always the ones mounted where rtop runs
+    #[arg(long, value_name = "PATH")]
+    proc_root: Option<String>,
+
+    /// Alternate sysfs mount point, eg. the host's /sys mounted at /host/sys inside a container.
+    /// Only applies to the cgroup, RAPL power, battery and NUMA panels
+    #[arg(long, value_name = "PATH")]
+    sys_root: Option<String>,
+}
+
+#[derive(ValueEnum, Clone, Debug)]
+enum FullScreenPanel {
+    Cpu,
+    Memory,
+    Disk,
+    Network,
+    Process,
+    Battery,
+    Cgroup,
+    Session,
+    Interrupt,
+}
+
+impl FullScreenPanel {
+    fn get_selected_container(&self) -> SelectedContainer {
+        match self {
+            FullScreenPanel::Cpu => SelectedContainer::Cpu,
+            FullScreenPanel::Memory => SelectedContainer::Memory,
+            FullScreenPanel::Disk => SelectedContainer::Disk,
+            FullScreenPanel::Network => SelectedContainer::Network,
+            FullScreenPanel::Process => SelectedContainer::Process,
+            FullScreenPanel::Battery => SelectedContainer::Battery,
+            FullScreenPanel::Cgroup => SelectedContainer::Cgroup,
+            FullScreenPanel::Session => SelectedContainer::Session,
+            FullScreenPanel::Interrupt => SelectedContainer::Interrupt,
+        }
+    }
+}
+
+// the sort names are the same as the "sort" in the settings
+fn parse_process_sort_type(value: &str) -> Result<ProcessSortType, String> {
+    serde_json::from_value(Value::String(value.to_string())).map_err(|error| error.to_string())
 }
 
 fn main() {
     let args = Arg::parse();
-    if args.theme {
+    set_config_filepath(args.config);
+
+    if args.list_themes {
+        for theme in get_theme_names() {
+            println!("{}", theme);
+        }
+        return;
+    }
+
+    if let Some(None) = args.theme {
         prompt_for_theme();
-    } else {
-        app();
+        return;
+    }
+
+    // command line options take precedence over the settings, they only apply to this run
+    let (mut app_config, config_warnings) = load_app_config();
+    if let Some(Some(theme)) = args.theme {
+        if !get_theme_names().contains(&theme) {
+            eprintln!(
+                "error: unknown theme '{}', see --list-themes for the available themes",
+                theme
+            );
+            std::process::exit(2);
+        }
+        app_config.theme = theme;
+    }
+    if let Some(tick) = args.tick {
+        app_config.tick = tick;
+    }
+    if let Some(sort) = args.sort {
+        app_config.process.sort = sort;
     }
+    if let Some(reverse) = args.reverse {
+        app_config.process.reverse = reverse;
+    }
+    if let Some(filter) = args.filter {
+        app_config.process.filter = filter;
+    }
+
+    let proc_root = args.proc_root.or(app_config.proc_root.clone());
+    let sys_root = args.sys_root.or(app_config.sys_root.clone());
+    set_proc_and_sys_root(proc_root, sys_root);
+    set_byte_unit(app_config.units);
+    set_color_mode(app_config.colors);
+    set_auto_theme(app_config.auto_theme.clone());
+    detect_terminal_background();
+
+    let process_pre_filter = ProcessPreFilter {
+        pids: args.pid,
+        users: args.user,
+    };
+    let full_screen_container = args.fullscreen.map(|panel| panel.get_selected_container());
+    app(
+        app_config,
+        config_warnings,
+        process_pre_filter,
+        full_screen_container,
+    );
 }
 
 fn prompt_for_theme() {
     println!("Starting in theme selection mode...");
 
-    let themes = vec![
-        "default",
-        "dracula",
-        "gruvbox_dark",
-        "gruvbox_light",
-        "gruvbox_mat_dark",
-        "ayu",
-        "everforest_dark",
-        "everforest_light",
-        "flatremix",
-        "flatremix_light",
-        "grayscale",
-        "horizon",
-        "kanagawa_wave",
-        "kanagawa_lotus",
-        "monokai",
-        "onedark",
-        "nightowl",
-        "rosepine",
-        "matcha_dark_sea",
-        "paper",
-        "solarized_dark",
-        "solarized_light",
-        "tokyo_night",
-        "tokyo_storm",
-        "catppuccin_mocha",
-        "github_dark",
-    ];
-    let ans = Select::new("Please choose a color theme:", themes).prompt();
+    let ans = Select::new("Please choose a color theme:", get_theme_names()).prompt();
 
     match ans {
         Ok(theme) => {
             println!("You chose: {}. Setting theme...", theme);
-            set_theme(theme.to_string());
+            for warning in set_theme(theme.to_string()) {
+                println!("{} {}", get_config_display_path(), warning);
+            }
             println!("Theme set")
         }
         Err(_) => {
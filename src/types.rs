@@ -1,11 +1,382 @@
-use ratatui::style::Color;
+use ratatui::{
+    style::{Color, Modifier},
+    widgets::ListState,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Instant};
 use sysinfo::Signal;
 
-#[derive(Serialize, Deserialize)]
-pub struct ThemeConfig {
+// the version of the settings schema, bump this when a key was renamed or changed its meaning
+// version 1 was the settings that only had the theme ( and the proc/sys root )
+pub const CONFIG_VERSION: u32 = 2;
+
+// the settings saved at ~/.rtop/settings.json, missing keys will fall back to the default
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct AppConfig {
+    #[serde(default = "AppConfig::legacy_version")]
+    pub version: u32,
     pub theme: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proc_root: Option<String>, // alternate procfs mount point, can be override by --proc-root
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sys_root: Option<String>, // alternate sysfs mount point, can be override by --sys-root
+    pub tick: u32, // refresh rate for the metrics in ms
+    pub graph_range: GraphRangeConfig,
+    pub process: ProcessConfig,
+    pub panels: PanelConfig,
+    pub layouts: Vec<LayoutConfig>, // the layouts of the main view that can be cycled through, the first one will be used on start up
+    pub units: ByteUnit,
+    pub colors: ColorMode, // the colors the terminal can show, the theme will be mapped to the nearest colors it has
+    pub auto_theme: AutoThemeConfig,
+}
+
+impl AppConfig {
+    // settings without the version key were written before the schema was versioned
+    fn legacy_version() -> u32 {
+        1
+    }
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        AppConfig {
+            version: CONFIG_VERSION,
+            theme: "default".to_string(),
+            proc_root: None,
+            sys_root: None,
+            tick: 1000,
+            graph_range: GraphRangeConfig::default(),
+            process: ProcessConfig::default(),
+            panels: PanelConfig::default(),
+            layouts: LayoutConfig::get_default_layouts(),
+            units: ByteUnit::Binary,
+            colors: ColorMode::Auto,
+            auto_theme: AutoThemeConfig::default(),
+        }
+    }
+}
+
+// the initial range of graph shown for each container, from 100 to 500 data points
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct GraphRangeConfig {
+    pub cpu: usize,
+    pub memory: usize,
+    pub disk: usize,
+    pub network: usize,
+    pub process: usize,
+    pub battery: usize,
+    pub cgroup: usize,
+    pub interrupt: usize,
+}
+
+impl Default for GraphRangeConfig {
+    fn default() -> Self {
+        GraphRangeConfig {
+            cpu: 100,
+            memory: 100,
+            disk: 100,
+            network: 100,
+            process: 100,
+            battery: 100,
+            cgroup: 100,
+            interrupt: 100,
+        }
+    }
+}
+
+// the initial sorting and filtering of the process list
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProcessConfig {
+    pub sort: ProcessSortType,
+    pub reverse: bool, // true for descending order
+    pub filter: String,
+}
+
+impl Default for ProcessConfig {
+    fn default() -> Self {
+        ProcessConfig {
+            sort: ProcessSortType::Thread,
+            reverse: true,
+            filter: String::new(),
+        }
+    }
+}
+
+// the containers shown in the main view, hidden containers can still be selected and shown in full screen
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct PanelConfig {
+    pub cpu: bool,
+    pub memory: bool,
+    pub disk: bool,
+    pub network: bool,
+    pub process: bool,
+    pub cgroup: bool, // only shown in the main view when it was placed in the layout
+}
+
+impl PanelConfig {
+    pub fn is_any_shown(&self) -> bool {
+        self.cpu || self.memory || self.disk || self.network || self.process || self.cgroup
+    }
+
+    pub fn set_shown(&mut self, panel: LayoutPanel, is_shown: bool) {
+        match panel {
+            LayoutPanel::Cpu => self.cpu = is_shown,
+            LayoutPanel::Memory => self.memory = is_shown,
+            LayoutPanel::Disk => self.disk = is_shown,
+            LayoutPanel::Network => self.network = is_shown,
+            LayoutPanel::Process => self.process = is_shown,
+            LayoutPanel::Cgroup => self.cgroup = is_shown,
+        }
+    }
+
+    pub fn is_shown(&self, panel: LayoutPanel) -> bool {
+        match panel {
+            LayoutPanel::Cpu => self.cpu,
+            LayoutPanel::Memory => self.memory,
+            LayoutPanel::Disk => self.disk,
+            LayoutPanel::Network => self.network,
+            LayoutPanel::Process => self.process,
+            LayoutPanel::Cgroup => self.cgroup,
+        }
+    }
+}
+
+impl Default for PanelConfig {
+    fn default() -> Self {
+        PanelConfig {
+            cpu: true,
+            memory: true,
+            disk: true,
+            network: true,
+            process: true,
+            cgroup: true,
+        }
+    }
+}
+
+// the containers that can be placed in a layout, the other containers are always shown in full screen
+// the cgroup panel is not in the default layouts, it will be shown in full screen unless it was placed in the layout
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum LayoutPanel {
+    Cpu,
+    Memory,
+    Disk,
+    Network,
+    Process,
+    Cgroup,
+}
+
+impl LayoutPanel {
+    // the battery was drawn in the network area, so it goes along with the network panel
+    pub fn is_containing(&self, selected_container: &SelectedContainer) -> bool {
+        matches!(
+            (self, selected_container),
+            (LayoutPanel::Cpu, SelectedContainer::Cpu)
+                | (LayoutPanel::Memory, SelectedContainer::Memory)
+                | (LayoutPanel::Disk, SelectedContainer::Disk)
+                | (LayoutPanel::Network, SelectedContainer::Network)
+                | (LayoutPanel::Network, SelectedContainer::Battery)
+                | (LayoutPanel::Process, SelectedContainer::Process)
+                | (LayoutPanel::Cgroup, SelectedContainer::Cgroup)
+        )
+    }
+}
+
+// a layout of the main view, split into either rows ( top to bottom ) or columns ( left to right )
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct LayoutConfig {
+    pub name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rows: Vec<LayoutNode>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<LayoutNode>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub hidden: Vec<LayoutPanel>, // the panels in this layout that are not shown, they can still be selected and shown in full screen
+}
+
+impl LayoutConfig {
+    // the whole layout as a node, so that it can be split the same way as the nodes in it
+    pub fn get_root_node(&self) -> LayoutNode {
+        LayoutNode {
+            ratio: 1,
+            panel: None,
+            rows: self.rows.clone(),
+            columns: self.columns.clone(),
+        }
+    }
+
+    //   default              wide                 compact
+    //   ----------------     ----------------     ----------------
+    //   |     CPU      |     | CPU   |      |     |     CPU      |
+    //   ----------------     |-------|      |     ----------------
+    //   | MEM | |      |     |MEM|DSK| PROC |     |              |
+    //   |-DSK-| | PROC |     |-------|      |     |     PROC     |
+    //   | NET | |      |     | NET   |      |     |              |
+    //   ----------------     ----------------     ----------------
+    pub fn get_default_layouts() -> Vec<LayoutConfig> {
+        let memory_and_disk = |ratio: u16| {
+            LayoutNode::columns(
+                ratio,
+                vec![
+                    LayoutNode::panel(LayoutPanel::Memory, 50),
+                    LayoutNode::panel(LayoutPanel::Disk, 50),
+                ],
+            )
+        };
+        vec![
+            LayoutConfig {
+                name: "default".to_string(),
+                rows: vec![
+                    LayoutNode::panel(LayoutPanel::Cpu, 30),
+                    LayoutNode::columns(
+                        70,
+                        vec![
+                            LayoutNode::rows(
+                                45,
+                                vec![
+                                    memory_and_disk(65),
+                                    LayoutNode::panel(LayoutPanel::Network, 35),
+                                ],
+                            ),
+                            LayoutNode::panel(LayoutPanel::Process, 55),
+                        ],
+                    ),
+                ],
+                columns: vec![],
+                hidden: vec![],
+            },
+            LayoutConfig {
+                name: "wide".to_string(),
+                rows: vec![],
+                columns: vec![
+                    LayoutNode::rows(
+                        50,
+                        vec![
+                            LayoutNode::panel(LayoutPanel::Cpu, 30),
+                            memory_and_disk(40),
+                            LayoutNode::panel(LayoutPanel::Network, 30),
+                        ],
+                    ),
+                    LayoutNode::panel(LayoutPanel::Process, 50),
+                ],
+                hidden: vec![],
+            },
+            LayoutConfig {
+                name: "compact".to_string(),
+                rows: vec![
+                    LayoutNode::panel(LayoutPanel::Cpu, 30),
+                    LayoutNode::panel(LayoutPanel::Process, 70),
+                ],
+                columns: vec![],
+                hidden: vec![],
+            },
+        ]
+    }
+}
+
+// either a panel or a split into rows or columns, the nodes in the same split share the space by their ratio
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct LayoutNode {
+    pub ratio: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub panel: Option<LayoutPanel>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rows: Vec<LayoutNode>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<LayoutNode>,
+}
+
+impl LayoutNode {
+    pub fn panel(panel: LayoutPanel, ratio: u16) -> Self {
+        LayoutNode {
+            ratio,
+            panel: Some(panel),
+            ..LayoutNode::default()
+        }
+    }
+
+    pub fn rows(ratio: u16, rows: Vec<LayoutNode>) -> Self {
+        LayoutNode {
+            ratio,
+            rows,
+            ..LayoutNode::default()
+        }
+    }
+
+    pub fn columns(ratio: u16, columns: Vec<LayoutNode>) -> Self {
+        LayoutNode {
+            ratio,
+            columns,
+            ..LayoutNode::default()
+        }
+    }
+
+    // the panels within this node, in the order they were placed
+    pub fn get_panels(&self) -> Vec<LayoutPanel> {
+        self.panel
+            .into_iter()
+            .chain(self.rows.iter().flat_map(|node| node.get_panels()))
+            .chain(self.columns.iter().flat_map(|node| node.get_panels()))
+            .collect()
+    }
+}
+
+impl Default for LayoutNode {
+    fn default() -> Self {
+        LayoutNode {
+            ratio: 1,
+            panel: None,
+            rows: vec![],
+            columns: vec![],
+        }
+    }
+}
+
+// the themes that the "auto" theme picks from, according to the background color of the terminal
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct AutoThemeConfig {
+    pub light: String,
+    pub dark: String,
+}
+
+impl Default for AutoThemeConfig {
+    fn default() -> Self {
+        AutoThemeConfig {
+            light: "gruvbox_light".to_string(),
+            dark: "gruvbox_dark".to_string(),
+        }
+    }
+}
+
+// binary for KiB/MiB/GiB ( 1024 ), decimal for kB/MB/GB ( 1000 )
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ByteUnit {
+    Binary,
+    Decimal,
+}
+
+// auto will be detected from NO_COLOR, COLORTERM and TERM on start up
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub enum ColorMode {
+    #[serde(rename = "auto")]
+    Auto,
+    #[serde(rename = "truecolor")]
+    TrueColor,
+    #[serde(rename = "256color")]
+    Ansi256,
+    #[serde(rename = "16color")]
+    Ansi16,
+    #[serde(rename = "none")]
+    NoColor,
 }
 
 // the main type structture for the application
@@ -14,12 +385,30 @@ pub struct SysInfo {
     pub memory: MemoryData,
     pub disks: HashMap<String, DiskData>,
     pub networks: HashMap<String, NetworkData>,
+    pub cgroups: Option<HashMap<String, CgroupData>>, // keyed by the cgroup path relative to the cgroup v2 mount, "/" being the root cgroup, None until the cgroup panel was first shown
+    pub container_limit: Option<ContainerLimitData>, // only available when rtop itself was running under cgroup limits ( inside a container for example )
+    pub batteries: HashMap<String, BatteryData>, // keyed by the power supply name ( BAT0 for example ), empty on machines without battery
+    pub power_zones: HashMap<String, PowerZoneData>, // keyed by the RAPL powercap zone ( intel-rapl:0 for example ), empty when RAPL was not available
+    pub sessions: Vec<SessionData>, // logged-in sessions from utmp, replaced entirely on every refresh
+    pub interrupts: InterruptData,
+    pub numa_nodes: HashMap<usize, NumaNodeData>, // keyed by the node id ( 0 for node0 ), empty when the NUMA topology was not exposed ( linux only )
 }
 
 pub struct ProcessesInfo {
     pub processes: HashMap<String, ProcessData>, // as a hashmap to easily update existing data by retrieving it based on PID which is the key
+    pub recently_exited: Vec<ExitedProcessData>, // processes that disappeared within the last few seconds, used to name the victim of an OOM kill
 }
 
+// a process that disappeared from the process list, only what is needed to name the victim of an OOM kill was kept
+pub struct ExitedProcessData {
+    pub exited_at: Instant,
+    pub pid: u32,
+    pub name: String,
+    pub oom_score: Option<i32>,
+}
+
+// the keys of the theme files are the same as the field names
+#[derive(Deserialize, Clone)]
 pub struct AppColorInfo {
     pub background_color: Color,
     pub base_app_text_color: Color,
@@ -72,6 +461,10 @@ pub struct AppColorInfo {
     pub process_text_color: Color,
     pub process_selected_color_bg: Color,
     pub process_selected_color_fg: Color,
+
+    // not part of the theme files, this will only be set when there are no colors so that the selection can still be seen
+    #[serde(skip)]
+    pub selected_modifier: Modifier,
 }
 
 const MAXIMUM_DATA_COLLECTION: usize = 500;
@@ -90,6 +483,14 @@ pub struct MemoryData {
     pub used_swap_vec: Vec<f64>,
     pub free_memory_vec: Vec<f64>, // free means memory that is not used at all
     pub cached_memory_vec: Vec<f64>,
+    // virtual memory activity from /proc/vmstat, each entry was the delta since the previous tick ( linux only, empty on other platforms )
+    pub page_in_vec: Vec<f64>,  // in B
+    pub page_out_vec: Vec<f64>, // in B
+    pub swap_in_vec: Vec<f64>,  // in B
+    pub swap_out_vec: Vec<f64>, // in B
+    pub page_fault_vec: Vec<f64>,
+    pub major_page_fault_vec: Vec<f64>,
+    pub oom_kill_vec: Vec<f64>,
 }
 
 pub struct DiskData {
@@ -115,6 +516,90 @@ pub struct NetworkData {
     pub is_updated: bool,
 }
 
+pub struct CgroupData {
+    pub path: String,
+    pub cpu_usage_vec: Vec<f64>, // percentage of the total cpu capacity used by the cgroup since last refresh
+    pub memory_current_vec: Vec<f64>, // in B
+    pub memory_max: Option<f64>, // None when memory.max was set to "max" or the controller was not enabled
+    pub io_read_vec: Vec<f64>,   // in B/s
+    pub io_write_vec: Vec<f64>,  // in B/s
+    pub pids: u64,
+    pub is_updated: bool,
+}
+
+#[derive(Default)]
+pub struct InterruptData {
+    pub cpu_count: usize,
+    pub sources: Vec<InterruptSourceData>, // replaced entirely on every refresh
+    pub context_switch_vec: Vec<f64>,      // per second
+    pub interrupt_vec: Vec<f64>,           // per second
+    pub fork_vec: Vec<f64>,                // per second
+}
+
+#[derive(Clone)]
+pub struct InterruptSourceData {
+    pub id: String,          // the irq number or name ( NMI, LOC, NET_RX for example )
+    pub description: String, // the chip, hardware irq and device name for irqs, "softirq" for softirqs
+    pub cpu_rate: Vec<f64>,  // per second, for each cpu
+    pub total_rate: f64,     // per second
+}
+
+pub struct NumaNodeData {
+    pub id: usize,
+    pub cpu_list: String, // cpu list format as in /sys/devices/system/node/node<id>/cpulist, eg. "0-7,16-23"
+    pub cpus: Vec<usize>, // ids of the cpus belonging to this node, to look up their usage from the CpuData
+    pub memory: MemoryData, // swap and virtual memory activity are not tracked per node, available was estimated from the free and file pages
+    // numastat counters, each entry was the number of pages allocated since the previous tick
+    pub numa_hit_vec: Vec<f64>,     // allocated on this node as intended
+    pub numa_miss_vec: Vec<f64>,    // allocated on this node although another node was preferred
+    pub numa_foreign_vec: Vec<f64>, // intended for this node but allocated on another node
+    pub other_node_vec: Vec<f64>,   // allocated on this node by a process running on another node
+    pub is_updated: bool,
+}
+
+pub struct SessionData {
+    pub user: String,
+    pub tty: String,
+    pub host: String, // remote host the user logged in from, empty for local sessions
+    pub login_time: i64, // unix timestamp in seconds
+    pub idle: Option<u64>, // in seconds, time since the tty was last accessed
+}
+
+// per user usage aggregated from the process list
+pub struct UserUsageData {
+    pub user: String,
+    pub session_count: usize,
+    pub process_count: usize,
+    pub cpu_usage: f32,
+    pub memory: f64,
+}
+
+pub struct PowerZoneData {
+    pub name: String,        // package-0, core-0, uncore-0, dram-0 or psys
+    pub power_vec: Vec<f64>, // in W, the average power draw since last refresh
+    pub is_updated: bool,
+}
+
+pub struct BatteryData {
+    pub name: String,
+    pub status: String, // Charging, Discharging, Full, Not charging or Unknown
+    pub charge_vec: Vec<f64>, // in %
+    pub power_draw_vec: Vec<f64>, // in W, the rate the battery was being charged or discharged
+    pub time_to_empty: Option<u64>, // in seconds, only available when discharging
+    pub time_to_full: Option<u64>, // in seconds, only available when charging
+    pub health: Option<f64>, // in %, the current full capacity compared to the design capacity
+    pub is_updated: bool,
+}
+
+// usage of the cgroup rtop was running in, presented relative to the cgroup limits instead of the host
+pub struct ContainerLimitData {
+    pub memory_max: Option<f64>, // the lowest memory.max along the cgroup ancestors, None when there is no limit
+    pub cpu_limit: Option<f64>, // number of cpus allowed by the cpu.max quota / period, None when there is no quota
+    pub cpuset: Vec<usize>, // the cpus allowed by cpuset.cpus.effective, empty when all the cpus were allowed
+    pub cpus: Vec<CpuData>, // same as SysInfo.cpus but only for the cpus in the cpuset, and the average was relative to the cpu limit
+    pub memory: MemoryData, // same as SysInfo.memory but the total was the memory limit
+}
+
 #[derive(Debug, Clone)]
 pub struct ProcessData {
     pub pid: u32,
@@ -132,9 +617,76 @@ pub struct ProcessData {
     pub total_read_disk_usage: u64,
     pub current_write_disk_usage: u64,
     pub total_write_disk_usage: u64,
+    pub disk_read_vec: Vec<f64>,                           // in B/s
+    pub disk_write_vec: Vec<f64>,                          // in B/s
+    pub cgroup: String, // the cgroup path the process belongs to ( linux only, empty on other platforms )
+    pub container: String, // container id / systemd unit / slice derived from the cgroup path
+    pub oom_score: Option<i32>, // /proc/<pid>/oom_score, the badness score used by the OOM killer to pick its victim ( linux only )
+    pub oom_score_adj: Option<i32>, // /proc/<pid>/oom_score_adj, from -1000 ( never kill ) to 1000 ( kill first ) ( linux only )
+    pub numa_memory: Vec<f64>, // in B, memory mapped on each NUMA node indexed by the node id, only collected for the process shown in the process detail on multi-node systems
+    pub security: Option<ProcessSecurityData>, // None if it was not a linux system or /proc/<pid>/status was not readable
+    pub deleted_files: Vec<String>, // executable and shared libraries that were deleted or replaced since the process started, the process needs a restart to pick up the new ones
+    pub scheduler_counters: Option<CProcessSchedulerData>, // the counters from the latest collection, to calculate the rates on the next update
+    pub scheduler: ProcessSchedulerData,                   // rates since the previous update
+    pub last_updated: Instant,
     pub is_updated: bool,
 }
 
+// context switches, page faults and run queue delay of a process since the previous update
+#[derive(Debug, Clone, Default)]
+pub struct ProcessSchedulerData {
+    pub voluntary_context_switch_rate: f64, // per second, the process gave up the cpu ( waiting for a lock or IO for example )
+    pub involuntary_context_switch_rate: f64, // per second, the process was preempted
+    pub minor_fault_rate: f64,              // per second
+    pub major_fault_rate: f64,              // per second, faults that required reading from disk
+    pub run_delay_rate: f64, // in ms per second, time spent runnable but waiting on a run queue
+}
+
+impl ProcessSchedulerData {
+    pub fn new(
+        previous: &CProcessSchedulerData,
+        current: &CProcessSchedulerData,
+        elapsed_sec: f64,
+    ) -> ProcessSchedulerData {
+        let rate =
+            |previous: u64, current: u64| current.saturating_sub(previous) as f64 / elapsed_sec;
+        ProcessSchedulerData {
+            voluntary_context_switch_rate: rate(
+                previous.voluntary_context_switches,
+                current.voluntary_context_switches,
+            ),
+            involuntary_context_switch_rate: rate(
+                previous.involuntary_context_switches,
+                current.involuntary_context_switches,
+            ),
+            minor_fault_rate: rate(previous.minor_faults, current.minor_faults),
+            major_fault_rate: rate(previous.major_faults, current.major_faults),
+            run_delay_rate: rate(previous.run_delay, current.run_delay) / 1_000_000.0,
+        }
+    }
+
+    pub fn context_switch_rate(&self) -> f64 {
+        self.voluntary_context_switch_rate + self.involuntary_context_switch_rate
+    }
+
+    pub fn page_fault_rate(&self) -> f64 {
+        self.minor_fault_rate + self.major_fault_rate
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ProcessSecurityData {
+    // capability sets from /proc/<pid>/status, each bit was a capability ( see CAPABILITY_NAMES in utils.rs )
+    pub cap_effective: u64,
+    pub cap_permitted: u64,
+    pub cap_bounding: u64,
+    pub no_new_privs: bool,
+    pub seccomp: u8,                          // 0 disabled, 1 strict, 2 filter
+    pub namespaces: Vec<(String, u64)>, // namespace type and inode from /proc/<pid>/ns, empty when not readable ( processes of other users without root )
+    pub outside_init_namespaces: Vec<String>, // namespace types that differ from the init process
+    pub lsm_label: Option<String>, // AppArmor profile or SELinux context from /proc/<pid>/attr/current
+}
+
 pub struct CurrentProcessSignalStateData {
     pub pid: String,
     pub signal: Option<Signal>,
@@ -144,6 +696,53 @@ pub struct CurrentProcessSignalStateData {
     pub no_confirmation: bool,
 }
 
+// this was used to temporary save the data when user trigger the oom_score_adj pop-up
+pub struct CurrentProcessOomScoreAdjStateData {
+    pub pid: String,
+    pub name: String,
+    pub oom_score_adj: i32,
+    pub error: Option<String>, // the reason when writing the new value failed, eg. lowering the value without CAP_SYS_RESOURCE
+}
+
+// this was used to temporary save the data when user trigger the theme selection pop-up
+pub struct CurrentThemeSelectionStateData {
+    pub theme_names: Vec<String>,
+    pub theme_selected_state: ListState,
+    pub original_app_color_info: AppColorInfo, // the colors before the pop-up was opened, to revert back to when user cancel
+    pub warnings: Vec<String>,                 // problems found in the theme being previewed
+}
+
+// the state of the app shown in the compact view, the process list state will be updated as it was drawn
+pub struct CompactViewData<'a> {
+    pub tick: u64,
+    pub cpus: &'a [CpuData],
+    pub memory: &'a MemoryData,
+    pub disk_data: &'a DiskData,
+    pub network_data: &'a NetworkData,
+    pub process_data: &'a HashMap<String, ProcessData>,
+    pub process_current_list: &'a mut Vec<ProcessData>,
+    pub process_selectable_entries: &'a mut usize,
+    pub process_selected_state: &'a mut ListState,
+    pub process_sort_type: &'a ProcessSortType,
+    pub process_sort_is_reversed: bool,
+    pub process_filter: String,
+    pub is_filtering: bool,
+    pub process_quick_filter: &'a ProcessQuickFilter,
+    pub process_pre_filter: &'a ProcessPreFilter,
+    pub process_detail: Option<&'a ProcessData>, // the process shown in the process detail, drawn as a single line
+    pub is_process_selected: bool,
+    pub panels: &'a PanelConfig,
+}
+
+// a new OOM kill seen from the oom_kill counter in /proc/vmstat
+pub struct OomKillEventData {
+    pub detected_at: Instant,
+    pub time: String, // local time the OOM kill was detected, eg. "14:03:27"
+    pub count: u64,   // number of OOM kills since the previous tick
+    pub victim: Option<(u32, String)>, // pid and name of the victim, if it could be determined from the processes that disappeared
+    pub is_resolved: bool, // false while still waiting for the victim to disappear from the process list
+}
+
 impl CpuData {
     pub fn new(id: i8, brand: String, usage: f32) -> CpuData {
         let id = if id == -1 {
@@ -184,6 +783,13 @@ impl MemoryData {
             used_swap_vec: vec![0.0],
             free_memory_vec: vec![0.0],
             cached_memory_vec: vec![0.0],
+            page_in_vec: vec![],
+            page_out_vec: vec![],
+            swap_in_vec: vec![],
+            swap_out_vec: vec![],
+            page_fault_vec: vec![],
+            major_page_fault_vec: vec![],
+            oom_kill_vec: vec![],
         }
     }
 
@@ -202,6 +808,13 @@ impl MemoryData {
             used_swap_vec: vec![used_swap],
             free_memory_vec: vec![free],
             cached_memory_vec: vec![cached],
+            page_in_vec: vec![],
+            page_out_vec: vec![],
+            swap_in_vec: vec![],
+            swap_out_vec: vec![],
+            page_fault_vec: vec![],
+            major_page_fault_vec: vec![],
+            oom_kill_vec: vec![],
         };
     }
 
@@ -237,6 +850,31 @@ impl MemoryData {
             self.cached_memory_vec.remove(0);
         }
     }
+
+    pub fn update_vm_activity(&mut self, vm_activity: &CVmActivityData) {
+        self.page_in_vec.push(vm_activity.page_in);
+        self.page_out_vec.push(vm_activity.page_out);
+        self.swap_in_vec.push(vm_activity.swap_in);
+        self.swap_out_vec.push(vm_activity.swap_out);
+        self.page_fault_vec.push(vm_activity.page_faults);
+        self.major_page_fault_vec
+            .push(vm_activity.major_page_faults);
+        self.oom_kill_vec.push(vm_activity.oom_kills);
+
+        for history in [
+            &mut self.page_in_vec,
+            &mut self.page_out_vec,
+            &mut self.swap_in_vec,
+            &mut self.swap_out_vec,
+            &mut self.page_fault_vec,
+            &mut self.major_page_fault_vec,
+            &mut self.oom_kill_vec,
+        ] {
+            if history.len() > MAXIMUM_DATA_COLLECTION {
+                history.remove(0);
+            }
+        }
+    }
 }
 
 impl DiskData {
@@ -342,77 +980,340 @@ impl NetworkData {
     }
 }
 
-impl ProcessData {
+impl InterruptData {
+    pub fn update(
+        &mut self,
+        cpu_count: usize,
+        sources: Vec<InterruptSourceData>,
+        context_switch_rate: f64,
+        interrupt_rate: f64,
+        fork_rate: f64,
+    ) {
+        self.cpu_count = cpu_count;
+        self.sources = sources;
+        self.context_switch_vec.push(context_switch_rate);
+        self.interrupt_vec.push(interrupt_rate);
+        self.fork_vec.push(fork_rate);
+
+        if self.context_switch_vec.len() > MAXIMUM_DATA_COLLECTION {
+            self.context_switch_vec.remove(0);
+        }
+        if self.interrupt_vec.len() > MAXIMUM_DATA_COLLECTION {
+            self.interrupt_vec.remove(0);
+        }
+        if self.fork_vec.len() > MAXIMUM_DATA_COLLECTION {
+            self.fork_vec.remove(0);
+        }
+    }
+}
+
+impl SessionData {
     pub fn new(
-        pid: u32,
-        name: String,
-        exe_path: Option<String>,
-        cmd: Vec<String>,
         user: String,
-        cpu_usage: f32,
-        thread_count: u32,
-        memory: f64,
+        tty: String,
+        host: String,
+        login_time: i64,
+        idle: Option<u64>,
+    ) -> SessionData {
+        SessionData {
+            user,
+            tty,
+            host,
+            login_time,
+            idle,
+        }
+    }
+}
+
+impl PowerZoneData {
+    pub fn new(name: String, power: f64) -> PowerZoneData {
+        PowerZoneData {
+            name,
+            power_vec: vec![power],
+            is_updated: true,
+        }
+    }
+
+    pub fn update(&mut self, power: f64) {
+        self.power_vec.push(power);
+        if self.power_vec.len() > MAXIMUM_DATA_COLLECTION {
+            self.power_vec.remove(0);
+        }
+        self.is_updated = true;
+    }
+
+    // package domains cover the core, uncore and dram domains within it
+    pub fn is_package(&self) -> bool {
+        self.name.starts_with("package")
+    }
+}
+
+impl NumaNodeData {
+    pub fn new(numa_node: &CNumaNodeData) -> NumaNodeData {
+        NumaNodeData {
+            id: numa_node.id,
+            cpu_list: numa_node.cpu_list.clone(),
+            cpus: numa_node.cpus.clone(),
+            memory: MemoryData::new(
+                numa_node.total_memory,
+                numa_node.available_memory,
+                numa_node.used_memory,
+                0.0,
+                numa_node.free_memory,
+                numa_node.cached_memory,
+            ),
+            numa_hit_vec: vec![numa_node.numa_hit],
+            numa_miss_vec: vec![numa_node.numa_miss],
+            numa_foreign_vec: vec![numa_node.numa_foreign],
+            other_node_vec: vec![numa_node.other_node],
+            is_updated: true,
+        }
+    }
+
+    pub fn update(&mut self, numa_node: &CNumaNodeData) {
+        self.cpu_list = numa_node.cpu_list.clone();
+        self.cpus = numa_node.cpus.clone();
+        self.memory.update(
+            numa_node.total_memory,
+            numa_node.available_memory,
+            numa_node.used_memory,
+            0.0,
+            numa_node.free_memory,
+            numa_node.cached_memory,
+        );
+
+        self.numa_hit_vec.push(numa_node.numa_hit);
+        self.numa_miss_vec.push(numa_node.numa_miss);
+        self.numa_foreign_vec.push(numa_node.numa_foreign);
+        self.other_node_vec.push(numa_node.other_node);
+        for history in [
+            &mut self.numa_hit_vec,
+            &mut self.numa_miss_vec,
+            &mut self.numa_foreign_vec,
+            &mut self.other_node_vec,
+        ] {
+            if history.len() > MAXIMUM_DATA_COLLECTION {
+                history.remove(0);
+            }
+        }
+        self.is_updated = true;
+    }
+}
+
+impl BatteryData {
+    pub fn new(
+        name: String,
         status: String,
-        elapsed: u64,
-        parent: String,
-        current_read_disk_usage: u64,
-        total_read_disk_usage: u64,
-        current_write_disk_usage: u64,
-        total_write_disk_usage: u64,
-    ) -> ProcessData {
-        return ProcessData {
-            pid,
+        charge: f64,
+        power_draw: f64,
+        time_to_empty: Option<u64>,
+        time_to_full: Option<u64>,
+        health: Option<f64>,
+    ) -> BatteryData {
+        BatteryData {
             name,
-            exe_path,
-            cmd,
-            user,
-            cpu_usage: vec![cpu_usage],
-            thread_count,
-            memory: vec![memory],
             status,
-            elapsed,
-            parent,
+            charge_vec: vec![charge],
+            power_draw_vec: vec![power_draw],
+            time_to_empty,
+            time_to_full,
+            health,
             is_updated: true,
-            current_read_disk_usage,
-            total_read_disk_usage,
-            current_write_disk_usage,
-            total_write_disk_usage,
-        };
+        }
     }
 
     pub fn update(
         &mut self,
-        pid: u32,
-        name: String,
-        exe_path: Option<String>,
-        cmd: Vec<String>,
-        user: String,
-        cpu_usage: f32,
-        thread_count: u32,
-        memory: f64,
         status: String,
-        elapsed: u64,
-        parent: String,
-        current_read_disk_usage: u64,
-        total_read_disk_usage: u64,
-        current_write_disk_usage: u64,
-        total_write_disk_usage: u64,
+        charge: f64,
+        power_draw: f64,
+        time_to_empty: Option<u64>,
+        time_to_full: Option<u64>,
+        health: Option<f64>,
     ) {
-        if self.pid == pid {
-            self.name = name;
-            self.exe_path = exe_path;
-            self.cmd = cmd;
-            self.user = user;
-            self.cpu_usage.push(cpu_usage);
-            self.thread_count = thread_count;
-            self.memory.push(memory);
-            self.status = status;
-            self.elapsed = elapsed;
-            self.parent = parent;
-            self.current_read_disk_usage = current_read_disk_usage;
-            self.total_read_disk_usage = total_read_disk_usage;
-            self.current_write_disk_usage = current_write_disk_usage;
-            self.total_write_disk_usage = total_write_disk_usage;
+        self.status = status;
+        self.charge_vec.push(charge);
+        self.power_draw_vec.push(power_draw);
+        self.time_to_empty = time_to_empty;
+        self.time_to_full = time_to_full;
+        self.health = health;
+
+        if self.charge_vec.len() > MAXIMUM_DATA_COLLECTION {
+            self.charge_vec.remove(0);
+        }
+        if self.power_draw_vec.len() > MAXIMUM_DATA_COLLECTION {
+            self.power_draw_vec.remove(0);
+        }
+        self.is_updated = true;
+    }
+}
+
+impl ContainerLimitData {
+    // the number of cpus the cgroup can effectively use, taking both cpu.max and cpuset into account
+    pub fn get_effective_cpus(&self, total_cpus: usize) -> f64 {
+        let mut effective_cpus = if self.cpuset.is_empty() {
+            total_cpus as f64
+        } else {
+            self.cpuset.len() as f64
+        };
+        if let Some(cpu_limit) = self.cpu_limit {
+            effective_cpus = effective_cpus.min(cpu_limit);
+        }
+        effective_cpus
+    }
+}
+
+impl CgroupData {
+    pub fn new(
+        path: String,
+        cpu_usage: f64,
+        memory_current: f64,
+        memory_max: Option<f64>,
+        io_read: f64,
+        io_write: f64,
+        pids: u64,
+    ) -> CgroupData {
+        CgroupData {
+            path,
+            cpu_usage_vec: vec![cpu_usage],
+            memory_current_vec: vec![memory_current],
+            memory_max,
+            io_read_vec: vec![io_read],
+            io_write_vec: vec![io_write],
+            pids,
+            is_updated: true,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        cpu_usage: f64,
+        memory_current: f64,
+        memory_max: Option<f64>,
+        io_read: f64,
+        io_write: f64,
+        pids: u64,
+    ) {
+        self.cpu_usage_vec.push(cpu_usage);
+        self.memory_current_vec.push(memory_current);
+        self.memory_max = memory_max;
+        self.io_read_vec.push(io_read);
+        self.io_write_vec.push(io_write);
+        self.pids = pids;
+
+        if self.cpu_usage_vec.len() > MAXIMUM_DATA_COLLECTION {
+            self.cpu_usage_vec.remove(0);
+        }
+        if self.memory_current_vec.len() > MAXIMUM_DATA_COLLECTION {
+            self.memory_current_vec.remove(0);
+        }
+        if self.io_read_vec.len() > MAXIMUM_DATA_COLLECTION {
+            self.io_read_vec.remove(0);
+        }
+        if self.io_write_vec.len() > MAXIMUM_DATA_COLLECTION {
+            self.io_write_vec.remove(0);
+        }
+        self.is_updated = true;
+    }
+
+    // the name shown in the cgroup tree, which is the last component of the path
+    pub fn get_name(&self) -> String {
+        if self.path == "/" {
+            return "/".to_string();
+        }
+        self.path
+            .rsplit('/')
+            .next()
+            .unwrap_or(self.path.as_str())
+            .to_string()
+    }
+
+    pub fn get_parent_path(&self) -> Option<String> {
+        if self.path == "/" {
+            return None;
+        }
+        match self.path.rfind('/') {
+            Some(0) | None => Some("/".to_string()),
+            Some(index) => Some(self.path[..index].to_string()),
+        }
+    }
+}
+
+impl ProcessData {
+    // the history starts with the values collected for the process
+    pub fn new(process: &CProcessData) -> ProcessData {
+        return ProcessData {
+            pid: process.pid,
+            name: process.name.clone(),
+            exe_path: process.exe_path.clone(),
+            cmd: process.cmd.clone(),
+            user: process.user.clone(),
+            cpu_usage: vec![process.cpu_usage],
+            thread_count: process.thread_count,
+            memory: vec![process.memory],
+            status: process.status.clone(),
+            elapsed: process.elapsed,
+            parent: process.parent.clone(),
+            is_updated: true,
+            current_read_disk_usage: process.current_read_disk_usage,
+            total_read_disk_usage: process.total_read_disk_usage,
+            current_write_disk_usage: process.current_write_disk_usage,
+            total_write_disk_usage: process.total_write_disk_usage,
+            // the rate is unknown until the next update as there was no previous collection to compare against
+            disk_read_vec: vec![0.0],
+            disk_write_vec: vec![0.0],
+            cgroup: process.cgroup.clone(),
+            container: process.container.clone(),
+            oom_score: process.oom_score,
+            oom_score_adj: process.oom_score_adj,
+            numa_memory: process.numa_memory.clone(),
+            security: process.security.clone(),
+            deleted_files: process.deleted_files.clone(),
+            scheduler_counters: process.scheduler.clone(),
+            scheduler: ProcessSchedulerData::default(),
+            last_updated: Instant::now(),
+        };
+    }
+
+    pub fn update(&mut self, process: &CProcessData) {
+        if self.pid == process.pid {
+            self.name = process.name.clone();
+            self.exe_path = process.exe_path.clone();
+            self.cmd = process.cmd.clone();
+            self.user = process.user.clone();
+            self.cpu_usage.push(process.cpu_usage);
+            self.thread_count = process.thread_count;
+            self.memory.push(process.memory);
+            self.status = process.status.clone();
+            self.elapsed = process.elapsed;
+            self.parent = process.parent.clone();
+            self.current_read_disk_usage = process.current_read_disk_usage;
+            self.total_read_disk_usage = process.total_read_disk_usage;
+            self.current_write_disk_usage = process.current_write_disk_usage;
+            self.total_write_disk_usage = process.total_write_disk_usage;
+            self.cgroup = process.cgroup.clone();
+            self.container = process.container.clone();
+            self.oom_score = process.oom_score;
+            self.oom_score_adj = process.oom_score_adj;
+            self.numa_memory = process.numa_memory.clone();
+            self.security = process.security.clone();
+            self.deleted_files = process.deleted_files.clone();
+
+            // the current disk usage was the bytes since the previous collection
+            let elapsed_sec = self.last_updated.elapsed().as_secs_f64().max(0.001);
+            self.disk_read_vec
+                .push(process.current_read_disk_usage as f64 / elapsed_sec);
+            self.disk_write_vec
+                .push(process.current_write_disk_usage as f64 / elapsed_sec);
+
+            self.scheduler = match (&self.scheduler_counters, &process.scheduler) {
+                (Some(previous), Some(current)) => {
+                    ProcessSchedulerData::new(previous, current, elapsed_sec)
+                }
+                _ => ProcessSchedulerData::default(),
+            };
+            self.scheduler_counters = process.scheduler.clone();
+            self.last_updated = Instant::now();
 
             if self.cpu_usage.len() > MAXIMUM_DATA_COLLECTION {
                 self.cpu_usage.remove(0);
@@ -421,6 +1322,14 @@ impl ProcessData {
             if self.memory.len() > MAXIMUM_DATA_COLLECTION {
                 self.memory.remove(0);
             }
+
+            if self.disk_read_vec.len() > MAXIMUM_DATA_COLLECTION {
+                self.disk_read_vec.remove(0);
+            }
+
+            if self.disk_write_vec.len() > MAXIMUM_DATA_COLLECTION {
+                self.disk_write_vec.remove(0);
+            }
             self.is_updated = true;
         }
     }
@@ -433,6 +1342,13 @@ pub struct CSysInfo {
     pub memory: CMemoryData,
     pub disks: Vec<CDiskData>,
     pub networks: Vec<CNetworkData>,
+    pub cgroups: Option<Vec<CCgroupData>>, // None when the cgroup panel was hidden, the cgroup tree was not walked then
+    pub container_limit: Option<CContainerLimitData>,
+    pub batteries: Vec<CBatteryData>,
+    pub power_zones: Vec<CPowerZoneData>,
+    pub sessions: Vec<CSessionData>,
+    pub interrupts: Option<CInterruptData>,
+    pub numa_nodes: Vec<CNumaNodeData>,
 }
 
 pub struct CProcessesInfo {
@@ -452,6 +1368,18 @@ pub struct CMemoryData {
     pub used_swap: f64,
     pub free_memory: f64, // free means memory that is not used at all
     pub cached_memory: f64,
+    pub vm_activity: Option<CVmActivityData>, // None on the first collection ( no previous counters to diff against ) or when /proc/vmstat is not available
+}
+
+// the deltas of the /proc/vmstat counters since the previous collection
+pub struct CVmActivityData {
+    pub page_in: f64,  // pgpgin, in B
+    pub page_out: f64, // pgpgout, in B
+    pub swap_in: f64,  // pswpin, in B
+    pub swap_out: f64, // pswpout, in B
+    pub page_faults: f64,
+    pub major_page_faults: f64,
+    pub oom_kills: f64,
 }
 
 pub struct CDiskData {
@@ -475,6 +1403,72 @@ pub struct CNetworkData {
     pub total_transmitted: f64,
 }
 
+pub struct CCgroupData {
+    pub path: String,
+    pub cpu_usage: f64,
+    pub memory_current: f64,
+    pub memory_max: Option<f64>,
+    pub io_read: f64,
+    pub io_write: f64,
+    pub pids: u64,
+}
+
+pub struct CInterruptData {
+    pub cpu_count: usize,
+    pub sources: Vec<InterruptSourceData>,
+    pub context_switch_rate: f64,
+    pub interrupt_rate: f64,
+    pub fork_rate: f64,
+}
+
+pub struct CSessionData {
+    pub user: String,
+    pub tty: String,
+    pub host: String,
+    pub login_time: i64,
+    pub idle: Option<u64>,
+}
+
+pub struct CPowerZoneData {
+    pub id: String,
+    pub name: String,
+    pub power: f64,
+}
+
+pub struct CNumaNodeData {
+    pub id: usize,
+    pub cpu_list: String,
+    pub cpus: Vec<usize>,
+    pub total_memory: f64,
+    pub available_memory: f64,
+    pub used_memory: f64,
+    pub free_memory: f64,
+    pub cached_memory: f64, // file pages on this node
+    // pages allocated since the previous collection
+    pub numa_hit: f64,
+    pub numa_miss: f64,
+    pub numa_foreign: f64,
+    pub other_node: f64,
+}
+
+pub struct CBatteryData {
+    pub name: String,
+    pub status: String,
+    pub charge: f64,
+    pub power_draw: f64,
+    pub time_to_empty: Option<u64>,
+    pub time_to_full: Option<u64>,
+    pub health: Option<f64>,
+}
+
+pub struct CContainerLimitData {
+    pub memory_max: Option<f64>,
+    pub cpu_limit: Option<f64>,
+    pub cpuset: Vec<usize>,
+    pub cpu_usage: f32, // usage of the cgroup relative to the effective cpus it was allowed to use
+    pub memory: CMemoryData,
+}
+
 pub struct CProcessData {
     pub pid: u32,
     pub name: String,
@@ -491,6 +1485,24 @@ pub struct CProcessData {
     pub total_read_disk_usage: u64,
     pub current_write_disk_usage: u64,
     pub total_write_disk_usage: u64,
+    pub cgroup: String,
+    pub container: String,
+    pub oom_score: Option<i32>,
+    pub oom_score_adj: Option<i32>,
+    pub numa_memory: Vec<f64>,
+    pub security: Option<ProcessSecurityData>,
+    pub deleted_files: Vec<String>,
+    pub scheduler: Option<CProcessSchedulerData>,
+}
+
+// cumulative counters since the process started
+#[derive(Debug, Clone)]
+pub struct CProcessSchedulerData {
+    pub voluntary_context_switches: u64,
+    pub involuntary_context_switches: u64,
+    pub minor_faults: u64,
+    pub major_faults: u64,
+    pub run_delay: u64, // in ns
 }
 
 #[derive(PartialEq)]
@@ -500,10 +1512,25 @@ pub enum SelectedContainer {
     Disk,
     Network,
     Process,
+    Battery,
+    Cgroup,
+    Session,
+    Interrupt,
     Menu,
     None,
 }
 
+impl SelectedContainer {
+    // containers that are not part of the default layouts, they will be shown in full screen when selected
+    // unless they were placed in the current layout ( see App::is_selected_container_standalone )
+    pub fn is_standalone(&self) -> bool {
+        matches!(
+            self,
+            SelectedContainer::Cgroup | SelectedContainer::Session | SelectedContainer::Interrupt
+        )
+    }
+}
+
 #[derive(PartialEq)]
 pub enum AppState {
     View,
@@ -518,9 +1545,12 @@ pub enum AppPopUpType {
     KillConfirmation,
     TerminateConfirmation,
     SignalMenu,
+    OomScoreAdjust,
+    ThemeSelection,
 }
 
-#[derive(PartialEq, Clone)]
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ProcessSortType {
     Thread,
     Memory,
@@ -529,6 +1559,13 @@ pub enum ProcessSortType {
     Name,
     Command,
     User,
+    Container,
+    OomScore,
+    ContextSwitch,
+    PageFault,
+    RunDelay,
+    DiskRead,
+    DiskWrite,
 }
 
 impl ProcessSortType {
@@ -541,6 +1578,13 @@ impl ProcessSortType {
             4 => ProcessSortType::Name,
             5 => ProcessSortType::Command,
             6 => ProcessSortType::User,
+            7 => ProcessSortType::Container,
+            8 => ProcessSortType::OomScore,
+            9 => ProcessSortType::ContextSwitch,
+            10 => ProcessSortType::PageFault,
+            11 => ProcessSortType::RunDelay,
+            12 => ProcessSortType::DiskRead,
+            13 => ProcessSortType::DiskWrite,
             _ => ProcessSortType::Thread,
         }
     }
@@ -554,11 +1598,170 @@ impl ProcessSortType {
             ProcessSortType::Name => "Name".to_string(),
             ProcessSortType::Command => "Command".to_string(),
             ProcessSortType::User => "User".to_string(),
+            ProcessSortType::Container => "Container".to_string(),
+            ProcessSortType::OomScore => "OOM".to_string(),
+            ProcessSortType::ContextSwitch => "Ctx Sw/s".to_string(),
+            ProcessSortType::PageFault => "Faults/s".to_string(),
+            ProcessSortType::RunDelay => "Run Delay".to_string(),
+            ProcessSortType::DiskRead => "Read/s".to_string(),
+            ProcessSortType::DiskWrite => "Write/s".to_string(),
+        }
+    }
+
+    pub fn total_selection_count() -> u8 {
+        14
+    }
+}
+
+// narrow down the process list to those that matter for a hardening review
+#[derive(PartialEq, Clone)]
+pub enum ProcessQuickFilter {
+    All,
+    ElevatedCapabilities,  // any effective capability
+    OutsideInitNamespaces, // in at least one namespace that differs from the init process
+    NeedsRestart,          // running a deleted or replaced executable or shared library
+}
+
+impl ProcessQuickFilter {
+    pub fn get_process_quick_filter_from_int(id: u8) -> ProcessQuickFilter {
+        match id {
+            0 => ProcessQuickFilter::All,
+            1 => ProcessQuickFilter::ElevatedCapabilities,
+            2 => ProcessQuickFilter::OutsideInitNamespaces,
+            3 => ProcessQuickFilter::NeedsRestart,
+            _ => ProcessQuickFilter::All,
+        }
+    }
+
+    pub fn get_filter_string_name(&self) -> String {
+        match self {
+            ProcessQuickFilter::All => "All".to_string(),
+            ProcessQuickFilter::ElevatedCapabilities => "Capabilities".to_string(),
+            ProcessQuickFilter::OutsideInitNamespaces => "Namespaced".to_string(),
+            ProcessQuickFilter::NeedsRestart => "Needs Restart".to_string(),
+        }
+    }
+
+    pub fn total_selection_count() -> u8 {
+        4
+    }
+
+    pub fn is_matching(&self, process: &ProcessData) -> bool {
+        match self {
+            ProcessQuickFilter::All => true,
+            ProcessQuickFilter::ElevatedCapabilities => process
+                .security
+                .as_ref()
+                .is_some_and(|security| security.cap_effective != 0),
+            ProcessQuickFilter::OutsideInitNamespaces => process
+                .security
+                .as_ref()
+                .is_some_and(|security| !security.outside_init_namespaces.is_empty()),
+            ProcessQuickFilter::NeedsRestart => !process.deleted_files.is_empty(),
+        }
+    }
+}
+
+// the processes to be shown set from the command line ( --pid and --user ), on top of the filter and quick filter
+#[derive(Default)]
+pub struct ProcessPreFilter {
+    pub pids: Vec<u32>,
+    pub users: Vec<String>,
+}
+
+impl ProcessPreFilter {
+    pub fn is_empty(&self) -> bool {
+        self.pids.is_empty() && self.users.is_empty()
+    }
+
+    pub fn is_matching(&self, process: &ProcessData) -> bool {
+        (self.pids.is_empty() || self.pids.contains(&process.pid))
+            && (self.users.is_empty() || self.users.contains(&process.user))
+    }
+
+    // eg. "pid 1234, user root"
+    pub fn get_pre_filter_string_name(&self) -> String {
+        let mut names = vec![];
+        if !self.pids.is_empty() {
+            names.push(format!(
+                "pid {}",
+                self.pids
+                    .iter()
+                    .map(|pid| pid.to_string())
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ));
+        }
+        if !self.users.is_empty() {
+            names.push(format!("user {}", self.users.join(",")));
+        }
+        names.join(", ")
+    }
+}
+
+#[derive(PartialEq, Clone)]
+pub enum CgroupSortType {
+    Name,
+    Cpu,
+    Memory,
+    Io,
+    Pids,
+}
+
+impl CgroupSortType {
+    pub fn get_cgroup_sort_type_from_int(id: u8) -> CgroupSortType {
+        match id {
+            0 => CgroupSortType::Name,
+            1 => CgroupSortType::Cpu,
+            2 => CgroupSortType::Memory,
+            3 => CgroupSortType::Io,
+            4 => CgroupSortType::Pids,
+            _ => CgroupSortType::Name,
+        }
+    }
+
+    pub fn get_sort_string_name(&self) -> String {
+        match self {
+            CgroupSortType::Name => "Name".to_string(),
+            CgroupSortType::Cpu => "CPU".to_string(),
+            CgroupSortType::Memory => "Memory".to_string(),
+            CgroupSortType::Io => "IO".to_string(),
+            CgroupSortType::Pids => "Pids".to_string(),
         }
     }
 
     pub fn total_selection_count() -> u8 {
-        7
+        5
+    }
+}
+
+// sorting of the interrupt sources, the sort can also be by the rate of each individual cpu
+#[derive(PartialEq, Clone)]
+pub enum InterruptSortType {
+    Total,
+    Name,
+    Cpu(usize),
+}
+
+impl InterruptSortType {
+    pub fn get_interrupt_sort_type_from_int(id: usize) -> InterruptSortType {
+        match id {
+            0 => InterruptSortType::Total,
+            1 => InterruptSortType::Name,
+            _ => InterruptSortType::Cpu(id - 2),
+        }
+    }
+
+    pub fn get_sort_string_name(&self) -> String {
+        match self {
+            InterruptSortType::Total => "Total".to_string(),
+            InterruptSortType::Name => "Name".to_string(),
+            InterruptSortType::Cpu(cpu) => format!("CPU{}", cpu),
+        }
+    }
+
+    pub fn total_selection_count(cpu_count: usize) -> usize {
+        2 + cpu_count
     }
 }
 
@@ -568,6 +1771,8 @@ impl AppPopUpType {
             AppPopUpType::KillConfirmation => " KILL ".to_string(),
             AppPopUpType::TerminateConfirmation => " TERMINATION ".to_string(),
             AppPopUpType::SignalMenu => " SIGNAL ".to_string(),
+            AppPopUpType::OomScoreAdjust => " OOM SCORE ADJ ".to_string(),
+            AppPopUpType::ThemeSelection => " THEME ".to_string(),
             _ => "".to_string(),
         }
     }
@@ -3,25 +3,79 @@ use std::{
     collections::HashMap,
     fs::{create_dir_all, File},
     path::PathBuf,
+    sync::OnceLock,
     thread,
+    time::{Duration, Instant},
 };
 
 use ratatui::{
-    layout::{Constraint, Layout, Rect},
-    style::{Style, Stylize},
-    symbols::border,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style, Stylize},
+    symbols::{border, Marker},
     text::{Line, Span},
-    widgets::Block,
+    widgets::{Axis, Block, Chart, Clear, Dataset, GraphType, List, ListItem, Paragraph, Wrap},
     Frame,
 };
 use sysinfo::{Pid, Signal, System};
 
+use crate::config::MAX_TICK;
 use crate::types::{
-    AppColorInfo, AppPopUpType, CProcessesInfo, CSysInfo, CpuData, CurrentProcessSignalStateData,
-    DiskData, MemoryData, NetworkData, ProcessData, ProcessSortType, ProcessesInfo, SignalExt,
-    SysInfo,
+    AppColorInfo, AppPopUpType, BatteryData, ByteUnit, CProcessesInfo, CSysInfo, CgroupData,
+    CgroupSortType, ContainerLimitData, CpuData, CurrentProcessOomScoreAdjStateData,
+    CurrentProcessSignalStateData, CurrentThemeSelectionStateData, DiskData, ExitedProcessData,
+    InterruptSortType, InterruptSourceData, LayoutConfig, LayoutNode, LayoutPanel, MemoryData,
+    NetworkData, NumaNodeData, OomKillEventData, PanelConfig, PowerZoneData, ProcessData,
+    ProcessPreFilter, ProcessQuickFilter, ProcessSortType, ProcessesInfo, SessionData, SignalExt,
+    SysInfo, UserUsageData,
 };
 
+static BYTE_UNIT: OnceLock<ByteUnit> = OnceLock::new();
+
+// capability names by their bit in the capability sets, without the "cap_" prefix
+const CAPABILITY_NAMES: [&str; 41] = [
+    "chown",
+    "dac_override",
+    "dac_read_search",
+    "fowner",
+    "fsetid",
+    "kill",
+    "setgid",
+    "setuid",
+    "setpcap",
+    "linux_immutable",
+    "net_bind_service",
+    "net_broadcast",
+    "net_admin",
+    "net_raw",
+    "ipc_lock",
+    "ipc_owner",
+    "sys_module",
+    "sys_rawio",
+    "sys_chroot",
+    "sys_ptrace",
+    "sys_pacct",
+    "sys_admin",
+    "sys_boot",
+    "sys_nice",
+    "sys_resource",
+    "sys_time",
+    "sys_tty_config",
+    "mknod",
+    "lease",
+    "audit_write",
+    "audit_control",
+    "setfcap",
+    "mac_override",
+    "mac_admin",
+    "syslog",
+    "wake_alarm",
+    "block_suspend",
+    "audit_read",
+    "perfmon",
+    "bpf",
+    "checkpoint_restore",
+];
+
 pub fn get_user_directory() -> PathBuf {
     let home_dir = if cfg!(unix) {
         std::env::var("HOME").unwrap()
@@ -80,6 +134,9 @@ pub fn process_sys_info(current_sys_info: &mut SysInfo, collected_sys_info: CSys
             collected_sys_info.memory.cached_memory,
         );
     }
+    if let Some(vm_activity) = &collected_sys_info.memory.vm_activity {
+        current_sys_info.memory.update_vm_activity(vm_activity);
+    }
 
     // -------------------------------------------
     //
@@ -234,6 +291,257 @@ pub fn process_sys_info(current_sys_info: &mut SysInfo, collected_sys_info: CSys
         }
     }
 
+    // -------------------------------------------
+    //
+    //           CGROUPS INFO UPDATE
+    //
+    // -------------------------------------------
+    // the cgroup tree was not walked while the cgroup panel was hidden, the history would have a gap in it by the time it was shown again
+    match &collected_sys_info.cgroups {
+        Some(collected_cgroups) => {
+            let cgroups = current_sys_info.cgroups.get_or_insert_with(HashMap::new);
+            for cgroup in cgroups.values_mut() {
+                cgroup.is_updated = false;
+            }
+
+            for cgroup in collected_cgroups.iter() {
+                match cgroups.get_mut(&cgroup.path) {
+                    Some(e_c) => {
+                        e_c.update(
+                            cgroup.cpu_usage,
+                            cgroup.memory_current,
+                            cgroup.memory_max,
+                            cgroup.io_read,
+                            cgroup.io_write,
+                            cgroup.pids,
+                        );
+                    }
+                    None => {
+                        let new_cgroup = CgroupData::new(
+                            cgroup.path.clone(),
+                            cgroup.cpu_usage,
+                            cgroup.memory_current,
+                            cgroup.memory_max,
+                            cgroup.io_read,
+                            cgroup.io_write,
+                            cgroup.pids,
+                        );
+                        cgroups.insert(cgroup.path.clone(), new_cgroup);
+                    }
+                }
+            }
+
+            // remove the cgroups that no longer exist, eg. a stopped container scope
+            cgroups.retain(|_, cgroup| cgroup.is_updated);
+        }
+        None => current_sys_info.cgroups = None,
+    }
+
+    // -------------------------------------------
+    //
+    //            INTERRUPTS INFO UPDATE
+    //
+    // -------------------------------------------
+    if let Some(interrupts) = &collected_sys_info.interrupts {
+        current_sys_info.interrupts.update(
+            interrupts.cpu_count,
+            interrupts.sources.clone(),
+            interrupts.context_switch_rate,
+            interrupts.interrupt_rate,
+            interrupts.fork_rate,
+        );
+    }
+
+    // -------------------------------------------
+    //
+    //             SESSIONS INFO UPDATE
+    //
+    // -------------------------------------------
+    current_sys_info.sessions = collected_sys_info
+        .sessions
+        .iter()
+        .map(|session| {
+            SessionData::new(
+                session.user.clone(),
+                session.tty.clone(),
+                session.host.clone(),
+                session.login_time,
+                session.idle,
+            )
+        })
+        .collect();
+
+    // -------------------------------------------
+    //
+    //           POWER ZONES INFO UPDATE
+    //
+    // -------------------------------------------
+    for power_zone in current_sys_info.power_zones.values_mut() {
+        power_zone.is_updated = false;
+    }
+
+    for power_zone in collected_sys_info.power_zones.iter() {
+        match current_sys_info.power_zones.get_mut(&power_zone.id) {
+            Some(e_p) => {
+                e_p.update(power_zone.power);
+            }
+            None => {
+                let new_power_zone = PowerZoneData::new(power_zone.name.clone(), power_zone.power);
+                current_sys_info
+                    .power_zones
+                    .insert(power_zone.id.clone(), new_power_zone);
+            }
+        }
+    }
+
+    current_sys_info
+        .power_zones
+        .retain(|_, power_zone| power_zone.is_updated);
+
+    // -------------------------------------------
+    //
+    //           NUMA NODES INFO UPDATE
+    //
+    // -------------------------------------------
+    for numa_node in current_sys_info.numa_nodes.values_mut() {
+        numa_node.is_updated = false;
+    }
+
+    for numa_node in collected_sys_info.numa_nodes.iter() {
+        match current_sys_info.numa_nodes.get_mut(&numa_node.id) {
+            Some(e_n) => {
+                e_n.update(numa_node);
+            }
+            None => {
+                current_sys_info
+                    .numa_nodes
+                    .insert(numa_node.id, NumaNodeData::new(numa_node));
+            }
+        }
+    }
+
+    current_sys_info
+        .numa_nodes
+        .retain(|_, numa_node| numa_node.is_updated);
+
+    // -------------------------------------------
+    //
+    //            BATTERY INFO UPDATE
+    //
+    // -------------------------------------------
+    for battery in current_sys_info.batteries.values_mut() {
+        battery.is_updated = false;
+    }
+
+    for battery in collected_sys_info.batteries.iter() {
+        match current_sys_info.batteries.get_mut(&battery.name) {
+            Some(e_b) => {
+                e_b.update(
+                    battery.status.clone(),
+                    battery.charge,
+                    battery.power_draw,
+                    battery.time_to_empty,
+                    battery.time_to_full,
+                    battery.health,
+                );
+            }
+            None => {
+                let new_battery = BatteryData::new(
+                    battery.name.clone(),
+                    battery.status.clone(),
+                    battery.charge,
+                    battery.power_draw,
+                    battery.time_to_empty,
+                    battery.time_to_full,
+                    battery.health,
+                );
+                current_sys_info
+                    .batteries
+                    .insert(battery.name.clone(), new_battery);
+            }
+        }
+    }
+
+    // remove the batteries that were removed
+    current_sys_info
+        .batteries
+        .retain(|_, battery| battery.is_updated);
+
+    // -------------------------------------------
+    //
+    //         CONTAINER LIMIT INFO UPDATE
+    //
+    // -------------------------------------------
+    match &collected_sys_info.container_limit {
+        Some(limit) => {
+            // the cpu average was replaced by the usage relative to the cpu limit and
+            // only the cpus within the cpuset will be kept
+            let limited_cpus: Vec<(i8, f32)> = collected_sys_info
+                .cpus
+                .iter()
+                .filter(|cpu| {
+                    cpu.id == -1
+                        || limit.cpuset.is_empty()
+                        || limit.cpuset.contains(&(cpu.id as usize))
+                })
+                .map(|cpu| {
+                    if cpu.id == -1 {
+                        (cpu.id, limit.cpu_usage)
+                    } else {
+                        (cpu.id, cpu.usage)
+                    }
+                })
+                .collect();
+
+            match current_sys_info.container_limit.as_mut() {
+                Some(e_l) if e_l.cpus.len() == limited_cpus.len() => {
+                    for (cpu, (id, usage)) in e_l.cpus.iter_mut().zip(limited_cpus.iter()) {
+                        cpu.update(*id, *usage);
+                    }
+                    e_l.memory.update(
+                        limit.memory.total_memory,
+                        limit.memory.available_memory,
+                        limit.memory.used_memory,
+                        limit.memory.used_swap,
+                        limit.memory.free_memory,
+                        limit.memory.cached_memory,
+                    );
+                    e_l.memory_max = limit.memory_max;
+                    e_l.cpu_limit = limit.cpu_limit;
+                    e_l.cpuset = limit.cpuset.clone();
+                }
+                // first collection or the cpuset has changed, start over again
+                _ => {
+                    let brand = collected_sys_info
+                        .cpus
+                        .first()
+                        .map(|cpu| cpu.brand.clone())
+                        .unwrap_or_default();
+                    current_sys_info.container_limit = Some(ContainerLimitData {
+                        memory_max: limit.memory_max,
+                        cpu_limit: limit.cpu_limit,
+                        cpuset: limit.cpuset.clone(),
+                        cpus: limited_cpus
+                            .iter()
+                            .map(|(id, usage)| CpuData::new(*id, brand.clone(), *usage))
+                            .collect(),
+                        memory: MemoryData::new(
+                            limit.memory.total_memory,
+                            limit.memory.available_memory,
+                            limit.memory.used_memory,
+                            limit.memory.used_swap,
+                            limit.memory.free_memory,
+                            limit.memory.cached_memory,
+                        ),
+                    });
+                }
+            }
+        }
+        None => {
+            current_sys_info.container_limit = None;
+        }
+    }
+
     // drop the collected system info that we got from a seperated thread
     drop(collected_sys_info);
 }
@@ -245,23 +553,7 @@ pub fn process_processes_info(
 ) {
     if current_process_info.processes.len() == 0 {
         for process in collected_process_info.processes.iter() {
-            let process_data = ProcessData::new(
-                process.pid,
-                process.name.clone(),
-                process.exe_path.clone(),
-                process.cmd.clone(),
-                process.user.clone(),
-                process.cpu_usage,
-                process.thread_count,
-                process.memory,
-                process.status.clone(),
-                process.elapsed,
-                process.parent.clone(),
-                process.current_read_disk_usage,
-                process.total_read_disk_usage,
-                process.current_write_disk_usage,
-                process.total_write_disk_usage,
-            );
+            let process_data = ProcessData::new(process);
             let pid_string = format!("{}", process.pid);
             current_process_info
                 .processes
@@ -285,23 +577,7 @@ pub fn process_processes_info(
                 .get_mut(&process.pid.to_string());
             match current_process {
                 Some(p) => {
-                    p.update(
-                        process.pid,
-                        process.name.clone(),
-                        process.exe_path.clone(),
-                        process.cmd.clone(),
-                        process.user.clone(),
-                        process.cpu_usage,
-                        process.thread_count,
-                        process.memory,
-                        process.status.clone(),
-                        process.elapsed,
-                        process.parent.clone(),
-                        process.current_read_disk_usage,
-                        process.total_read_disk_usage,
-                        process.current_write_disk_usage,
-                        process.total_write_disk_usage,
-                    );
+                    p.update(process);
 
                     // if there process detail info showing, update the process detail info
                     if let Some(hashmap) = process_detail_info.as_mut() {
@@ -312,23 +588,7 @@ pub fn process_processes_info(
                     }
                 }
                 None => {
-                    let p = ProcessData::new(
-                        process.pid,
-                        process.name.clone(),
-                        process.exe_path.clone(),
-                        process.cmd.clone(),
-                        process.user.clone(),
-                        process.cpu_usage,
-                        process.thread_count,
-                        process.memory,
-                        process.status.clone(),
-                        process.elapsed,
-                        process.parent.clone(),
-                        process.current_read_disk_usage,
-                        process.total_read_disk_usage,
-                        process.current_write_disk_usage,
-                        process.total_write_disk_usage,
-                    );
+                    let p = ProcessData::new(process);
                     let pid_string = format!("{}", process.pid);
                     current_process_info.processes.insert(pid_string, p);
                 }
@@ -342,9 +602,25 @@ pub fn process_processes_info(
             .map(|(key, _)| key.clone())
             .collect();
 
+        // keep the processes that disappeared for a while, so that the victim of an OOM kill can be named
+        let now = Instant::now();
         for key in keys_to_remove {
-            current_process_info.processes.remove(&key);
+            if let Some(process) = current_process_info.processes.remove(&key) {
+                current_process_info
+                    .recently_exited
+                    .push(ExitedProcessData {
+                        exited_at: now,
+                        pid: process.pid,
+                        name: process.name,
+                        oom_score: process.oom_score,
+                    });
+            }
         }
+        current_process_info
+            .recently_exited
+            .retain(|exited_process| {
+                now.duration_since(exited_process.exited_at) <= get_recently_exited_retention()
+            });
 
         // if there is process detail info showing, and the field is_updated is not marked as true,
         // it was possible due to process being killed/terminated
@@ -411,23 +687,24 @@ pub fn round_to_2_decimal(value: f32) -> f32 {
     (value * 100.0).round() / 100.0
 }
 
+// this need to be called before the UI was drawn, subsequent calls will be ignored
+pub fn set_byte_unit(byte_unit: ByteUnit) {
+    let _ = BYTE_UNIT.set(byte_unit);
+}
+
 pub fn process_to_kib_mib_gib(value: f64) -> String {
+    let (base, units) = match BYTE_UNIT.get_or_init(|| ByteUnit::Binary) {
+        ByteUnit::Binary => (1024.0, ["KiB", "MiB", "GiB"]),
+        ByteUnit::Decimal => (1000.0, ["kB", "MB", "GB"]),
+    };
     let mut value = value;
     let mut unit = "B";
 
-    if value >= 1024.0 {
-        value /= 1024.0;
-        unit = "KiB";
-    }
-
-    if value >= 1024.0 {
-        value /= 1024.0;
-        unit = "MiB";
-    }
-
-    if value >= 1024.0 {
-        value /= 1024.0;
-        unit = "GiB";
+    for next_unit in units {
+        if value >= base {
+            value /= base;
+            unit = next_unit;
+        }
     }
 
     return format!("{:.2} {}", ((value * 1000.0).round() / 1000.0), unit);
@@ -445,17 +722,34 @@ pub fn format_seconds(value: u64) -> String {
     }
 }
 
+// pad the value with spaces to fill up the column width, or truncate it with some spacing left for the next column
+pub fn pad_or_truncate(value: &str, width: usize) -> String {
+    if value.chars().count() < width {
+        format!("{:width$}", value, width = width)
+    } else {
+        let mut truncated = value
+            .chars()
+            .take(width.saturating_sub(2))
+            .collect::<String>();
+        truncated.push_str(&" ".repeat(width.min(2)));
+        truncated
+    }
+}
+
 // function to sort and filter the process list based on user selected sort type, sorting order and filtering input
 pub fn sort_process(
     sort_type: ProcessSortType,
     is_reversed: bool,
     filter: String,
+    quick_filter: &ProcessQuickFilter,
+    pre_filter: &ProcessPreFilter,
     process_data: &HashMap<String, ProcessData>,
 ) -> Vec<ProcessData> {
     // we first map the hashmap into a vec for easy processing
     let mut processes: Vec<ProcessData> = process_data
         .iter()
         .map(|(_, value)| value)
+        .filter(|value| quick_filter.is_matching(value) && pre_filter.is_matching(value))
         .cloned()
         .collect();
 
@@ -469,6 +763,10 @@ pub fn sort_process(
                     .to_lowercase()
                     .contains(&filter.to_lowercase())
                 || process.user.to_lowercase().contains(&filter.to_lowercase())
+                || process
+                    .container
+                    .to_lowercase()
+                    .contains(&filter.to_lowercase())
         });
     }
 
@@ -554,10 +852,263 @@ pub fn sort_process(
                 ordering
             }
         })
+    } else if sort_type == ProcessSortType::OomScore {
+        processes.sort_by(|a, b| {
+            let ordering = a.oom_score.cmp(&b.oom_score);
+            if is_reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+    } else if sort_type == ProcessSortType::ContextSwitch {
+        processes.sort_by(|a, b| {
+            let ordering = a
+                .scheduler
+                .context_switch_rate()
+                .partial_cmp(&b.scheduler.context_switch_rate())
+                .unwrap_or(Ordering::Equal);
+            if is_reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+    } else if sort_type == ProcessSortType::PageFault {
+        processes.sort_by(|a, b| {
+            let ordering = a
+                .scheduler
+                .page_fault_rate()
+                .partial_cmp(&b.scheduler.page_fault_rate())
+                .unwrap_or(Ordering::Equal);
+            if is_reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+    } else if sort_type == ProcessSortType::RunDelay {
+        processes.sort_by(|a, b| {
+            let ordering = a
+                .scheduler
+                .run_delay_rate
+                .partial_cmp(&b.scheduler.run_delay_rate)
+                .unwrap_or(Ordering::Equal);
+            if is_reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+    } else if sort_type == ProcessSortType::DiskRead {
+        processes.sort_by(|a, b| {
+            let ordering = a
+                .disk_read_vec
+                .last()
+                .unwrap_or(&0.0)
+                .partial_cmp(b.disk_read_vec.last().unwrap_or(&0.0))
+                .unwrap_or(Ordering::Equal);
+            if is_reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+    } else if sort_type == ProcessSortType::DiskWrite {
+        processes.sort_by(|a, b| {
+            let ordering = a
+                .disk_write_vec
+                .last()
+                .unwrap_or(&0.0)
+                .partial_cmp(b.disk_write_vec.last().unwrap_or(&0.0))
+                .unwrap_or(Ordering::Equal);
+            if is_reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+    } else if sort_type == ProcessSortType::Container {
+        // group the processes by their container/unit, processes within the same group are ordered by pid
+        processes.sort_by(|a, b| {
+            let ordering = a
+                .container
+                .to_lowercase()
+                .cmp(&b.container.to_lowercase())
+                .then(a.pid.cmp(&b.pid));
+            if is_reversed {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
     }
     return processes;
 }
 
+// function to flatten the cgroup hierarchy into the order it will be shown as a tree,
+// each cgroup will be followed by its children which are sorted based on user selected sort type
+pub fn sort_cgroup_tree(
+    sort_type: CgroupSortType,
+    cgroups: &HashMap<String, CgroupData>,
+) -> Vec<(String, usize)> {
+    let mut children: HashMap<String, Vec<&CgroupData>> = HashMap::new();
+    let mut roots: Vec<&CgroupData> = vec![];
+    for cgroup in cgroups.values() {
+        match cgroup.get_parent_path() {
+            Some(parent) if cgroups.contains_key(&parent) => {
+                children.entry(parent).or_default().push(cgroup);
+            }
+            _ => roots.push(cgroup),
+        }
+    }
+
+    let sort_siblings = |siblings: &mut Vec<&CgroupData>| {
+        siblings.sort_by(|a, b| {
+            let latest = |history: &Vec<f64>| history.last().copied().unwrap_or(0.0);
+            match sort_type {
+                CgroupSortType::Name => a
+                    .get_name()
+                    .to_lowercase()
+                    .cmp(&b.get_name().to_lowercase()),
+                CgroupSortType::Cpu => latest(&b.cpu_usage_vec)
+                    .partial_cmp(&latest(&a.cpu_usage_vec))
+                    .unwrap_or(Ordering::Equal),
+                CgroupSortType::Memory => latest(&b.memory_current_vec)
+                    .partial_cmp(&latest(&a.memory_current_vec))
+                    .unwrap_or(Ordering::Equal),
+                CgroupSortType::Io => (latest(&b.io_read_vec) + latest(&b.io_write_vec))
+                    .partial_cmp(&(latest(&a.io_read_vec) + latest(&a.io_write_vec)))
+                    .unwrap_or(Ordering::Equal),
+                CgroupSortType::Pids => b.pids.cmp(&a.pids),
+            }
+        })
+    };
+
+    // depth first walk, the stack is filled in reverse so the first sibling will be visited first
+    sort_siblings(&mut roots);
+    let mut tree = vec![];
+    let mut stack: Vec<(&CgroupData, usize)> = roots.into_iter().rev().map(|c| (c, 0)).collect();
+    while let Some((cgroup, depth)) = stack.pop() {
+        tree.push((cgroup.path.clone(), depth));
+        if let Some(cgroup_children) = children.get_mut(&cgroup.path) {
+            sort_siblings(cgroup_children);
+            for child in cgroup_children.iter().rev() {
+                stack.push((child, depth + 1));
+            }
+        }
+    }
+    tree
+}
+
+// sort the interrupt sources based on user selected sort type, Name will be in ascending order while the rates will be in descending order
+pub fn sort_interrupt_sources(
+    sort_type: &InterruptSortType,
+    sources: &[InterruptSourceData],
+) -> Vec<InterruptSourceData> {
+    let mut sources = sources.to_vec();
+    match sort_type {
+        InterruptSortType::Name => sources.sort_by(|a, b| a.id.cmp(&b.id)),
+        InterruptSortType::Total => sources.sort_by(|a, b| {
+            b.total_rate
+                .partial_cmp(&a.total_rate)
+                .unwrap_or(Ordering::Equal)
+        }),
+        InterruptSortType::Cpu(cpu) => sources.sort_by(|a, b| {
+            let a_rate = a.cpu_rate.get(*cpu).unwrap_or(&0.0);
+            let b_rate = b.cpu_rate.get(*cpu).unwrap_or(&0.0);
+            b_rate.partial_cmp(a_rate).unwrap_or(Ordering::Equal)
+        }),
+    }
+    sources
+}
+
+// aggregate the cpu, memory and process count of each user from the process list
+// users with a logged-in session but without any process will still be listed
+pub fn aggregate_user_usage(
+    processes: &HashMap<String, ProcessData>,
+    sessions: &[SessionData],
+) -> Vec<UserUsageData> {
+    let mut user_usage: HashMap<String, UserUsageData> = HashMap::new();
+    for session in sessions {
+        user_usage
+            .entry(session.user.clone())
+            .or_insert(UserUsageData {
+                user: session.user.clone(),
+                session_count: 0,
+                process_count: 0,
+                cpu_usage: 0.0,
+                memory: 0.0,
+            })
+            .session_count += 1;
+    }
+
+    for process in processes.values() {
+        let usage = user_usage
+            .entry(process.user.clone())
+            .or_insert(UserUsageData {
+                user: process.user.clone(),
+                session_count: 0,
+                process_count: 0,
+                cpu_usage: 0.0,
+                memory: 0.0,
+            });
+        usage.process_count += 1;
+        usage.cpu_usage += process.cpu_usage.last().unwrap_or(&0.0);
+        usage.memory += process.memory.last().unwrap_or(&0.0);
+    }
+
+    let mut user_usage: Vec<UserUsageData> = user_usage.into_values().collect();
+    user_usage.sort_by(|a, b| {
+        b.cpu_usage
+            .partial_cmp(&a.cpu_usage)
+            .unwrap_or(Ordering::Equal)
+            .then(b.memory.partial_cmp(&a.memory).unwrap_or(Ordering::Equal))
+    });
+    user_usage
+}
+
+// render the history as a bar graph filling up the area, the most recent point will be at the right most
+// each point is scaled against max_value into the 0-100 range of the graph
+pub fn render_history_graph(
+    history: &[f64],
+    max_value: f64,
+    graph_show_range: usize,
+    color: Color,
+    app_color_info: &AppColorInfo,
+    area: Rect,
+    frame: &mut Frame,
+) {
+    let num_points_to_display = graph_show_range.min(history.len());
+    let start_idx = history.len().saturating_sub(num_points_to_display);
+    let data_points: Vec<(f64, f64)> = history[start_idx..]
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| {
+            let x = graph_show_range as f64 - (num_points_to_display - i) as f64;
+            let y = if value > 0.0 && max_value > 0.0 {
+                (value.min(max_value) / max_value) * 100.0
+            } else {
+                0.0
+            };
+            (x, y)
+        })
+        .collect();
+
+    let dataset = Dataset::default()
+        .data(&data_points)
+        .graph_type(GraphType::Bar)
+        .marker(Marker::Braille)
+        .style(Style::default().fg(color));
+
+    let chart = Chart::new(vec![dataset])
+        .x_axis(Axis::default().bounds([0.0, graph_show_range as f64]))
+        .y_axis(Axis::default().bounds([0.0, 100.0]))
+        .bg(app_color_info.background_color);
+
+    frame.render_widget(chart, area);
+}
+
 pub fn render_pop_up_menu(
     area: Rect,
     frame: &mut Frame,
@@ -693,7 +1244,11 @@ pub fn render_pop_up_menu(
             .border_set(border::ROUNDED);
 
         if current_process_signal_state_data.yes_confirmation {
-            yes_button_block = yes_button_block.border_style(app_color_info.key_text_color);
+            yes_button_block = yes_button_block.border_style(
+                Style::default()
+                    .fg(app_color_info.key_text_color)
+                    .add_modifier(app_color_info.selected_modifier),
+            );
         }
 
         let yes_button_line = Line::from(Span::styled(
@@ -731,7 +1286,11 @@ pub fn render_pop_up_menu(
             .border_set(border::ROUNDED);
 
         if current_process_signal_state_data.no_confirmation {
-            no_button_block = no_button_block.border_style(app_color_info.key_text_color);
+            no_button_block = no_button_block.border_style(
+                Style::default()
+                    .fg(app_color_info.key_text_color)
+                    .add_modifier(app_color_info.selected_modifier),
+            );
         }
 
         let no_button_line = Line::from(Span::styled(
@@ -1165,6 +1724,10 @@ pub fn render_pop_up_menu(
 
 pub fn send_signal(pid: usize, signal: Signal) {
     thread::spawn(move || {
+        // the process could be from an alternate procfs, and the pid taken by another process in here
+        if !crate::get_sys_info::is_local_process(pid as u32) {
+            return;
+        }
         let s = System::new_all();
         if let Some(process) = s.process(Pid::from(pid)) {
             process.kill_with(signal);
@@ -1172,6 +1735,490 @@ pub fn send_signal(pid: usize, signal: Signal) {
     });
 }
 
+// decode a capability set into the capability names, eg. 0x3000 -> "net_admin,net_raw"
+// a set holding most of the known capabilities will be shown by the missing ones instead, eg. "all but sys_module"
+pub fn format_capabilities(capabilities: u64) -> String {
+    let all_capabilities = (1u64 << CAPABILITY_NAMES.len()) - 1;
+    let capability_names = |capabilities: u64| {
+        (0..64)
+            .filter(|bit| capabilities & (1u64 << bit) != 0)
+            .map(|bit| {
+                CAPABILITY_NAMES
+                    .get(bit)
+                    .map(|name| name.to_string())
+                    .unwrap_or(format!("cap_{}", bit))
+            })
+            .collect::<Vec<String>>()
+            .join(",")
+    };
+
+    if capabilities == 0 {
+        "none".to_string()
+    } else if capabilities & all_capabilities == all_capabilities {
+        "all".to_string()
+    } else if (capabilities & all_capabilities).count_ones() as usize > CAPABILITY_NAMES.len() / 2 {
+        format!(
+            "all but {}",
+            capability_names(!capabilities & all_capabilities)
+        )
+    } else {
+        capability_names(capabilities)
+    }
+}
+
+// write the new oom_score_adj of a process, lowering the value requires CAP_SYS_RESOURCE
+pub fn set_oom_score_adj(pid: &str, oom_score_adj: i32) -> Result<(), String> {
+    #[cfg(target_os = "linux")]
+    let result = std::fs::write(
+        crate::get_sys_info::get_proc_path(&format!("{}/oom_score_adj", pid)),
+        oom_score_adj.to_string(),
+    )
+    .map_err(|e| match e.kind() {
+        std::io::ErrorKind::PermissionDenied => {
+            "Permission denied, lowering the value requires CAP_SYS_RESOURCE".to_string()
+        }
+        _ => e.to_string(),
+    });
+
+    #[cfg(not(target_os = "linux"))]
+    let result = {
+        let _ = (pid, oom_score_adj);
+        Err("oom_score_adj is only available on linux".to_string())
+    };
+
+    result
+}
+
+// the process list and the vmstat counters were collected separately, so the processes that disappeared up to a tick
+// before and two ticks after the OOM kill was detected were considered for its victim
+pub fn get_oom_kill_lookback(tick: u32) -> Duration {
+    Duration::from_millis(tick as u64 + 500)
+}
+
+pub fn get_oom_kill_lookahead(tick: u32) -> Duration {
+    Duration::from_millis(tick as u64 * 2 + 1000)
+}
+
+// how long the processes that disappeared will be kept for naming the victim of an OOM kill, this covers the look-back
+// and the look-ahead at the slowest tick, with another tick for the victim to be picked
+fn get_recently_exited_retention() -> Duration {
+    get_oom_kill_lookback(MAX_TICK)
+        + get_oom_kill_lookahead(MAX_TICK)
+        + Duration::from_millis(MAX_TICK as u64)
+}
+
+// the OOM killer picks the process with the highest oom_score, so among the processes that disappeared
+// around the time the OOM kill was detected, the one with the highest oom_score will most likely be the victim
+pub fn get_oom_kill_victim(
+    recently_exited: &[ExitedProcessData],
+    since: Instant,
+    until: Instant,
+) -> Option<&ExitedProcessData> {
+    recently_exited
+        .iter()
+        .filter(|exited_process| {
+            exited_process.exited_at >= since && exited_process.exited_at <= until
+        })
+        .max_by_key(|exited_process| exited_process.oom_score.unwrap_or(0))
+}
+
+// a one line banner on the top of the screen to notify user about the OOM kill
+pub fn render_oom_kill_banner(
+    area: Rect,
+    frame: &mut Frame,
+    oom_kill_event: &OomKillEventData,
+    app_color_info: &AppColorInfo,
+) {
+    let victim = match (&oom_kill_event.victim, oom_kill_event.is_resolved) {
+        (Some((pid, name)), _) => format!("killed {} (PID {})", name, pid),
+        (None, false) => "looking for the victim...".to_string(),
+        (None, true) => "victim unknown".to_string(),
+    };
+    let message = format!(
+        " OOM KILL at {}{}: {}  ( Esc to dismiss ) ",
+        oom_kill_event.time,
+        if oom_kill_event.count > 1 {
+            format!(" (x{})", oom_kill_event.count)
+        } else {
+            "".to_string()
+        },
+        victim
+    );
+
+    let [_, banner_layout, _] = Layout::horizontal([
+        Constraint::Fill(1),
+        Constraint::Length((message.chars().count() as u16).min(area.width)),
+        Constraint::Fill(1),
+    ])
+    .areas(area);
+    let [banner_layout, _] =
+        Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(banner_layout);
+
+    let banner = Paragraph::new(Line::from(message)).style(
+        Style::default()
+            .fg(app_color_info.background_color)
+            .bg(app_color_info.key_text_color)
+            .bold(),
+    );
+    frame.render_widget(banner, banner_layout);
+}
+
+// split the area by the layout, the nodes without any panel shown will give up their space to the others
+pub fn get_layout_panel_areas(
+    layout: &LayoutConfig,
+    area: Rect,
+    panels: &PanelConfig,
+) -> HashMap<LayoutPanel, Rect> {
+    let shown_panels = get_layout_shown_panels(layout, panels);
+    let mut panel_areas = HashMap::new();
+    split_layout_node(
+        &layout.get_root_node(),
+        area,
+        &|panel| shown_panels.contains(panel),
+        &mut panel_areas,
+    );
+    panel_areas
+}
+
+// the panels placed in the layout that are not hidden, every panel in the layout will be shown rather than an empty screen
+pub fn get_layout_shown_panels(layout: &LayoutConfig, panels: &PanelConfig) -> Vec<LayoutPanel> {
+    let layout_panels = layout.get_root_node().get_panels();
+    let shown_panels: Vec<LayoutPanel> = layout_panels
+        .iter()
+        .filter(|panel| panels.is_shown(**panel) && !layout.hidden.contains(panel))
+        .copied()
+        .collect();
+    if shown_panels.is_empty() {
+        layout_panels
+    } else {
+        shown_panels
+    }
+}
+
+fn split_layout_node(
+    node: &LayoutNode,
+    area: Rect,
+    is_panel_shown: &dyn Fn(&LayoutPanel) -> bool,
+    panel_areas: &mut HashMap<LayoutPanel, Rect>,
+) {
+    if let Some(panel) = node.panel {
+        panel_areas.insert(panel, area);
+        return;
+    }
+
+    let (children, direction) = if node.rows.is_empty() {
+        (&node.columns, Direction::Horizontal)
+    } else {
+        (&node.rows, Direction::Vertical)
+    };
+    let shown_children: Vec<&LayoutNode> = children
+        .iter()
+        .filter(|child| child.get_panels().iter().any(is_panel_shown))
+        .collect();
+    let child_areas = Layout::default()
+        .direction(direction)
+        .constraints(
+            shown_children
+                .iter()
+                .map(|child| Constraint::Fill(child.ratio)),
+        )
+        .split(area);
+    for (child, child_area) in shown_children.iter().zip(child_areas.iter()) {
+        split_layout_node(child, *child_area, is_panel_shown, panel_areas);
+    }
+}
+
+// the problems found in the settings at the bottom, one per line
+pub fn render_config_warning_banner(
+    area: Rect,
+    frame: &mut Frame,
+    config_warnings: &[String],
+    app_color_info: &AppColorInfo,
+) {
+    let mut messages = vec![" SETTINGS: problems found  ( Esc to dismiss ) ".to_string()];
+    messages.extend(
+        config_warnings
+            .iter()
+            .map(|warning| format!(" {} ", warning)),
+    );
+
+    let banner_width = messages
+        .iter()
+        .map(|message| message.chars().count())
+        .max()
+        .unwrap_or(0) as u16;
+    let [_, banner_layout, _] = Layout::horizontal([
+        Constraint::Fill(1),
+        Constraint::Length(banner_width.min(area.width)),
+        Constraint::Fill(1),
+    ])
+    .areas(area);
+    let [_, banner_layout] = Layout::vertical([
+        Constraint::Fill(1),
+        Constraint::Length((messages.len() as u16).min(area.height)),
+    ])
+    .areas(banner_layout);
+
+    let banner = Paragraph::new(messages.into_iter().map(Line::from).collect::<Vec<Line>>()).style(
+        Style::default()
+            .fg(app_color_info.background_color)
+            .bg(app_color_info.key_text_color)
+            .bold(),
+    );
+    frame.render_widget(Clear, banner_layout);
+    frame.render_widget(banner, banner_layout);
+}
+
+pub fn render_oom_score_adj_pop_up(
+    area: Rect,
+    frame: &mut Frame,
+    current_process_oom_score_adj_state_data: &CurrentProcessOomScoreAdjStateData,
+    app_color_info: &AppColorInfo,
+) {
+    let [_, pop_up_width, _] = Layout::horizontal(vec![
+        Constraint::Fill(1),
+        Constraint::Length(60.min(area.width)),
+        Constraint::Fill(1),
+    ])
+    .areas(area);
+
+    let [_, pop_up, _] = Layout::vertical(vec![
+        Constraint::Fill(1),
+        Constraint::Length(12.min(area.height)),
+        Constraint::Fill(1),
+    ])
+    .areas(pop_up_width);
+
+    let info = Line::from(vec![Span::styled(
+        AppPopUpType::OomScoreAdjust.get_string_name(),
+        Style::default().fg(app_color_info.app_title_color).bold(),
+    )]);
+
+    let pop_up_blur_block = Block::new().style(Style::default().bg(app_color_info.pop_up_blur_bg));
+
+    let pop_up_block = Block::bordered()
+        .title(info.left_aligned())
+        .style(
+            Style::reset()
+                .bg(app_color_info.background_color)
+                .fg(app_color_info.background_color),
+        )
+        .border_style(app_color_info.pop_up_color)
+        .border_set(border::ROUNDED);
+
+    frame.render_widget(pop_up_blur_block, frame.area());
+    frame.render_widget(pop_up_block, pop_up);
+
+    let [_, padded_pop_up, _] = Layout::horizontal(vec![
+        Constraint::Length(3),
+        Constraint::Fill(1),
+        Constraint::Length(3),
+    ])
+    .areas(pop_up);
+    let [_, pid_layout, _, value_layout, _, error_layout, instruction_layout, _] =
+        Layout::vertical(vec![
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(2),
+            Constraint::Length(2),
+            Constraint::Fill(1),
+        ])
+        .areas(padded_pop_up);
+
+    // which PID information
+    let pid_info_line = Line::from(vec![
+        Span::styled(
+            "OOM Score Adjustment Of PID ",
+            Style::default().fg(app_color_info.app_title_color),
+        )
+        .bold(),
+        Span::styled(
+            format!("{} ", current_process_oom_score_adj_state_data.pid),
+            Style::default().fg(app_color_info.key_text_color),
+        )
+        .bold(),
+        Span::styled(
+            format!("({})", current_process_oom_score_adj_state_data.name),
+            Style::default().fg(app_color_info.app_title_color),
+        )
+        .bold(),
+    ]);
+
+    let value_line = Line::from(vec![
+        Span::styled("< ", Style::default().fg(app_color_info.key_text_color)).bold(),
+        Span::styled(
+            format!(
+                "{:^7}",
+                current_process_oom_score_adj_state_data.oom_score_adj
+            ),
+            Style::default().fg(app_color_info.base_app_text_color),
+        )
+        .bold(),
+        Span::styled(" >", Style::default().fg(app_color_info.key_text_color)).bold(),
+    ])
+    .centered();
+
+    let error_line = Paragraph::new(
+        Line::from(
+            current_process_oom_score_adj_state_data
+                .error
+                .clone()
+                .unwrap_or_default(),
+        )
+        .style(app_color_info.key_text_color)
+        .centered(),
+    )
+    .wrap(Wrap { trim: true });
+
+    let instruction_line = Paragraph::new(vec![
+        Line::from("-1000 never kill, 1000 kill first")
+            .style(app_color_info.base_app_text_color)
+            .centered(),
+        Line::from(vec![
+            Span::styled("←/→", Style::default().fg(app_color_info.key_text_color)).bold(),
+            Span::styled(
+                " ±1  ",
+                Style::default().fg(app_color_info.base_app_text_color),
+            ),
+            Span::styled("↑/↓", Style::default().fg(app_color_info.key_text_color)).bold(),
+            Span::styled(
+                " ±100  ",
+                Style::default().fg(app_color_info.base_app_text_color),
+            ),
+            Span::styled("↵", Style::default().fg(app_color_info.key_text_color)).bold(),
+            Span::styled(
+                " Apply  ",
+                Style::default().fg(app_color_info.base_app_text_color),
+            ),
+            Span::styled("Esc", Style::default().fg(app_color_info.key_text_color)).bold(),
+            Span::styled(
+                " Cancel",
+                Style::default().fg(app_color_info.base_app_text_color),
+            ),
+        ])
+        .centered(),
+    ]);
+
+    frame.render_widget(pid_info_line, pid_layout);
+    frame.render_widget(value_line, value_layout);
+    frame.render_widget(error_line, error_layout);
+    frame.render_widget(instruction_line, instruction_layout);
+}
+
+// the dashboard will be left unblurred behind the pop-up, so that the theme selected can be previewed on it
+pub fn render_theme_selection_pop_up(
+    area: Rect,
+    frame: &mut Frame,
+    current_theme_selection_state_data: &mut CurrentThemeSelectionStateData,
+    app_color_info: &AppColorInfo,
+) {
+    let theme_count = current_theme_selection_state_data.theme_names.len() as u16;
+    let [_, pop_up_width, _] = Layout::horizontal(vec![
+        Constraint::Fill(1),
+        Constraint::Length(40.min(area.width)),
+        Constraint::Fill(1),
+    ])
+    .areas(area);
+
+    let [_, pop_up, _] = Layout::vertical(vec![
+        Constraint::Fill(1),
+        Constraint::Length((theme_count + 5).min(area.height.saturating_sub(4))),
+        Constraint::Fill(1),
+    ])
+    .areas(pop_up_width);
+
+    let info = Line::from(vec![Span::styled(
+        AppPopUpType::ThemeSelection.get_string_name(),
+        Style::default().fg(app_color_info.app_title_color).bold(),
+    )]);
+
+    let pop_up_block = Block::bordered()
+        .title(info.left_aligned())
+        .style(
+            Style::reset()
+                .bg(app_color_info.background_color)
+                .fg(app_color_info.background_color),
+        )
+        .border_style(app_color_info.pop_up_color)
+        .border_set(border::ROUNDED);
+
+    frame.render_widget(Clear, pop_up);
+    frame.render_widget(pop_up_block, pop_up);
+
+    let [_, padded_pop_up, _] = Layout::horizontal(vec![
+        Constraint::Length(3),
+        Constraint::Fill(1),
+        Constraint::Length(3),
+    ])
+    .areas(pop_up);
+    let [_, theme_list_layout, _, warning_layout, instruction_layout, _] = Layout::vertical(vec![
+        Constraint::Length(1),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(padded_pop_up);
+
+    let theme_list: Vec<ListItem> = current_theme_selection_state_data
+        .theme_names
+        .iter()
+        .map(|theme_name| {
+            ListItem::new(Line::from(Span::styled(
+                format!(" {}", theme_name),
+                Style::default().fg(app_color_info.base_app_text_color),
+            )))
+        })
+        .collect();
+    let theme_list = List::new(theme_list).highlight_style(
+        Style::default()
+            .bg(app_color_info.pop_up_selected_color_bg)
+            .fg(app_color_info.base_app_text_color)
+            .bold()
+            .add_modifier(app_color_info.selected_modifier),
+    );
+
+    // the full problems will be shown in the banner once the theme was applied
+    let warning_line = match current_theme_selection_state_data.warnings.len() {
+        0 => Line::from(""),
+        1 => Line::from("1 problem found in this theme"),
+        warning_count => Line::from(format!("{} problems found in this theme", warning_count)),
+    }
+    .style(app_color_info.key_text_color)
+    .centered();
+
+    let instruction_line = Line::from(vec![
+        Span::styled("↑/↓", Style::default().fg(app_color_info.key_text_color)).bold(),
+        Span::styled(
+            " Preview  ",
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+        Span::styled("↵", Style::default().fg(app_color_info.key_text_color)).bold(),
+        Span::styled(
+            " Apply  ",
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+        Span::styled("Esc", Style::default().fg(app_color_info.key_text_color)).bold(),
+        Span::styled(
+            " Cancel",
+            Style::default().fg(app_color_info.base_app_text_color),
+        ),
+    ])
+    .centered();
+
+    frame.render_stateful_widget(
+        theme_list,
+        theme_list_layout,
+        &mut current_theme_selection_state_data.theme_selected_state,
+    );
+    frame.render_widget(warning_line, warning_layout);
+    frame.render_widget(instruction_line, instruction_layout);
+}
+
 pub fn get_signal_from_int(int: u16) -> Signal {
     match int {
         0 => Signal::Hangup,
@@ -1223,7 +2270,8 @@ fn render_signal_menu_choice_selection(
                 format!("{:<width$}", signal_id, width = 3),
                 Style::default()
                     .fg(app_color_info.key_text_color)
-                    .bg(app_color_info.pop_up_selected_color_bg),
+                    .bg(app_color_info.pop_up_selected_color_bg)
+                    .add_modifier(app_color_info.selected_modifier),
             )
         } else {
             Span::styled(
@@ -1247,7 +2295,8 @@ fn render_signal_menu_choice_selection(
                 ),
                 Style::default()
                     .fg(app_color_info.base_app_text_color)
-                    .bg(app_color_info.pop_up_selected_color_bg),
+                    .bg(app_color_info.pop_up_selected_color_bg)
+                    .add_modifier(app_color_info.selected_modifier),
             )
         } else {
             Span::styled(
@@ -1274,3 +2323,59 @@ fn render_signal_menu_choice_selection(
 
     frame.render_widget(signal_menu_choice, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exited_process(
+        exited_at: Instant,
+        pid: u32,
+        name: &str,
+        oom_score: i32,
+    ) -> ExitedProcessData {
+        ExitedProcessData {
+            exited_at,
+            pid,
+            name: name.to_string(),
+            oom_score: Some(oom_score),
+        }
+    }
+
+    #[test]
+    fn oom_kill_victim_is_picked_by_oom_score_within_the_window() {
+        let detected_at = Instant::now();
+        let since = detected_at - Duration::from_millis(1500);
+        let until = detected_at + Duration::from_millis(3000);
+        let recently_exited = vec![
+            // a short-lived process that exited before the previous vmstat sample
+            exited_process(detected_at - Duration::from_secs(2), 10, "grep", 900),
+            exited_process(detected_at - Duration::from_millis(500), 11, "sh", 5),
+            // the victim disappeared from the process list a tick after the OOM kill was detected
+            exited_process(detected_at + Duration::from_millis(1000), 12, "java", 650),
+        ];
+
+        let victim = get_oom_kill_victim(&recently_exited, since, until).unwrap();
+        assert_eq!((victim.pid, victim.name.as_str()), (12, "java"));
+        assert!(get_oom_kill_victim(&recently_exited[..1], since, until).is_none());
+    }
+
+    #[test]
+    fn capabilities_are_decoded_into_names() {
+        let all_capabilities = (1u64 << CAPABILITY_NAMES.len()) - 1;
+        assert_eq!(format_capabilities(0), "none");
+        assert_eq!(format_capabilities(0x3000), "net_admin,net_raw");
+        assert_eq!(format_capabilities(all_capabilities), "all");
+        // the bounding set of a process usually holds every bit the kernel knows about and more
+        assert_eq!(format_capabilities(u64::MAX), "all");
+        assert_eq!(
+            format_capabilities(all_capabilities & !(1 << 16)),
+            "all but sys_module"
+        );
+        // capabilities newer than the known names are shown by their bit
+        assert_eq!(
+            format_capabilities(1 << CAPABILITY_NAMES.len()),
+            format!("cap_{}", CAPABILITY_NAMES.len())
+        );
+    }
+}